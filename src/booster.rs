@@ -11,6 +11,18 @@ mod cerlpboost;
 mod gradient_boost;
 mod graph_separation_boosting;
 mod madaboost;
+mod brownboost;
+mod rusboost;
+mod adac2;
+mod ozaboost;
+mod filterboost;
+mod logitboost;
+mod gentleboost;
+mod samme;
+mod adaboost_mh;
+mod rankboost;
+mod assemble;
+mod dpboost;
 // mod branching_program;
 mod lpboost;
 mod mlpboost;
@@ -32,6 +44,49 @@ pub use self::core::Booster;
 // Empirical Risk Minimization
 pub use self::adaboost::AdaBoost;
 pub use self::madaboost::MadaBoost;
+pub use self::logitboost::LogitBoost;
+pub use self::gentleboost::GentleBoost;
+
+
+// Noise tolerant boosting
+pub use self::brownboost::BrownBoost;
+
+
+// Imbalanced classification
+pub use self::rusboost::RUSBoost;
+pub use self::adac2::AdaC2;
+
+
+// Online boosting
+pub use self::ozaboost::OzaBoost;
+
+
+// Boosting by filtering
+pub use self::filterboost::{
+    FilterBoost,
+    ExampleOracle,
+    SampleOracle,
+};
+
+
+// Multi-class classification
+pub use self::samme::SAMME;
+
+
+// Multi-label classification
+pub use self::adaboost_mh::AdaBoostMH;
+
+
+// Bipartite ranking
+pub use self::rankboost::RankBoost;
+
+
+// Semi-supervised classification
+pub use self::assemble::ASSEMBLE;
+
+
+// Differentially private classification
+pub use self::dpboost::DPBoost;
 
 
 // Hard Margin Maximization
@@ -51,7 +106,7 @@ pub use self::smoothboost::SmoothBoost;
 
 
 
-pub use self::gradient_boost::GBM;
+pub use self::gradient_boost::{GBM, MultiClassGBM};
 pub use self::graph_separation_boosting::GraphSepBoost;
 
 // // ------------------------------------------------
@@ -43,6 +43,20 @@ use std::ops::ControlFlow;
 /// - [`AdaBoostV`](crate::booster::AdaBoostV), 
 ///   a successor of AdaBoost, maximizes the hard margin.
 /// 
+/// # Abstaining hypotheses
+/// `AdaBoost` accepts the hypotheses that abstain,
+/// i.e., `Classifier::confidence` returns `0.0` on some examples
+/// and `+1.0` or `-1.0` on the others.
+/// For such a hypothesis, `AdaBoost` sets the weight
+/// `ln( (W+ + ε) / (W- + ε) ) / 2`
+/// proposed by Schapire and Singer,
+/// where `W+` (resp. `W-`) is the total weight of the examples
+/// that the hypothesis predicts correctly (resp. incorrectly),
+/// and `ε = 1 / (2m)` is a smoothing parameter.
+/// Use [`Classifier::predict_or_abstain`] and
+/// [`WeightedMajority::coverage_accuracy`]
+/// to abstain from the final prediction.
+/// 
 /// 
 /// ```no_run
 /// use miniboosts::prelude::*;
@@ -183,7 +197,41 @@ impl<'a, F> AdaBoost<'a, F> {
 
 
     /// Returns a weight on the new hypothesis.
-    /// `update_params` also updates `self.dist`.
+    /// If the new hypothesis abstains on some examples
+    /// and predicts `+1` or `-1` on the others,
+    /// this method returns the smoothed weight
+    /// by Schapire and Singer.
+    /// 
+    /// Time complexity: `O( m )`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    fn weight_on_hypothesis(&self, margins: &[f64], edge: f64) -> f64 {
+        let is_abstaining = margins.iter()
+            .all(|&p| p == 0.0 || p.abs() == 1.0);
+        let abstained = margins.iter()
+            .zip(&self.dist[..])
+            .filter_map(|(&p, &d)| (p == 0.0).then_some(d))
+            .sum::<f64>();
+
+        if !is_abstaining || abstained <= 0.0 {
+            return ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+        }
+
+
+        let (correct, incorrect) = margins.iter()
+            .zip(&self.dist[..])
+            .fold((0.0, 0.0), |(c, i), (&p, &d)| {
+                if p > 0.0 { (c + d, i) }
+                else if p < 0.0 { (c, i + d) }
+                else { (c, i) }
+            });
+        let eps = 0.5 / self.sample.shape().0 as f64;
+        ((correct + eps) / (incorrect + eps)).ln() / 2.0
+    }
+
+
+    /// Updates `self.dist` by the new hypothesis
+    /// with weight `weight`.
     /// 
     /// `AdaBoost` uses exponential update,
     /// which is numerically unstable so that I adopt a logarithmic computation.
@@ -195,17 +243,12 @@ impl<'a, F> AdaBoost<'a, F> {
     fn update_params(
         &mut self,
         margins: Vec<f64>,
-        edge: f64
-    ) -> f64
+        weight: f64
+    )
     {
         let n_sample = self.sample.shape().0;
 
 
-        // Compute the weight on new hypothesis.
-        // This is the returned value of this function.
-        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
-
-
         // To prevent overflow, take the logarithm.
         self.dist.par_iter_mut()
             .zip(margins)
@@ -236,9 +279,6 @@ impl<'a, F> AdaBoost<'a, F> {
         // Update distribution over training examples.
         self.dist.par_iter_mut()
             .for_each(|d| *d = (*d - normalizer).exp());
-
-
-        weight
     }
 }
 
@@ -331,7 +371,8 @@ impl<F> Booster<F> for AdaBoost<'_, F>
 
 
         // Compute the weight on the new hypothesis
-        let weight = self.update_params(margins, edge);
+        let weight = self.weight_on_hypothesis(&margins[..], edge);
+        self.update_params(margins, weight);
         self.weights.push(weight);
         self.hypotheses.push(h);
 
@@ -0,0 +1,12 @@
+//! The AdaBoost.MH algorithm proposed
+//! by Robert E. Schapire and Yoram Singer.
+//! This algorithm is based on the paper:
+//! [Improved Boosting Algorithms Using Confidence-rated Predictions](https://link.springer.com/article/10.1023/A:1007614523901)
+//! by Robert E. Schapire and Yoram Singer.
+//! 
+//! AdaBoost.MH is a boosting algorithm for multi-label classification
+//! that keeps a distribution over the pairs of example and label.
+//!
+pub mod adaboost_mh_algorithm;
+
+pub use adaboost_mh_algorithm::AdaBoostMH;
@@ -0,0 +1,324 @@
+//! Provides [`AdaBoostMH`] by Schapire and Singer, 1999.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    MultiLabelClassifier,
+    WeightedMajority,
+    Sample,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The AdaBoost.MH algorithm
+/// proposed by Robert E. Schapire and Yoram Singer.
+/// 
+/// This struct is based on the paper: 
+///
+/// [
+/// Improved Boosting Algorithms Using Confidence-rated Predictions
+/// ](https://link.springer.com/article/10.1023/A:1007614523901)  
+/// by Robert E. Schapire and Yoram Singer.
+/// 
+/// AdaBoost.MH is a boosting algorithm for multi-label classification.
+/// Each example has `K` labels `Y[i][k]` in `{-1, +1}`,
+/// given by [`Sample::set_multi_target`].
+/// `AdaBoostMH` keeps a distribution `D` over the `m * K` pairs
+/// of example and label, and the weak learner returns
+/// a [`MultiLabelClassifier`] `h`
+/// that outputs a confidence `h(x)[k]` in `[-1, 1]` for each label `k`.
+/// 
+/// The distribution passed to the weak learner has length `m * K`.
+/// The weight on the pair `(i, k)` is `dist[i * K + k]`.
+/// 
+/// In each round, `AdaBoostMH` computes the edge
+/// `r = sum( D(i, k) Y[i][k] h(x_i)[k] )`
+/// and sets the weight on `h` as `a = ln( (1 + r) / (1 - r) ) / 2`.
+/// Then, it updates the distribution as
+/// `D(i, k) <- D(i, k) exp( -a Y[i][k] h(x_i)[k] ) / Z`.
+/// 
+/// [`MultiLabelStump`](crate::weak_learner::MultiLabelStump)
+/// is a weak learner for `AdaBoostMH`.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the columns named `label1`, `label2`, and `label3`
+/// // as the multi-label targets.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .multi_target_features(vec!["label1", "label2", "label3"])
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `AdaBoostMH` and set the maximal number of rounds.
+/// let mut booster = AdaBoostMH::init(&sample)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner.
+/// let weak_learner = MultiLabelStump::init();
+/// 
+/// // Run `AdaBoostMH` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_labels_all(&sample);
+/// 
+/// // Calculate the Hamming loss.
+/// let (n_sample, _) = sample.shape();
+/// let targets = sample.multi_target();
+/// let n_label = targets.len();
+/// let hamming_loss = predictions.into_iter()
+///     .enumerate()
+///     .map(|(i, labels)| {
+///         labels.into_iter()
+///             .zip(targets)
+///             .filter(|(p, y)| *p != y[i] as i64)
+///             .count()
+///     })
+///     .sum::<usize>() as f64
+///     / (n_sample * n_label) as f64;
+/// 
+/// println!("Hamming Loss is: {hamming_loss}");
+/// ```
+pub struct AdaBoostMH<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Number of labels
+    n_label: usize,
+
+    // Distribution over the pairs of example and label
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> AdaBoostMH<'a, F> {
+    /// Constructs a new instance of `AdaBoostMH`.
+    /// By default, `AdaBoostMH` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            n_label: 0,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the products `Y[i][k] h(x_i)[k]`
+    /// for each pair of example and label.
+    /// The pair `(i, k)` corresponds to the index `i * K + k`.
+    #[inline]
+    fn margins_of_hypothesis(&self, h: &F) -> Vec<f64>
+        where F: MultiLabelClassifier + Sync,
+    {
+        let n_sample = self.sample.shape().0;
+        let targets = self.sample.multi_target();
+        (0..n_sample).into_par_iter()
+            .flat_map_iter(|i| {
+                h.confidences(self.sample, i)
+                    .into_iter()
+                    .zip(targets)
+                    .map(move |(conf, labels)| labels[i] * conf)
+            })
+            .collect()
+    }
+
+
+    /// Returns a weight on the new hypothesis.
+    /// `update_params` also updates `self.dist`.
+    /// 
+    /// `AdaBoostMH` uses exponential update,
+    /// which is numerically unstable so that I adopt a logarithmic computation.
+    /// 
+    /// Time complexity: `O( m K )`,
+    /// where `m` is the number of training examples
+    /// and `K` is the number of labels.
+    #[inline]
+    fn update_params(
+        &mut self,
+        margins: Vec<f64>,
+        edge: f64
+    ) -> f64
+    {
+        // Compute the weight on new hypothesis.
+        // This is the returned value of this function.
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+
+
+        // To prevent overflow, take the logarithm.
+        self.dist.par_iter_mut()
+            .zip(margins)
+            .for_each(|(d, p)| *d = d.ln() - weight * p);
+
+
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let normalizer = max + self.dist.iter()
+            .map(|d| (d - max).exp())
+            .sum::<f64>()
+            .ln();
+
+
+        // Update distribution over the pairs of example and label.
+        self.dist.par_iter_mut()
+            .for_each(|d| *d = (*d - normalizer).exp());
+
+
+        weight
+    }
+}
+
+
+impl<F> Booster<F> for AdaBoostMH<'_, F>
+    where F: MultiLabelClassifier + Clone + Sync,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "AdaBoost.MH"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let n_label = self.sample.multi_target().len();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("# of labels", format!("{}", n_label)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.n_label = self.sample.is_valid_multilabel_instance();
+        // Initialize parameters
+        let n_pair = self.sample.shape().0 * self.n_label;
+        let uni = 1.0 / n_pair as f64;
+        self.dist = vec![uni; n_pair];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis
+        let h = weak_learner.produce(self.sample, &self.dist);
+
+
+        // Each element in `margins` is the product of
+        // the confidence and the correct label
+        // for a pair of example and label.
+        let margins = self.margins_of_hypothesis(&h);
+
+
+        let edge = margins.par_iter()
+            .zip(&self.dist[..])
+            .map(|(p, d)| p * d)
+            .sum::<f64>();
+
+
+        // If `h` predicted all the pairs correctly,
+        // use it as the combined classifier.
+        if edge.abs() >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![edge.signum()];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // Compute the weight on the new hypothesis
+        let weight = self.update_params(margins, edge);
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for AdaBoostMH<'_, H>
+    where H: MultiLabelClassifier + Clone + Sync,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
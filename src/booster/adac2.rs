@@ -0,0 +1,10 @@
+//! The AdaC2 algorithm proposed
+//! by Sun, Kamel, Wong, and Wang.
+//! This algorithm is based on the paper: 
+//! [
+//! Cost-sensitive boosting for classification of imbalanced data
+//! ](https://www.sciencedirect.com/science/article/pii/S0031320307001835)
+//! 
+pub mod adac2_algorithm;
+
+pub use adac2_algorithm::AdaC2;
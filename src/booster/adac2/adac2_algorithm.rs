@@ -0,0 +1,353 @@
+//! Provides [`AdaC2`] by Sun, Kamel, Wong, and Wang, 2007.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The AdaC2 algorithm 
+/// proposed by Yanmin Sun, Mohamed S. Kamel, Andrew K.C. Wong,
+/// and Yang Wang.
+/// 
+/// This algorithm is based on the paper: 
+///
+/// [
+/// Cost-sensitive boosting for classification of imbalanced data
+/// ](https://www.sciencedirect.com/science/article/pii/S0031320307001835)
+/// by Yanmin Sun, Mohamed S. Kamel, Andrew K.C. Wong, and Yang Wang.
+/// 
+/// AdaC2 is a cost-sensitive variant of
+/// [`AdaBoost`](crate::booster::AdaBoost) for binary classification.
+/// `AdaC2` takes the cost of a false positive
+/// and the cost of a false negative.
+/// Each negative example has the cost of a false positive
+/// and each positive example has the cost of a false negative.
+///
+/// # Update rule
+/// Let `C(x, y)` be the cost of the example `(x, y)`.
+/// Given a new hypothesis `h`, `AdaC2` sets the weight on `h` as
+/// ```text
+/// α = 1/2 ln( (1 + γ) / (1 - γ) ),
+/// ```
+/// where `γ` is the edge of `h` w.r.t. the distribution
+/// `D(x, y) C(x, y)` (normalized).
+/// After that, `AdaC2` updates the distribution as
+/// ```text
+/// D(x, y) <- D(x, y) C(x, y) exp( -α y h(x) ) / Z.
+/// ```
+/// Thus, the costly examples gain more weight
+/// than the others in each round.
+/// If both costs are `1.0`, `AdaC2` is equivalent to `AdaBoost`.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `AdaC2`.
+/// // A false negative costs 10 times more than a false positive.
+/// let mut booster = AdaC2::init(&sample)
+///     .fp_cost(1.0)
+///     .fn_cost(10.0)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `AdaC2` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// 
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+/// 
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as i64 == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct AdaC2<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Distribution on sample.
+    dist: Vec<f64>,
+
+    // Cost of a false positive.
+    fp_cost: f64,
+
+    // Cost of a false negative.
+    fn_cost: f64,
+
+    // Cost for each training example.
+    costs: Vec<f64>,
+
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> AdaC2<'a, F> {
+    /// Constructs a new instance of `AdaC2`.
+    /// By default, both costs are `1.0`
+    /// and `AdaC2` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            dist: Vec::new(),
+
+            fp_cost: 1.0,
+            fn_cost: 1.0,
+            costs: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the cost of a false positive,
+    /// *i.e.,* the cost of misclassifying a negative example.
+    /// Default value is `1.0`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn fp_cost(mut self, cost: f64) -> Self {
+        assert!(cost > 0.0, "The cost must be positive");
+        self.fp_cost = cost;
+        self
+    }
+
+
+    /// Set the cost of a false negative,
+    /// *i.e.,* the cost of misclassifying a positive example.
+    /// Default value is `1.0`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn fn_cost(mut self, cost: f64) -> Self {
+        assert!(cost > 0.0, "The cost must be positive");
+        self.fn_cost = cost;
+        self
+    }
+
+
+    /// Returns a weight on the new hypothesis.
+    /// `update_params` also updates `self.dist`.
+    /// 
+    /// `AdaC2` uses exponential update,
+    /// which is numerically unstable so that I adopt a logarithmic computation.
+    /// 
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    fn update_params(
+        &mut self,
+        margins: Vec<f64>,
+        edge: f64
+    ) -> f64
+    {
+        // Compute the weight on new hypothesis.
+        // This is the returned value of this function.
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+
+
+        // To prevent overflow, take the logarithm.
+        self.dist.par_iter_mut()
+            .zip(&self.costs[..])
+            .zip(margins)
+            .for_each(|((d, c), p)| *d = d.ln() + c.ln() - weight * p);
+
+
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let normalizer = max + self.dist.iter()
+            .map(|d| (d - max).exp())
+            .sum::<f64>()
+            .ln();
+
+
+        // Update distribution over training examples.
+        self.dist.par_iter_mut()
+            .for_each(|d| *d = (*d - normalizer).exp());
+
+
+        weight
+    }
+}
+
+
+impl<F> Booster<F> for AdaC2<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "AdaC2"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("FP cost", format!("{}", self.fp_cost)),
+            ("FN cost", format!("{}", self.fn_cost)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        let uni = 1.0 / n_sample as f64;
+        self.dist = vec![uni; n_sample];
+
+        self.costs = self.sample.target()
+            .iter()
+            .map(|&y| if y > 0.0 { self.fn_cost } else { self.fp_cost })
+            .collect();
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis
+        let h = weak_learner.produce(self.sample, &self.dist);
+
+
+        // Each element in `margins` is the product of
+        // the predicted vector and the correct vector
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+
+
+        // Compute the edge w.r.t. the cost-weighted distribution.
+        let mut cost_dist = self.dist.iter()
+            .zip(&self.costs[..])
+            .map(|(d, c)| d * c)
+            .collect::<Vec<_>>();
+        utils::normalize(&mut cost_dist[..]);
+        let edge = utils::inner_product(&margins, &cost_dist);
+
+
+        // If `h` predicted all the examples in `sample` correctly,
+        // use it as the combined classifier.
+        if edge.abs() >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![edge.signum()];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // Compute the weight on the new hypothesis
+        let weight = self.update_params(margins, edge);
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for AdaC2<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
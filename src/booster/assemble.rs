@@ -0,0 +1,13 @@
+//! The ASSEMBLE algorithm proposed
+//! by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+//! This algorithm is based on the paper:
+//! [Exploiting Unlabeled Data in Ensemble Methods](https://dl.acm.org/doi/10.1145/775047.775090)
+//! by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+//! 
+//! ASSEMBLE is a semi-supervised boosting algorithm
+//! that assigns pseudo-labels to the unlabeled examples
+//! by the current combined hypothesis.
+//!
+pub mod assemble_algorithm;
+
+pub use assemble_algorithm::ASSEMBLE;
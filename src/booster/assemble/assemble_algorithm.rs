@@ -0,0 +1,415 @@
+//! Provides [`ASSEMBLE`] by Bennett, Demiriz, and Maclin, 2002.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The ASSEMBLE algorithm
+/// proposed by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+/// 
+/// This struct is based on the paper: 
+///
+/// [
+/// Exploiting Unlabeled Data in Ensemble Methods
+/// ](https://dl.acm.org/doi/10.1145/775047.775090)  
+/// by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+/// 
+/// ASSEMBLE (Adaptive Supervised Ensemble) is a boosting algorithm
+/// for semi-supervised binary classification.
+/// The target value of an unlabeled example must be `NaN`,
+/// which is the value of an empty field in the target column
+/// of a CSV file read with
+/// [`SampleReader::allow_missing_target`](crate::SampleReader::allow_missing_target).
+/// See [`Sample::is_valid_semi_supervised_instance`].
+/// 
+/// # Pseudo-labels
+/// Let `F` be the current combined hypothesis.
+/// In each round, `ASSEMBLE` assigns the pseudo-label `sign( F(x) )`
+/// to each unlabeled example `x`.
+/// Before the first round, the pseudo-label of an unlabeled example is
+/// the label of its nearest labeled example in the Euclidean distance.
+/// The weak learner receives the sample
+/// whose target values are the labels and the pseudo-labels.
+/// 
+/// # Distribution
+/// `ASSEMBLE` minimizes the exponential loss
+/// `sum( c_i exp( -y_i F(x_i) ) )`,
+/// where `y_i` is the label or the pseudo-label of the `i`th example.
+/// The cost `c_i` is `beta / l` for the labeled examples
+/// and `(1 - beta) / u` for the unlabeled ones,
+/// where `l` and `u` are the numbers of the labeled and unlabeled examples.
+/// Thus, the labeled and unlabeled examples have
+/// the total weight `beta` and `1 - beta` at first.
+/// You can set `beta` by [`ASSEMBLE::labeled_weight`].
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// // The unlabeled rows have an empty field in the column.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .allow_missing_target(true)
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `ASSEMBLE` and set the parameters.
+/// let mut booster = ASSEMBLE::init(&sample)
+///     .labeled_weight(0.9)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `ASSEMBLE` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// 
+/// // Calculate the training loss on the labeled examples.
+/// let target = sample.target();
+/// let (n_error, n_labeled) = target.iter()
+///     .zip(predictions)
+///     .filter(|(y, _)| !y.is_nan())
+///     .fold((0.0, 0.0), |(e, n), (&y, fx)| {
+///         if y as i64 == fx { (e, n + 1.0) } else { (e + 1.0, n + 1.0) }
+///     });
+/// let training_loss = n_error / n_labeled;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct ASSEMBLE<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Training sample whose target values are
+    // the labels and the pseudo-labels.
+    pseudo: Sample,
+
+    // Total weight on the labeled examples at first.
+    beta: f64,
+
+    // Cost `c_i` of each example.
+    costs: Vec<f64>,
+
+    // Distribution over training examples
+    dist: Vec<f64>,
+
+    // Current score `F(x)` for each training example.
+    scores: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> ASSEMBLE<'a, F> {
+    /// Constructs a new instance of `ASSEMBLE`.
+    /// By default, `ASSEMBLE` runs at most `100` rounds
+    /// and sets the total weight on the labeled examples as `0.9`.
+    /// 
+    /// Time complexity: `O(m d)`,
+    /// where `m` is the number of training examples
+    /// and `d` is the number of features.
+    /// This comes from the copy of `sample`
+    /// that holds the pseudo-labels.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            pseudo: sample.clone(),
+
+            beta: 0.9,
+            costs: Vec::new(),
+            dist: Vec::new(),
+            scores: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the total weight `beta` on the labeled examples
+    /// in the initial distribution.
+    /// The unlabeled examples have the total weight `1 - beta`.
+    /// Default value is `0.9`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn labeled_weight(mut self, beta: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&beta),
+            "The weight on the labeled examples must be in [0, 1]"
+        );
+        self.beta = beta;
+        self
+    }
+
+
+    /// Returns the labels and the pseudo-labels
+    /// by the nearest labeled example.
+    /// 
+    /// Time complexity: `O( l u d )`,
+    /// where `l` and `u` are the numbers of the labeled and unlabeled examples
+    /// and `d` is the number of features.
+    fn nearest_neighbor_labels(&self) -> Vec<f64> {
+        let n_sample = self.sample.shape().0;
+        let labeled = (0..n_sample)
+            .map(|i| self.sample.at(i))
+            .filter(|(_, y)| !y.is_nan())
+            .collect::<Vec<_>>();
+
+        (0..n_sample).into_par_iter()
+            .map(|i| {
+                let (x, y) = self.sample.at(i);
+                if !y.is_nan() { return y; }
+
+                labeled.iter()
+                    .map(|(xl, yl)| {
+                        let dist = x.iter()
+                            .zip(xl)
+                            .map(|(a, b)| (a - b).powi(2))
+                            .sum::<f64>();
+                        (dist, *yl)
+                    })
+                    .min_by(|a, b| a.0.total_cmp(&b.0))
+                    .map(|(_, yl)| yl)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+
+    /// Returns the labels and the pseudo-labels
+    /// based on the current scores.
+    /// The pseudo-label of an example with zero score is kept.
+    #[inline]
+    fn pseudo_labels(&self) -> Vec<f64> {
+        self.sample.target()
+            .par_iter()
+            .zip(&self.scores[..])
+            .zip(self.pseudo.target())
+            .map(|((&y, &s), &old)| {
+                if !y.is_nan() { y }
+                else if s > 0.0 { 1.0 }
+                else if s < 0.0 { -1.0 }
+                else { old }
+            })
+            .collect()
+    }
+
+
+    /// Updates `self.dist` by the current scores and labels.
+    /// 
+    /// `ASSEMBLE` uses exponential update,
+    /// which is numerically unstable so that I adopt a logarithmic computation.
+    #[inline]
+    fn update_dist(&mut self) {
+        let target = self.pseudo.target();
+        self.dist = self.costs.par_iter()
+            .zip(target)
+            .zip(&self.scores[..])
+            .map(|((c, y), s)| c.ln() - y * s)
+            .collect();
+
+
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let normalizer = max + self.dist.iter()
+            .map(|d| (d - max).exp())
+            .sum::<f64>()
+            .ln();
+
+
+        self.dist.par_iter_mut()
+            .for_each(|d| *d = (*d - normalizer).exp());
+    }
+}
+
+
+impl<F> Booster<F> for ASSEMBLE<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "ASSEMBLE"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let n_unlabeled = self.sample.target()
+            .iter()
+            .filter(|y| y.is_nan())
+            .count();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of unlabeled examples", format!("{}", n_unlabeled)),
+            ("# of features", format!("{}", n_feature)),
+            ("Weight on labeled examples", format!("{}", self.beta)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_unlabeled = self.sample.is_valid_semi_supervised_instance();
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        let n_labeled = n_sample - n_unlabeled;
+
+        // If there are no unlabeled examples,
+        // all the weight is on the labeled examples.
+        let beta = if n_unlabeled == 0 { 1.0 } else { self.beta };
+        let labeled_cost = beta / n_labeled as f64;
+        let unlabeled_cost = (1.0 - beta) / n_unlabeled.max(1) as f64;
+        self.costs = self.sample.target()
+            .iter()
+            .map(|y| if y.is_nan() { unlabeled_cost } else { labeled_cost })
+            .collect();
+
+
+        // The pseudo-labels before the first round
+        // are given by the nearest labeled examples.
+        self.scores = vec![0.0; n_sample];
+        let pseudo_labels = self.nearest_neighbor_labels();
+        self.pseudo.replace_target(pseudo_labels);
+        self.update_dist();
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis for the labels and the pseudo-labels.
+        let h = weak_learner.produce(&self.pseudo, &self.dist);
+
+
+        let confidences = h.confidence_all(&self.pseudo);
+        let edge = confidences.par_iter()
+            .zip(self.pseudo.target())
+            .zip(&self.dist[..])
+            .map(|((c, y), d)| c * y * d)
+            .sum::<f64>();
+
+
+        // If `h` predicted all the examples correctly,
+        // use it as the combined classifier.
+        if edge >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![1.0];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // If `h` has no advantage, terminate the boosting.
+        if edge <= 0.0 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+        self.scores.par_iter_mut()
+            .zip(confidences)
+            .for_each(|(s, c)| { *s += weight * c; });
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+
+        // Re-assign the pseudo-labels and update the distribution.
+        let pseudo_labels = self.pseudo_labels();
+        self.pseudo.replace_target(pseudo_labels);
+        self.update_dist();
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for ASSEMBLE<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,10 @@
+//! The BrownBoost algorithm proposed
+//! by Yoav Freund.
+//! This algorithm is based on the paper: 
+//! [
+//! An adaptive version of the boost by majority algorithm
+//! ](https://link.springer.com/article/10.1023/A:1010852229904)
+//! 
+pub mod brownboost_algorithm;
+
+pub use brownboost_algorithm::BrownBoost;
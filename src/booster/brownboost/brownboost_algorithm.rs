@@ -0,0 +1,413 @@
+//! Provides [`BrownBoost`] by Freund, 2001.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The number of ODE steps per unit `sqrt(c)` of the margin.
+const STEPS_PER_UNIT: f64 = 100.0;
+
+
+/// The BrownBoost algorithm 
+/// proposed by Yoav Freund, 2001.
+/// 
+/// This algorithm is based on the paper: 
+///
+/// [
+/// An adaptive version of the boost by majority algorithm
+/// ](https://link.springer.com/article/10.1023/A:1010852229904)
+/// by Yoav Freund.
+/// 
+/// BrownBoost is a boosting algorithm for binary classification
+/// that tolerates label noise.
+/// `BrownBoost` has a *total time* `c > 0`
+/// and runs until the remaining time `s` reaches `0`.
+/// In each round, `BrownBoost` assigns the weight
+/// `exp( -(r(x, y) + s)² / c )` to each example `(x, y)`,
+/// where `r(x, y)` is the current margin of the example.
+/// Since the weights vanish on the examples with very negative margins,
+/// `BrownBoost` gives up on persistently misclassified examples,
+/// which are likely to be noisy.
+///
+/// Given a new hypothesis `h`,
+/// `BrownBoost` finds the weight `α` on `h`
+/// and the consumed time `t`
+/// by solving the ordinary differential equation
+/// ```text
+/// dt / dα = γ(α, t),
+/// ```
+/// where `γ(α, t)` is the edge of `h`
+/// w.r.t. the weights after the update.
+/// The integration stops when `γ(α, t) ≤ ν` or `t` reaches `s`.
+///
+/// # Total time
+/// If the weak learner keeps returning hypotheses with positive edges,
+/// the training error of the output is at most `1 - erf( sqrt(c) )`.
+/// Thus, a small `c` yields a noise-tolerant classifier
+/// with a large training error.
+/// For example, `c = 1.0` targets a training error around `0.16`.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `BrownBoost` and set the total time as `2.0`.
+/// let mut booster = BrownBoost::init(&sample)
+///     .time(2.0)
+///     .force_quit_at(200);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `BrownBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// 
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+/// 
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as i64 == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct BrownBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Total time `c`.
+    time: f64,
+
+    // Remaining time `s`.
+    remaining: f64,
+
+    // The ODE terminates if the edge becomes smaller than `nu`.
+    nu: f64,
+
+    // Current margin `r(x, y)` for each training example.
+    margins: Vec<f64>,
+
+
+    // Weights on hypotheses in `hypotheses`
+    alphas: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> BrownBoost<'a, F> {
+    /// Constructs a new instance of `BrownBoost`.
+    /// By default, the total time is `1.0`
+    /// and `ν` is `0.01`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            time: 1.0,
+            remaining: 1.0,
+            nu: 0.01,
+
+            margins: Vec::new(),
+
+            alphas: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: usize::MAX,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the total time `c`.
+    /// A smaller `c` gives up on more examples.
+    /// Default value is `1.0`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn time(mut self, time: f64) -> Self {
+        assert!(time > 0.0, "The total time must be positive");
+        self.time = time;
+        self
+    }
+
+
+    /// Set the threshold `ν` for the edge.
+    /// The ODE for each round stops
+    /// when the edge of the new hypothesis becomes at most `ν`.
+    /// Default value is `0.01`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn nu(mut self, nu: f64) -> Self {
+        assert!(nu > 0.0, "The threshold nu must be positive");
+        self.nu = nu;
+        self
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Returns the distribution over the training examples
+    /// for the remaining time `s`.
+    /// 
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    fn distribution(&self) -> Vec<f64> {
+        let s = self.remaining;
+        let c = self.time;
+        let exponents = self.margins.iter()
+            .map(|r| -(r + s).powi(2) / c)
+            .collect::<Vec<_>>();
+
+        // To prevent underflow, subtract the maximum exponent.
+        let max = exponents.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let mut dist = exponents.into_iter()
+            .map(|e| (e - max).exp())
+            .collect::<Vec<_>>();
+        utils::normalize(&mut dist[..]);
+        dist
+    }
+
+
+    /// Returns the edge `γ(α, t)` of the new hypothesis,
+    /// whose margins are `hm`,
+    /// w.r.t. the weights after the update.
+    /// 
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    fn edge_at(&self, hm: &[f64], alpha: f64, t: f64) -> f64 {
+        let s = self.remaining - t;
+        let c = self.time;
+        let exponents = self.margins.par_iter()
+            .zip(hm)
+            .map(|(r, yh)| -(r + alpha * yh + s).powi(2) / c)
+            .collect::<Vec<_>>();
+
+        let max = exponents.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let (numer, denom) = exponents.into_par_iter()
+            .zip(hm)
+            .map(|(e, yh)| {
+                let w = (e - max).exp();
+                (w * yh, w)
+            })
+            .reduce(|| (0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+
+        numer / denom
+    }
+
+
+    /// Solves the ODE `dt / dα = γ(α, t)` with `t = 0` at `α = 0`
+    /// by the Runge-Kutta method
+    /// and returns the pair `(α, t)` at the end of the integration.
+    /// 
+    /// Time complexity: `O( m c / (ν h) )`,
+    /// where `m` is the number of training examples
+    /// and `h` is the step size.
+    fn solve_ode(&self, hm: &[f64]) -> (f64, f64) {
+        let h = self.time.sqrt() / STEPS_PER_UNIT;
+        let s = self.remaining;
+
+        let mut alpha = 0.0;
+        let mut t = 0.0;
+        loop {
+            let k1 = self.edge_at(hm, alpha, t);
+            let k2 = self.edge_at(hm, alpha + 0.5 * h, t + 0.5 * h * k1);
+            let k3 = self.edge_at(hm, alpha + 0.5 * h, t + 0.5 * h * k2);
+            let k4 = self.edge_at(hm, alpha + h, t + h * k3);
+            let dt = h * (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0;
+
+
+            // If the time runs out within this step,
+            // interpolate `α` so that `t` equals to `s`.
+            if t + dt >= s {
+                alpha += h * (s - t) / dt;
+                return (alpha, s);
+            }
+
+            alpha += h;
+            t += dt;
+
+            if self.edge_at(hm, alpha, t) <= self.nu {
+                return (alpha, t);
+            }
+        }
+    }
+}
+
+
+impl<F> Booster<F> for BrownBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "BrownBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("Total time", format!("{}", self.time)),
+            ("Nu", format!("{}", self.nu)),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        self.margins = vec![0.0; n_sample];
+        self.remaining = self.time;
+
+        self.alphas = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.max_iter = self.force_quit_at.unwrap_or(usize::MAX);
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        let dist = self.distribution();
+        // Get a new hypothesis
+        let h = weak_learner.produce(self.sample, &dist[..]);
+
+
+        // Each element in `margins` is the product of
+        // the predicted vector and the correct vector
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+
+
+        // If `h` has no advantage, `BrownBoost` cannot make progress.
+        let edge = utils::inner_product(&margins, &dist[..]);
+        if edge <= self.nu {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let (alpha, t) = self.solve_ode(&margins[..]);
+        self.margins.par_iter_mut()
+            .zip(margins)
+            .for_each(|(r, yh)| { *r += alpha * yh; });
+        self.remaining -= t;
+
+        self.alphas.push(alpha);
+        self.hypotheses.push(h);
+
+
+        // Terminate if the time runs out.
+        if self.remaining <= 0.0 {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.alphas[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for BrownBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.alphas[..], &self.hypotheses[..])
+    }
+}
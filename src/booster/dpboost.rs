@@ -0,0 +1,11 @@
+//! A differentially private boosting algorithm
+//! based on the paper:
+//! [Efficient, Noise-Tolerant, and Private Learning via Boosting](https://proceedings.mlr.press/v125/bun20a.html)
+//! by Mark Bun, Marco Leandro Carmosino, and Jessica Sorrell.
+//! 
+//! `DPBoost` passes smooth distributions to a private weak learner
+//! and sets the weight on each hypothesis from the noisy edge.
+//!
+pub mod dpboost_algorithm;
+
+pub use dpboost_algorithm::DPBoost;
@@ -0,0 +1,412 @@
+//! Provides [`DPBoost`], a differentially private boosting algorithm
+//! based on the paper by Bun, Carmosino, and Sorrell, 2020.
+use rand::prelude::*;
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    common::privacy::{PrivacyAccountant, laplace_noise},
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// A differentially private boosting algorithm
+/// over the smooth distributions.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Efficient, Noise-Tolerant, and Private Learning via Boosting
+/// ](https://proceedings.mlr.press/v125/bun20a.html)
+/// by Mark Bun, Marco Leandro Carmosino, and Jessica Sorrell.
+///
+/// # Smooth distributions
+/// Let `m` be the number of training examples.
+/// Like [`SmoothBoost`](crate::booster::SmoothBoost),
+/// `DPBoost` only passes `κ`-smooth distributions to the weak learner,
+/// i.e., each example has weight at most `1 / (κ m)`.
+/// In each round, `DPBoost` projects the exponential weights
+/// `exp( -y F(x) )` onto the capped simplex.
+/// Since no example has large weight,
+/// replacing an example changes the weighted accuracy
+/// by at most `2 / (κ m)`.
+/// You can set `κ` by [`DPBoost::smoothness`].
+///
+/// # Privacy
+/// Each round of `DPBoost` accesses the training examples twice:
+/// 1. The weak learner produces a hypothesis `h`
+///    with the privacy budget `ε_wl`.
+///    [`DecisionTree`](crate::weak_learner::DecisionTree) built by
+///    [`DecisionTreeBuilder::differential_privacy`](crate::weak_learner::DecisionTreeBuilder::differential_privacy)
+///    is such a weak learner.
+/// 2. `DPBoost` adds the Laplace noise to the edge of `h`
+///    with the privacy budget `ε_edge`
+///    and sets the weight on `h` from the noisy edge
+///    as `AdaBoost` does.
+///
+/// Thus, each round is `(ε_wl + ε_edge, 0)`-differentially private.
+/// `DPBoost` runs exactly `T` rounds set by [`DPBoost::force_quit_at`]
+/// since a data-dependent stopping rule leaks the privacy.
+/// The [`PrivacyAccountant`] returned by [`DPBoost::accountant`]
+/// reports the privacy loss `(ε, δ)` spent in each round
+/// and the total privacy loss.
+///
+/// `DPBoost` reads `ε_wl` and the smoothness `κ_wl` of the weak learner
+/// from [`WeakLearner::privacy`]
+/// and panics if the weak learner is not differentially private
+/// or `κ_wl` is larger than `κ`,
+/// i.e., the weak learner is not private
+/// for the distributions that `DPBoost` passes.
+///
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Initialize `DPBoost` and set the parameters.
+/// let mut booster = DPBoost::init(&sample)
+///     .smoothness(0.5)
+///     .edge_epsilon(0.1)
+///     .force_quit_at(20);
+///
+/// // Set the differentially private weak learner
+/// // with the same smoothness.
+/// // The bins must cut the public ranges of the features,
+/// // not the ones of the private data.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .feature_range("age", 0.0, 120.0)
+///     .feature_range("income", 0.0, 1e6)
+///     .differential_privacy(0.5, 0.5)
+///     .build();
+///
+/// // Run `DPBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+///
+/// // Get the total privacy loss.
+/// let (epsilon, delta) = booster.accountant().spent();
+/// println!("The hypothesis is ({epsilon}, {delta})-DP.");
+///
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+///
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+///
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as i64 == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+///
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct DPBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Smoothness parameter `κ`.
+    kappa: f64,
+
+    // Privacy budget of the weak learner per round.
+    // This value is read from the weak learner.
+    wl_epsilon: f64,
+
+    // Privacy budget of the noisy edge per round.
+    edge_epsilon: f64,
+
+    // Privacy accountant.
+    accountant: PrivacyAccountant,
+
+    // Seed for the noise on the edges.
+    seed: u64,
+    rng: StdRng,
+
+    // Distribution over training examples
+    dist: Vec<f64>,
+
+    // Current score `F(x)` for each training example.
+    scores: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> DPBoost<'a, F> {
+    /// Constructs a new instance of `DPBoost`.
+    /// By default, `DPBoost` runs `100` rounds with
+    /// ```text
+    /// smoothness: 0.5,
+    /// edge_epsilon: 0.1,
+    /// delta: 1e-6,
+    /// seed: 1234,
+    /// ```
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let seed = 1234;
+        Self {
+            sample,
+
+            kappa: 0.5,
+            wl_epsilon: 0.0,
+            edge_epsilon: 0.1,
+            accountant: PrivacyAccountant::new(1e-6),
+
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+
+            dist: Vec::new(),
+            scores: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the number of rounds `T`.
+    /// `DPBoost` runs exactly `T` rounds.
+    /// Default value is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the smoothness parameter `κ`.
+    /// Each example has weight at most `1 / (κ m)`,
+    /// where `m` is the number of training examples.
+    /// Default value is `0.5`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn smoothness(mut self, kappa: f64) -> Self {
+        assert!(
+            kappa > 0.0 && kappa <= 1.0,
+            "The smoothness parameter must be in (0.0, 1.0]"
+        );
+        self.kappa = kappa;
+        self
+    }
+
+
+    /// Set the privacy budget `ε_edge` of the noisy edge per round.
+    /// Default value is `0.1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn edge_epsilon(mut self, epsilon: f64) -> Self {
+        assert!(epsilon > 0.0, "The privacy budget must be positive");
+        self.edge_epsilon = epsilon;
+        self
+    }
+
+
+    /// Set the slack parameter `δ'` for the advanced composition.
+    /// See [`PrivacyAccountant`].
+    /// Default value is `1e-6`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn delta(mut self, delta: f64) -> Self {
+        self.accountant = PrivacyAccountant::new(delta);
+        self
+    }
+
+
+    /// Set the seed for the noise on the edges.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the privacy accountant.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn accountant(&self) -> &PrivacyAccountant {
+        &self.accountant
+    }
+
+
+    /// Returns the sensitivity `2 / (κ m)` of the edge.
+    #[inline]
+    fn sensitivity(&self) -> f64 {
+        let n_sample = self.sample.shape().0 as f64;
+        2.0 / (self.kappa * n_sample)
+    }
+
+
+    /// Updates the distribution
+    /// by projecting `exp( -y F(x) )` onto the capped simplex.
+    #[inline]
+    fn update_distribution(&mut self) {
+        let n_sample = self.sample.shape().0 as f64;
+        let nu = self.kappa * n_sample;
+        let iter = self.scores.iter().map(|yf| -yf);
+        self.dist = utils::project_log_distribution_to_capped_simplex(nu, iter);
+    }
+}
+
+
+impl<F> Booster<F> for DPBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "DPBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("Smoothness (κ)", format!("{}", self.kappa)),
+            ("Privacy budget (edge)", format!("{}", self.edge_epsilon)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+
+        let (wl_epsilon, wl_kappa) = weak_learner.privacy()
+            .expect("The weak learner must be differentially private");
+        assert!(
+            wl_kappa <= self.kappa,
+            "The weak learner is private only for {wl_kappa}-smooth \
+             distributions, but `DPBoost` passes {}-smooth ones",
+            self.kappa
+        );
+        self.wl_epsilon = wl_epsilon;
+        let n_sample = self.sample.shape().0;
+
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.accountant.reset();
+
+        self.scores = vec![0.0; n_sample];
+        self.dist = vec![1.0 / n_sample as f64; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis from the private weak learner.
+        let h = weak_learner.produce(self.sample, &self.dist[..]);
+
+
+        // Compute the noisy edge of `h`.
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+        let edge = margins.iter()
+            .zip(&self.dist[..])
+            .map(|(yh, d)| yh * d)
+            .sum::<f64>();
+        let scale = self.sensitivity() / self.edge_epsilon;
+        let noisy_edge = edge + laplace_noise(scale, &mut self.rng);
+
+        self.accountant.spend(self.wl_epsilon + self.edge_epsilon, 0.0);
+
+
+        // Compute the weight on `h` from the noisy edge.
+        // The noisy edge is clipped to avoid the infinite weight.
+        let n_sample = self.sample.shape().0 as f64;
+        let noisy_edge = noisy_edge.clamp(0.0, 1.0 - 1.0 / n_sample);
+        let weight = 0.5 * ((1.0 + noisy_edge) / (1.0 - noisy_edge)).ln();
+
+
+        self.scores.par_iter_mut()
+            .zip(margins)
+            .for_each(|(s, yh)| { *s += weight * yh; });
+        self.update_distribution();
+
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for DPBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,12 @@
+//! The FilterBoost algorithm proposed
+//! by Joseph K. Bradley and Robert E. Schapire.
+//! This algorithm is based on the paper: 
+//! [
+//! FilterBoost: Regression and Classification on Large Datasets
+//! ](https://papers.nips.cc/paper_files/paper/2007)
+//! 
+pub mod filterboost_algorithm;
+pub mod oracle;
+
+pub use filterboost_algorithm::FilterBoost;
+pub use oracle::{ExampleOracle, SampleOracle};
@@ -0,0 +1,515 @@
+//! Provides [`FilterBoost`] by Bradley and Schapire, 2007.
+use rand::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    research::Research,
+};
+
+use super::oracle::ExampleOracle;
+
+use std::ops::ControlFlow;
+
+
+/// A batch of examples drawn from the oracle
+/// and the scores `F(x)` of the current combined hypothesis.
+struct Batch {
+    sample: Sample,
+    scores: Vec<f64>,
+    cursor: usize,
+}
+
+
+/// The FilterBoost algorithm 
+/// proposed by Joseph K. Bradley and Robert E. Schapire.
+/// 
+/// This algorithm is based on the paper: 
+///
+/// [
+/// FilterBoost: Regression and Classification on Large Datasets
+/// ](https://papers.nips.cc/paper_files/paper/2007)
+/// by Joseph K. Bradley and Robert E. Schapire.
+/// 
+/// FilterBoost is a boosting algorithm for binary classification
+/// that never keeps a distribution over the whole sample.
+/// Instead, `FilterBoost` draws examples from an [`ExampleOracle`]
+/// and accepts each example `(x, y)` with probability
+/// `q(x, y) = 1 / (1 + exp( y F(x) ))`,
+/// where `F` is the current combined hypothesis.
+/// As the weights of [`MadaBoost`](crate::booster::MadaBoost),
+/// `q(x, y)` is bounded by `1`,
+/// so that the filter realizes the weights by rejection sampling.
+/// 
+/// In each round, `FilterBoost`
+/// 1. passes `n_train` accepted examples to the weak learner
+///    with the uniform distribution,
+/// 2. estimates the edge `γ` of the new hypothesis
+///    from the accepted examples
+///    until the estimate is accurate enough (see below), and
+/// 3. sets the weight `1/2 ln( (1 + γ) / (1 - γ) )`
+///    on the new hypothesis.
+/// 
+/// # Sequential edge estimate
+/// Let `u` be the average of `y h(x)` over `n` accepted examples.
+/// `FilterBoost` stops drawing examples once
+/// `|u| >= (1 + 1/τ) ε(n)` holds,
+/// where `ε(n) = sqrt( 2 ln( n(n+1) / δ ) / n )`
+/// is a deviation bound that holds for all `n` simultaneously
+/// with probability at least `1 - δ`.
+/// Then, `u / (1 + τ)` is a lower bound of the edge.
+/// 
+/// # Termination
+/// If the filter rejects many consecutive examples,
+/// the training error of `F` is at most `tolerance`
+/// with high probability.
+/// Then, `FilterBoost` terminates.
+/// `FilterBoost` also terminates
+/// if the oracle returns an empty batch,
+/// e.g., a stream of examples reaches its end.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read a part of the dataset to set up the weak learner.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // An oracle that draws examples from `sample`.
+/// // Implement `ExampleOracle` to stream examples from disk.
+/// let oracle = SampleOracle::new(&sample)
+///     .seed(777);
+/// 
+/// // Initialize `FilterBoost`.
+/// let mut booster = FilterBoost::init(oracle)
+///     .n_train(1_000)
+///     .tolerance(0.01)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `FilterBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct FilterBoost<O, F> {
+    // The example oracle.
+    oracle: O,
+
+    // The current batch of examples.
+    batch: Option<Batch>,
+
+    // The number of accepted examples passed to the weak learner.
+    n_train: usize,
+
+    // Target training error.
+    tolerance: f64,
+
+    // Confidence parameter.
+    delta: f64,
+
+    // Relative accuracy of the edge estimate.
+    tau: f64,
+
+    // The maximum number of examples for an edge estimate.
+    edge_sample_limit: usize,
+
+    // Seed of the randomness for the rejection sampling.
+    seed: u64,
+
+    // Random number generator for the rejection sampling.
+    rng: StdRng,
+
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<O, F> FilterBoost<O, F> {
+    /// Constructs a new instance of `FilterBoost`.
+    /// By default, `FilterBoost` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(oracle: O) -> Self {
+        let seed = 1234;
+        Self {
+            oracle,
+            batch: None,
+
+            n_train: 1_000,
+            tolerance: 0.01,
+            delta: 0.1,
+            tau: 0.3,
+            edge_sample_limit: 100_000,
+
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the number of accepted examples
+    /// passed to the weak learner in each round.
+    /// Default value is `1000`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn n_train(mut self, n_train: usize) -> Self {
+        assert!(n_train > 0);
+        self.n_train = n_train;
+        self
+    }
+
+
+    /// Set the target training error.
+    /// Default value is `0.01`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(0.0 < tolerance && tolerance < 1.0);
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the confidence parameter `δ`.
+    /// Default value is `0.1`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn delta(mut self, delta: f64) -> Self {
+        assert!(0.0 < delta && delta < 1.0);
+        self.delta = delta;
+        self
+    }
+
+
+    /// Set the relative accuracy `τ` of the edge estimate.
+    /// Default value is `0.3`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn tau(mut self, tau: f64) -> Self {
+        assert!(tau > 0.0);
+        self.tau = tau;
+        self
+    }
+
+
+    /// Set the maximum number of examples for an edge estimate.
+    /// If the estimate does not converge within this number,
+    /// `FilterBoost` uses the empirical edge.
+    /// Default value is `100000`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn edge_sample_limit(mut self, limit: usize) -> Self {
+        assert!(limit > 0);
+        self.edge_sample_limit = limit;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the rejection sampling.
+    /// Default vaule is `1234.`
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the maximum number of consecutive rejections.
+    /// Since the filter accepts a misclassified example
+    /// with probability at least `1/2`,
+    /// `FilterBoost` rejects this number of consecutive examples
+    /// with probability at most `δ`
+    /// if the training error is larger than `tolerance`.
+    /// 
+    /// Time complexity: `O(1)`.
+    fn max_rejections(&self) -> usize {
+        ((2.0 / self.tolerance) * (1.0 / self.delta).ln()).ceil() as usize
+    }
+}
+
+
+impl<O, F> FilterBoost<O, F>
+    where O: ExampleOracle,
+          F: Classifier,
+{
+    /// Returns the scores `F(x)` of the current combined hypothesis
+    /// on the given sample.
+    /// 
+    /// Time complexity: `O( m T )`,
+    /// where `m` is the number of examples in `sample`
+    /// and `T` is the number of hypotheses.
+    fn scores(&self, sample: &Sample) -> Vec<f64> {
+        let n_sample = sample.shape().0;
+        let mut scores = vec![0.0; n_sample];
+        for (w, h) in self.weights.iter().zip(&self.hypotheses[..]) {
+            scores.iter_mut()
+                .enumerate()
+                .for_each(|(i, s)| { *s += w * h.confidence(sample, i); });
+        }
+        scores
+    }
+
+
+    /// Recomputes the scores of the current batch
+    /// after `F` is updated.
+    fn refresh_batch(&mut self) {
+        if let Some(batch) = self.batch.take() {
+            let scores = self.scores(&batch.sample);
+            self.batch = Some(Batch { scores, ..batch });
+        }
+    }
+
+
+    /// Draws examples until the filter accepts one
+    /// and returns its row in the current batch.
+    /// Returns `None` if the filter rejects
+    /// `self.max_rejections()` consecutive examples
+    /// or the oracle returns an empty batch.
+    fn filter(&mut self) -> Option<usize> {
+        let max_rejections = self.max_rejections();
+        for _ in 0..max_rejections {
+            let exhausted = self.batch.as_ref()
+                .map(|batch| batch.cursor >= batch.sample.shape().0)
+                .unwrap_or(true);
+            if exhausted {
+                let sample = self.oracle.draw();
+                if sample.shape().0 == 0 {
+                    self.batch = None;
+                    return None;
+                }
+                let scores = self.scores(&sample);
+                self.batch = Some(Batch { sample, scores, cursor: 0 });
+            }
+
+            let batch = self.batch.as_mut().unwrap();
+            let row = batch.cursor;
+            batch.cursor += 1;
+
+            let y = batch.sample.target()[row];
+            let q = 1.0 / (1.0 + (y * batch.scores[row]).exp());
+            if self.rng.gen::<f64>() < q {
+                return Some(row);
+            }
+        }
+        None
+    }
+
+
+    /// Returns the sample of `self.n_train` accepted examples.
+    /// Returns `None` if the filter fails.
+    fn accepted_sample(&mut self) -> Option<Sample> {
+        let mut rows = Vec::with_capacity(self.n_train);
+        let mut target = Vec::with_capacity(self.n_train);
+        let mut names = Vec::new();
+        for _ in 0..self.n_train {
+            let row = self.filter()?;
+            let sample = &self.batch.as_ref().unwrap().sample;
+            if names.is_empty() {
+                names = sample.features()
+                    .iter()
+                    .map(|feat| feat.name().to_string())
+                    .collect::<Vec<_>>();
+            }
+            let (x, y) = sample.at(row);
+            rows.push(x);
+            target.push(y);
+        }
+
+
+        let columns = names.into_iter()
+            .enumerate()
+            .map(|(j, name)| {
+                let column = rows.iter()
+                    .map(|x| x[j])
+                    .collect::<Vec<_>>();
+                (name, column)
+            })
+            .collect::<Vec<_>>();
+        let sample = Sample::from_dense_columns(columns, target)
+            .expect("The batches of the oracle have different features");
+        Some(sample)
+    }
+
+
+    /// Estimates the edge of `h` by the sequential sampling
+    /// with confidence parameter `delta`.
+    /// Returns `None` if the filter fails.
+    fn estimate_edge(&mut self, h: &F, delta: f64) -> Option<f64> {
+        let mut sum = 0.0;
+        let mut n = 0.0;
+        for _ in 0..self.edge_sample_limit {
+            let row = self.filter()?;
+            let sample = &self.batch.as_ref().unwrap().sample;
+            let y = sample.target()[row];
+            sum += y * h.confidence(sample, row);
+            n += 1.0;
+
+            let u = sum / n;
+            let eps = (2.0 * (n * (n + 1.0) / delta).ln() / n).sqrt();
+            if u.abs() >= (1.0 + 1.0 / self.tau) * eps {
+                return Some(u / (1.0 + self.tau));
+            }
+        }
+        Some(sum / n)
+    }
+}
+
+
+impl<O, F> Booster<F> for FilterBoost<O, F>
+    where O: ExampleOracle,
+          F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "FilterBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("# of training examples per round", format!("{}", self.n_train)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Delta", format!("{}", self.delta)),
+            ("Tau", format!("{}", self.tau)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.batch = None;
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // If the filter fails,
+        // the current combined hypothesis is accurate enough.
+        let Some(sample) = self.accepted_sample() else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        };
+
+
+        // Get a new hypothesis
+        let n_sample = sample.shape().0;
+        let dist = vec![1.0 / n_sample as f64; n_sample];
+        let h = weak_learner.produce(&sample, &dist[..]);
+
+
+        // Split the confidence parameter over the rounds.
+        let t = iteration as f64;
+        let delta = self.delta / (t * (t + 1.0));
+        let Some(edge) = self.estimate_edge(&h, delta) else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        };
+
+
+        // If `h` has no advantage, discard it.
+        if edge <= 0.0 {
+            return ControlFlow::Continue(());
+        }
+
+
+        let edge = edge.min(1.0 - f64::EPSILON);
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+        self.refresh_batch();
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<O, H> Research for FilterBoost<O, H>
+    where O: ExampleOracle,
+          H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
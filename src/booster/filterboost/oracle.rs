@@ -0,0 +1,94 @@
+//! Defines the example oracles for [`FilterBoost`](super::FilterBoost).
+use rand::prelude::*;
+
+use crate::Sample;
+
+
+/// An interface for the example oracles.
+/// An example oracle returns a batch of examples
+/// drawn from the underlying distribution
+/// every time [`ExampleOracle::draw`] is called.
+/// 
+/// The batches must have the same features in the same order.
+/// To boost over a dataset that does not fit in memory,
+/// implement this trait for a reader
+/// that streams the chunks of the dataset from disk.
+pub trait ExampleOracle {
+    /// Draws a batch of examples.
+    /// An empty batch means that the oracle is exhausted;
+    /// [`FilterBoost`](super::FilterBoost) treats it as a failure
+    /// of the filter and terminates.
+    fn draw(&mut self) -> Sample;
+}
+
+
+/// An [`ExampleOracle`] that draws examples
+/// uniformly at random with replacement from a [`Sample`].
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// let oracle = SampleOracle::new(&sample)
+///     .batch_size(1_000)
+///     .seed(777);
+/// ```
+pub struct SampleOracle<'a> {
+    sample: &'a Sample,
+    batch_size: usize,
+    seed: u64,
+    rng: StdRng,
+}
+
+
+impl<'a> SampleOracle<'a> {
+    /// Construct a new instance of `SampleOracle`.
+    #[inline]
+    pub fn new(sample: &'a Sample) -> Self {
+        let seed = 1234;
+        Self {
+            sample,
+            batch_size: 1_000,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+
+    /// Set the number of examples in a batch.
+    /// Default value is `1000.`
+    #[inline]
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "The batch size must be positive");
+        self.batch_size = batch_size;
+        self
+    }
+
+
+    /// Set the seed of the randomness for sampling.
+    /// Default vaule is `1234.`
+    #[inline]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+}
+
+
+impl ExampleOracle for SampleOracle<'_> {
+    fn draw(&mut self) -> Sample {
+        let n_sample = self.sample.shape().0;
+        let ix = (0..self.batch_size)
+            .map(|_| self.rng.gen_range(0..n_sample))
+            .collect::<Vec<_>>();
+        self.sample.subsample(ix)
+    }
+}
@@ -0,0 +1,12 @@
+//! The GentleBoost algorithm proposed
+//! by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+//! This algorithm is based on the paper:
+//! [Additive logistic regression: a statistical view of boosting](https://projecteuclid.org/journals/annals-of-statistics/volume-28/issue-2/Additive-logistic-regression--a-statistical-view-of-boosting-With/10.1214/aos/1016218223.full)
+//! by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+//! 
+//! GentleBoost is a boosting algorithm for binary classification
+//! that minimizes exponential loss by Newton steps.
+//!
+pub mod gentleboost_algorithm;
+
+pub use gentleboost_algorithm::GentleBoost;
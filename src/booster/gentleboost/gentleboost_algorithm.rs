@@ -0,0 +1,300 @@
+//! Provides [`GentleBoost`] by Friedman, Hastie, and Tibshirani, 2000.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Regressor,
+    WeightedMajority,
+    LogisticClassifier,
+    Sample,
+
+    common::loss_functions::{GBMLoss, LossFunction},
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The GentleBoost algorithm
+/// proposed by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+/// 
+/// This struct is based on the paper: 
+///
+/// [
+/// Additive logistic regression: a statistical view of boosting
+/// ](https://projecteuclid.org/journals/annals-of-statistics/volume-28/issue-2/Additive-logistic-regression--a-statistical-view-of-boosting-With/10.1214/aos/1016218223.full)  
+/// by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+/// 
+/// GentleBoost is a boosting algorithm for binary classification
+/// that minimizes the exponential loss `exp( -y F(x) )`,
+/// the same objective as [`AdaBoost`](crate::booster::AdaBoost).
+/// Unlike `AdaBoost`, GentleBoost adds each hypothesis
+/// without a line search,
+/// so that it takes bounded steps and is numerically stable.
+///
+/// # Weighted least squares
+/// Let `w(x, y) ∝ exp( -y F(x) )` be the current weights.
+/// In each round, `GentleBoost` fits a confidence-rated regressor `f`
+/// to the labels `y` by the weighted least squares
+/// with the weights `w`,
+/// and then updates `F(x) <- F(x) + f(x)`.
+///
+/// Here, `GentleBoost` follows the same protocol as
+/// [`GBM`](crate::booster::GBM):
+/// the weak learner receives the current scores `F(x)` as `dist`.
+/// A weak learner that takes a Newton step
+/// on [`GBMLoss::Exponential`] yields the above weighted least squares fit,
+/// since the Newton step `-G / H` on each leaf equals
+/// `sum( w y ) / sum( w )` over the examples in the leaf.
+/// [`RegressionTree`](crate::weak_learner::RegressionTree)
+/// with `GBMLoss::Exponential` is such a weak learner.
+/// Setting `max_depth(2)` yields regression stumps.
+///
+/// The output of `GentleBoost` is a [`LogisticClassifier`].
+/// Since the minimizer of the exponential loss is
+/// the half of the log-odds,
+/// each hypothesis has weight `2.0` in the inner regressor.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `GentleBoost` and set the maximal number of rounds.
+/// let mut booster = GentleBoost::init(&sample)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// // The loss function must be `GBMLoss::Exponential`.
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::Exponential)
+///     .build();
+/// 
+/// // Run `GentleBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// 
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+/// 
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as i64 == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct GentleBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+
+
+    // Current score `F(x)` for each training example.
+    predictions: Vec<f64>,
+}
+
+
+impl<'a, F> GentleBoost<'a, F> {
+    /// Constructs a new instance of `GentleBoost`.
+    /// By default, `GentleBoost` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            tolerance: 0.0,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            predictions: Vec::new(),
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `GentleBoost` terminates if the decrease of the exponential loss
+    /// in a round is at most `tolerance`.
+    /// Default value is `0.0`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Returns the current combined hypothesis.
+    #[inline]
+    fn combined_hypothesis(&self) -> LogisticClassifier<WeightedMajority<F>>
+        where F: Clone,
+    {
+        // The inner regressor outputs `2 F(x)`, the log-odds.
+        let f = WeightedMajority {
+            weights: self.weights.clone(),
+            hypotheses: self.hypotheses.clone(),
+        };
+        LogisticClassifier::new(f)
+    }
+}
+
+
+impl<F> Booster<F> for GentleBoost<'_, F>
+    where F: Regressor + Clone,
+{
+    type Output = LogisticClassifier<WeightedMajority<F>>;
+
+
+    fn name(&self) -> &str {
+        "GentleBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        self.predictions = vec![0.0; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis that fits the labels
+        // by the weighted least squares.
+        let h = weak_learner.produce(self.sample, &self.predictions[..]);
+        let predictions = h.predict_all(self.sample);
+
+
+        let target = self.sample.target();
+        let old_loss = GBMLoss::Exponential.eval(&self.predictions[..], target);
+        let new_predictions = self.predictions.par_iter()
+            .zip(predictions)
+            .map(|(p, q)| p + q)
+            .collect::<Vec<_>>();
+        let new_loss = GBMLoss::Exponential.eval(&new_predictions[..], target);
+
+
+        // If the new hypothesis increases the exponential loss,
+        // discard it and terminate the boosting.
+        if new_loss > old_loss {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        self.weights.push(2.0);
+        self.hypotheses.push(h);
+        self.predictions = new_predictions;
+
+
+        // If the new hypothesis does not decrease the exponential loss
+        // sufficiently, terminate the boosting.
+        if old_loss - new_loss <= self.tolerance {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.combined_hypothesis()
+    }
+}
+
+
+impl<H> Research for GentleBoost<'_, H>
+    where H: Regressor + Clone,
+{
+    type Output = LogisticClassifier<WeightedMajority<H>>;
+    fn current_hypothesis(&self) -> Self::Output {
+        self.combined_hypothesis()
+    }
+}
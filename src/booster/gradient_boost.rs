@@ -1,4 +1,6 @@
 //! Defines Gradient Boosting Machine.
 pub mod gbm;
+pub mod multiclass_gbm;
 
 pub use gbm::*;
+pub use multiclass_gbm::*;
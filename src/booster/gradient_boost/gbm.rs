@@ -1,4 +1,6 @@
 //! Provides Gradient Boosting Machine ([`GBM`]) by Friedman, 2001.
+use rand::prelude::*;
+use rand::seq::index;
 use rayon::prelude::*;
 
 use crate::{
@@ -7,9 +9,11 @@ use crate::{
     Booster,
     WeakLearner,
     Regressor,
-    WeightedMajority
+    WeightedMajority,
+    BaseScoreRegressor,
 };
 
+use std::mem;
 use std::ops::ControlFlow;
 
 
@@ -24,7 +28,124 @@ use std::ops::ControlFlow;
 /// (One can see GBM as coordinate descent algorithm,
 /// where each coordinate corresponds to some function in that space).
 /// 
-/// **Note.** Currently, I only implements GBM for regression.
+/// **Note.** Currently, I only implements GBM for regression
+/// and binary classification.
+/// For multi-class classification,
+/// see [`MultiClassGBM`](crate::booster::MultiClassGBM).
+/// 
+/// # Binary classification
+/// `GBM` with [`GBMLoss::Logistic`] minimizes the binomial deviance
+/// for the labels `+1` and `-1`.
+/// The output of `GBM` is then the log-odds of `+1`,
+/// and [`LogisticClassifier`](crate::LogisticClassifier)
+/// turns it into a classifier that outputs the probabilities:
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let mut booster = GBM::init_with_loss(&sample, GBMLoss::Logistic);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::Logistic)
+///     .build();
+/// 
+/// let f = LogisticClassifier::new(booster.run(&weak_learner));
+/// let probabilities = f.probability_all(&sample);
+/// let labels = f.predict_all(&sample);
+/// ```
+/// 
+/// # Learning to rank
+/// `GBM` with [`LambdaRank`](crate::LambdaRank) loss is **LambdaMART**.
+/// Specify the query of each example by
+/// [`Sample::set_group`](crate::Sample::set_group),
+/// so that the gradients are computed per query.
+/// The weak learner computes the gradients by
+/// [`LossFunction::gradient_and_hessian_grouped`].
+/// 
+/// # Survival analysis
+/// `GBM` with [`CoxPH`](crate::CoxPH) loss fits the log hazard ratios
+/// of the Cox proportional hazards model.
+/// Specify the censoring indicators by
+/// [`Sample::set_event`](crate::Sample::set_event)
+/// and evaluate the ranking of the risk scores by
+/// [`CoxPH::concordance_index`](crate::CoxPH::concordance_index).
+/// 
+/// # Base score and shrinkage
+/// `GBM` starts from the base score,
+/// the constant prediction that minimizes the loss
+/// (see [`LossFunction::base_score`]),
+/// instead of zero.
+/// `GBM` returns the trees wrapped by [`BaseScoreRegressor`]
+/// with the base score, so that [`Regressor::predict`] includes it.
+/// Use [`GBM::base_score`] to specify the base score by yourself.
+/// 
+/// [`GBM::learning_rate`] multiplies the coefficient of each tree
+/// by the learning rate `η` in `(0, 1]`.
+/// Smaller `η` requires more rounds but generalizes better.
+/// 
+/// # Early stopping
+/// [`GBM::early_stopping`] takes a validation sample.
+/// `GBM` stops if the loss on the validation sample
+/// does not improve for the given number of rounds,
+/// and returns the ensemble with the smallest validation loss.
+/// 
+/// # Count and positive-valued regression
+/// [`GBMLoss::Poisson`], [`GBMLoss::Gamma`], and [`GBMLoss::Tweedie`]
+/// use the log link,
+/// so that the output of `GBM` is the log-mean.
+/// Wrap the output by [`LogLinkRegressor`](crate::LogLinkRegressor)
+/// to predict the mean.
+/// 
+/// # Newton boosting
+/// By default, `GBM` scales each tree by the coefficient
+/// found by [`LossFunction::line_search`].
+/// [`GBM::newton`] switches `GBM` to the second-order boosting
+/// of XGBoost (Chen and Guestrin, 2016).
+/// In this mode, `GBM` adds each tree as it is,
+/// since [`RegressionTree`] already takes a Newton step;
+/// each leaf predicts `-G / (H + λ)`
+/// and each split maximizes the gain
+/// ```txt
+/// 0.5 * [ G_L^2 / (H_L + λ) + G_R^2 / (H_R + λ) - G^2 / (H + λ) ],
+/// ```
+/// where `G` and `H` are the sums of
+/// [`LossFunction::gradient`] and [`LossFunction::hessian`]
+/// over the examples in the node
+/// and `λ` is [`RegressionTreeBuilder::lambda_l2`].
+/// 
+/// The Newton step requires the second derivative of the loss.
+/// For the losses whose [`LossFunction::has_hessian`] is `false`,
+/// e.g., [`GBMLoss::L1`], [`GBMLoss::Huber`], and [`GBMLoss::Quantile`],
+/// `-G / (H + λ)` is not a Newton step,
+/// so that `GBM` scales each tree by the line search
+/// even if [`GBM::newton`] is set.
+/// 
+/// # DART
+/// [`GBM::dart`] switches `GBM` to DART (Rashmi and Gilad-Bachrach, 2015),
+/// which applies the dropout to the trees.
+/// In each round, `GBM` drops `k` random trees from the ensemble,
+/// fits a new tree to the gradients of the remaining ensemble,
+/// and then multiplies the weight of the new tree by `1 / (k + 1)`
+/// and the weights of the dropped trees by `k / (k + 1)`.
+/// This prevents the late trees from contributing almost nothing.
+/// 
+/// # Stochastic gradient boosting
+/// `GBM` can train each tree on a random part of the sample:
+/// - [`GBM::subsample`] draws a fraction of the examples
+///   without replacement in each round (Friedman, 2002),
+/// - [`GBM::colsample`] draws a fraction of the features
+///   for each tree,
+/// - [`GBM::goss`] keeps the examples with large gradients
+///   and draws a fraction of the rest,
+///   whose weights are amplified to keep the gradient sum unbiased.
+///   This is the Gradient-based One-Side Sampling of LightGBM
+///   (Ke et al., 2017).
+/// 
+/// The randomness is controlled by [`GBM::seed`].
+/// The same seed also controls the dropout of DART.
+/// The weak learner must accept a sample
+/// with fewer examples and features than the one given to `GBM`,
+/// and it should respect the weights set by GOSS.
+/// [`RegressionTree`] does both.
 /// 
 /// 
 /// # Example
@@ -32,11 +153,14 @@ use std::ops::ControlFlow;
 /// for running [`GBM`].  
 /// See also:
 /// - [`Regressor`]
+/// - [`BaseScoreRegressor`]
 /// - [`WeightedMajority<F>`]
 /// 
 /// [`RegressionTree`]: crate::weak_learner::RegressionTree
+/// [`RegressionTreeBuilder::lambda_l2`]: crate::weak_learner::RegressionTreeBuilder::lambda_l2
 /// [`RegressionTreeRegressor`]: crate::weak_learner::RegressionTreeRegressor
 /// [`WeightedMajority<F>`]: crate::hypothesis::WeightedMajority
+/// [`BaseScoreRegressor`]: crate::hypothesis::BaseScoreRegressor
 /// 
 /// 
 /// ```no_run
@@ -116,6 +240,54 @@ pub struct GBM<'a, F, L> {
 
     // A prediction vector at a state.
     predictions: Vec<f64>,
+
+
+    // Learning rate (shrinkage parameter).
+    learning_rate: f64,
+
+    // Base score specified by the user.
+    // If `None`, `GBM` uses `LossFunction::base_score`.
+    base_score: Option<f64>,
+
+    // Base score used in the current run.
+    bias: f64,
+
+
+    // Validation sample and the number of rounds
+    // without improvement until `GBM` stops.
+    validation: Option<(&'a Sample, usize)>,
+
+    // A prediction vector for the validation sample.
+    valid_predictions: Vec<f64>,
+
+    // The smallest validation loss so far,
+    // the weights on the hypotheses at that point,
+    // and the number of rounds since then.
+    best_loss: f64,
+    best_weights: Vec<f64>,
+    stall: usize,
+
+
+    // Fraction of the examples used in each round.
+    subsample: f64,
+
+    // Fraction of the features used for each tree.
+    colsample: f64,
+
+    // If `true`, each tree is added without the line search.
+    newton: bool,
+
+
+    // Parameters `(drop_rate, skip_drop)` of DART.
+    dart: Option<(f64, f64)>,
+
+
+    // Parameters `(top_rate, other_rate)` of GOSS.
+    goss: Option<(f64, f64)>,
+
+    // Seed and random number generator for the sampling.
+    seed: u64,
+    rng: StdRng,
 }
 
 
@@ -130,6 +302,8 @@ impl<'a, F, L> GBM<'a, F, L>
         let n_sample = sample.shape().0;
         let predictions = vec![0.0; n_sample];
 
+        let seed = 1234;
+
         Self {
             sample,
             tolerance: 0.0,
@@ -144,12 +318,33 @@ impl<'a, F, L> GBM<'a, F, L>
             terminated: usize::MAX,
 
             predictions,
+
+            learning_rate: 1.0,
+            base_score: None,
+            bias: 0.0,
+
+            validation: None,
+            valid_predictions: Vec::new(),
+            best_loss: f64::MAX,
+            best_weights: Vec::new(),
+            stall: 0,
+
+            subsample: 1.0,
+            colsample: 1.0,
+            goss: None,
+
+            newton: false,
+
+            dart: None,
+
+            seed,
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 }
 
 
-impl<F, L> GBM<'_, F, L> {
+impl<'a, F, L> GBM<'a, F, L> {
     /// Returns the maximum iteration
     /// of the `GBM` to find a combined hypothesis
     /// that has error at most `tolerance`.
@@ -173,6 +368,255 @@ impl<F, L> GBM<'_, F, L> {
         self.loss = loss_type;
         self
     }
+
+
+    /// Set the learning rate (shrinkage parameter) `η`.
+    /// `GBM` multiplies the coefficient of each tree by `η`.
+    /// Default value is `1.0`.
+    pub fn learning_rate(mut self, learning_rate: f64) -> Self {
+        assert!(
+            0.0 < learning_rate && learning_rate <= 1.0,
+            "The learning rate must be in (0.0, 1.0]"
+        );
+        self.learning_rate = learning_rate;
+        self
+    }
+
+
+    /// Set the base score, the initial prediction for all the examples.
+    /// By default, `GBM` uses [`LossFunction::base_score`].
+    /// Set `0.0` to start from zero.
+    pub fn base_score(mut self, base_score: f64) -> Self {
+        self.base_score = Some(base_score);
+        self
+    }
+
+
+    /// Stop the boosting if the loss on the validation sample `valid`
+    /// does not improve for `patience` consecutive rounds.
+    /// The output is the ensemble with the smallest validation loss.
+    pub fn early_stopping(mut self, valid: &'a Sample, patience: usize)
+        -> Self
+    {
+        assert!(patience > 0, "`patience` must be positive");
+        self.validation = Some((valid, patience));
+        self
+    }
+
+
+    /// Use the second-order (Newton) boosting.
+    /// If `true`, `GBM` adds each tree with coefficient `1`
+    /// instead of the one found by [`LossFunction::line_search`].
+    /// This flag is ignored for the losses
+    /// whose [`LossFunction::has_hessian`] is `false`.
+    /// See the section **Newton boosting** of [`GBM`].
+    /// Default value is `false`.
+    pub fn newton(mut self, newton: bool) -> Self {
+        self.newton = newton;
+        self
+    }
+
+
+    /// Use DART, the dropout for the trees.
+    /// In each round, `GBM` skips the dropout with probability `skip_drop`.
+    /// Otherwise, `GBM` drops each tree with probability `drop_rate`,
+    /// and at least one tree is dropped.
+    /// See the section **DART** of [`GBM`].
+    pub fn dart(mut self, drop_rate: f64, skip_drop: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&drop_rate)
+                && (0.0..=1.0).contains(&skip_drop),
+            "`drop_rate` and `skip_drop` must be in [0.0, 1.0]"
+        );
+        self.dart = Some((drop_rate, skip_drop));
+        self
+    }
+
+
+    /// Set the fraction of the examples
+    /// that the weak learner receives in each round.
+    /// The examples are drawn uniformly without replacement.
+    /// This option is ignored if [`GBM::goss`] is specified.
+    /// Default value is `1.0`.
+    pub fn subsample(mut self, ratio: f64) -> Self {
+        assert!(
+            0.0 < ratio && ratio <= 1.0,
+            "The subsampling ratio must be in (0.0, 1.0]"
+        );
+        self.subsample = ratio;
+        self
+    }
+
+
+    /// Set the fraction of the features used for each tree.
+    /// At least one feature is used.
+    /// Default value is `1.0`.
+    pub fn colsample(mut self, ratio: f64) -> Self {
+        assert!(
+            0.0 < ratio && ratio <= 1.0,
+            "The column sampling ratio must be in (0.0, 1.0]"
+        );
+        self.colsample = ratio;
+        self
+    }
+
+
+    /// Use the Gradient-based One-Side Sampling (GOSS).
+    /// In each round, `GBM` keeps the `top_rate` fraction of the examples
+    /// with the largest absolute gradients
+    /// and draws the `other_rate` fraction of the examples
+    /// from the rest.
+    /// The weights of the drawn examples are multiplied by
+    /// `(1 - top_rate) / other_rate`.
+    pub fn goss(mut self, top_rate: f64, other_rate: f64) -> Self {
+        assert!(
+            top_rate >= 0.0 && other_rate > 0.0
+                && top_rate + other_rate <= 1.0,
+            "GOSS requires `top_rate >= 0`, `other_rate > 0`, \
+            and `top_rate + other_rate <= 1`"
+        );
+        self.goss = Some((top_rate, other_rate));
+        self
+    }
+
+
+    /// Set the seed of the randomness for the sampling.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+
+impl<F, L> GBM<'_, F, L>
+    where L: LossFunction,
+{
+    /// Returns `true` if `GBM` takes the Newton steps.
+    #[inline]
+    fn is_newton(&self) -> bool {
+        self.newton && self.loss.has_hessian()
+    }
+
+
+    /// Returns the indices of the trees dropped in this round.
+    /// The returned vector is empty if DART is not specified.
+    fn drop_trees(&mut self) -> Vec<usize> {
+        let Some((drop_rate, skip_drop)) = self.dart else {
+            return Vec::new();
+        };
+
+        let n_trees = self.hypotheses.len();
+        if n_trees == 0 || self.rng.gen::<f64>() < skip_drop {
+            return Vec::new();
+        }
+
+        let mut dropped = (0..n_trees)
+            .filter(|_| self.rng.gen::<f64>() < drop_rate)
+            .collect::<Vec<_>>();
+        if dropped.is_empty() {
+            dropped.push(self.rng.gen_range(0..n_trees));
+        }
+        dropped
+    }
+
+
+    /// Returns the sub-sample given to the weak learner in this round
+    /// and the indices of its examples in `self.sample`.
+    /// Returns `None` if `GBM` uses the full sample.
+    fn draw_sample(&mut self, predictions: &[f64])
+        -> Option<(Sample, Vec<usize>)>
+    {
+        let (n_sample, n_feature) = self.sample.shape();
+
+        if self.goss.is_none()
+            && self.subsample >= 1.0
+            && self.colsample >= 1.0
+        {
+            return None;
+        }
+
+
+        let mut weight = Vec::new();
+        let ix = match self.goss {
+            Some((top_rate, other_rate)) => {
+                let (ix, w) = self.goss_indices(
+                    predictions, top_rate, other_rate
+                );
+                weight = w;
+                ix
+            },
+            None => {
+                let size = sample_size(n_sample, self.subsample);
+                let mut ix = index::sample(&mut self.rng, n_sample, size)
+                    .into_vec();
+                ix.sort_unstable();
+                ix
+            },
+        };
+
+        let mut sub_sample = self.sample.subsample(&ix);
+        if !weight.is_empty() {
+            sub_sample.set_weight(weight);
+        }
+
+        if self.colsample < 1.0 {
+            let size = sample_size(n_feature, self.colsample);
+            let mut cols = index::sample(&mut self.rng, n_feature, size)
+                .into_vec();
+            cols.sort_unstable();
+            sub_sample.retain_features(cols);
+        }
+
+        Some((sub_sample, ix))
+    }
+
+
+    /// Returns the indices of the examples drawn by GOSS
+    /// and the weights on them.
+    fn goss_indices(
+        &mut self,
+        predictions: &[f64],
+        top_rate: f64,
+        other_rate: f64,
+    ) -> (Vec<usize>, Vec<f64>)
+    {
+        let n_sample = self.sample.shape().0;
+        let target = self.sample.target();
+        let group = self.sample.group();
+        let gradient = self.loss.gradient_grouped(predictions, target, group);
+
+        let mut order = (0..n_sample).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| {
+            gradient[j].abs().partial_cmp(&gradient[i].abs()).unwrap()
+        });
+
+        let n_top = (top_rate * n_sample as f64).round() as usize;
+        let n_rest = n_sample - n_top;
+        let n_other = sample_size(n_sample, other_rate).min(n_rest);
+        let amplifier = (1.0 - top_rate) / other_rate;
+
+        let mut items = order[..n_top].iter()
+            .map(|&i| (i, 1.0))
+            .collect::<Vec<_>>();
+        let rest = &order[n_top..];
+        items.extend(
+            index::sample(&mut self.rng, n_rest, n_other)
+                .into_iter()
+                .map(|k| (rest[k], amplifier))
+        );
+        items.sort_unstable_by_key(|(i, _)| *i);
+
+        items.into_iter().unzip()
+    }
+}
+
+
+/// Returns the number of items drawn from `n` items with ratio `ratio`.
+/// At least one item is drawn.
+#[inline(always)]
+fn sample_size(n: usize, ratio: f64) -> usize {
+    ((ratio * n as f64).round() as usize).clamp(1, n)
 }
 
 
@@ -180,7 +624,7 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
     where F: Regressor + Clone,
           L: LossFunction,
 {
-    type Output = WeightedMajority<F>;
+    type Output = BaseScoreRegressor<WeightedMajority<F>>;
 
 
     fn name(&self) -> &str {
@@ -195,7 +639,12 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
             ("# of features", format!("{n_feature}")),
             ("Tolerance", format!("{}", self.tolerance)),
             ("Loss", self.loss.name().to_string()),
+            ("Step", (if self.is_newton() { "Newton" } else { "Line search" }).to_string()),
             ("Max iteration", format!("{}", self.max_iter)),
+            ("Learning rate", format!("{}", self.learning_rate)),
+            ("Subsample", format!("{}", self.subsample)),
+            ("Colsample", format!("{}", self.colsample)),
+            ("DART", format!("{:?}", self.dart)),
         ]);
         Some(info)
     }
@@ -215,7 +664,27 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
 
 
         self.terminated = self.max_iter;
-        self.predictions = vec![0.0; n_sample];
+
+        self.loss.check_target(self.sample.target());
+        if let Some((valid, _)) = self.validation {
+            self.loss.check_target(valid.target());
+        }
+
+        self.bias = self.base_score
+            .unwrap_or_else(|| self.loss.base_score(self.sample.target()));
+        self.predictions = vec![self.bias; n_sample];
+
+        if let Some((valid, _)) = self.validation {
+            let n_valid = valid.shape().0;
+            self.valid_predictions = vec![self.bias; n_valid];
+            self.best_loss = self.loss.eval_grouped(
+                &self.valid_predictions[..], valid.target(), valid.group()
+            );
+            self.best_weights = Vec::new();
+            self.stall = 0;
+        }
+
+        self.rng = StdRng::seed_from_u64(self.seed);
     }
 
 
@@ -231,18 +700,97 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
         }
 
 
-        // Get a new hypothesis
-        let h = weak_learner.produce(self.sample, &self.predictions[..]);
+        // Drop some trees from the current ensemble for DART.
+        // `base` is the predictions of the remaining ensemble.
+        let dropped = self.drop_trees();
+        let dropped_predictions = dropped.iter()
+            .map(|&j| self.hypotheses[j].predict_all(self.sample))
+            .collect::<Vec<_>>();
+        let dropped_valid_predictions = match self.validation {
+            Some((valid, _)) => {
+                dropped.iter()
+                    .map(|&j| self.hypotheses[j].predict_all(valid))
+                    .collect::<Vec<_>>()
+            },
+            None => Vec::new(),
+        };
+        let mut base = mem::take(&mut self.predictions);
+        for (&j, q) in dropped.iter().zip(&dropped_predictions) {
+            let w = self.weights[j];
+            base.par_iter_mut()
+                .zip(q)
+                .for_each(|(p, q)| { *p -= w * q; });
+        }
+
+
+        // Get a new hypothesis.
+        // If the sampling options are specified,
+        // the weak learner receives a part of the sample.
+        let sub_sample = self.draw_sample(&base[..]);
+        let sampled = sub_sample.is_some();
+        let h = match sub_sample {
+            Some((sub_sample, ix)) => {
+                let sub_predictions = ix.iter()
+                    .map(|&i| base[i])
+                    .collect::<Vec<_>>();
+                weak_learner.produce(&sub_sample, &sub_predictions[..])
+            },
+            None => weak_learner.produce(self.sample, &base[..]),
+        };
 
         let predictions = h.predict_all(self.sample);
-        let coef = self.loss.best_coefficient(
-            self.sample.target(), &predictions[..]
-        );
+        let coef = if self.is_newton() {
+            // The leaves of the regression tree
+            // already take a Newton step.
+            // If `h` predicts zero for all the examples,
+            // `h` does nothing.
+            if predictions.iter().all(|&p| p == 0.0) { 0.0 } else { 1.0 }
+        } else {
+            // A negative coefficient flips the tree,
+            // which breaks the monotone constraints.
+            // Since `h` is fitted to the negative gradient,
+            // such a tree does not decrease the loss.
+            self.loss.line_search_grouped(
+                &base[..],
+                &predictions[..],
+                self.sample.target(),
+                self.sample.group(),
+            ).max(0.0)
+        };
+
+
+        let coef = self.learning_rate * coef;
+
+
+        // Normalize the weights of the new tree and the dropped trees.
+        // If no tree is dropped, the new tree keeps the coefficient.
+        let k = dropped.len() as f64;
+        let dropped_scale = if coef == 0.0 { 1.0 } else { k / (k + 1.0) };
+        let coef = coef / (k + 1.0);
+        for (i, (&j, q)) in dropped.iter().zip(&dropped_predictions).enumerate() {
+            let old = self.weights[j];
+            self.weights[j] *= dropped_scale;
+            let w = self.weights[j];
+            base.par_iter_mut()
+                .zip(q)
+                .for_each(|(p, q)| { *p += w * q; });
+
+            if let Some(q) = dropped_valid_predictions.get(i) {
+                self.valid_predictions.par_iter_mut()
+                    .zip(q)
+                    .for_each(|(p, q)| { *p += (w - old) * q; });
+            }
+        }
+        self.predictions = base;
+
 
         // If the best coefficient is zero,
         // the newly-attained hypothesis `h` do nothing.
         // Thus, we can terminate the boosting at this point.
+        // If `h` is fitted to a random subsample,
+        // we discard `h` and draw another subsample.
         if coef == 0.0 {
+            if sampled { return ControlFlow::Continue(()); }
             self.terminated = iteration;
             return ControlFlow::Break(iteration);
         }
@@ -256,6 +804,30 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
             .zip(predictions)
             .for_each(|(p, q)| { *p += coef * q; });
 
+
+        // Early stopping by the validation loss.
+        if let Some((valid, patience)) = self.validation {
+            let h = &self.hypotheses[self.hypotheses.len() - 1];
+            self.valid_predictions.par_iter_mut()
+                .zip(h.predict_all(valid))
+                .for_each(|(p, q)| { *p += coef * q; });
+
+            let loss = self.loss.eval_grouped(
+                &self.valid_predictions[..], valid.target(), valid.group()
+            );
+            if loss < self.best_loss {
+                self.best_loss = loss;
+                self.best_weights = self.weights.clone();
+                self.stall = 0;
+            } else {
+                self.stall += 1;
+                if self.stall >= patience {
+                    self.terminated = iteration;
+                    return ControlFlow::Break(iteration);
+                }
+            }
+        }
+
         ControlFlow::Continue(())
     }
 
@@ -266,7 +838,18 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
     ) -> Self::Output
         where W: WeakLearner<Hypothesis = F>
     {
-        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+        // If the validation sample is given,
+        // returns the ensemble with the smallest validation loss.
+        let weights = match self.validation {
+            Some(_) => self.best_weights.clone(),
+            None => self.weights.clone(),
+        };
+        let hypotheses = self.hypotheses[..weights.len()].to_vec();
+
+        // `WeightedMajority::from_slices` normalizes the weights,
+        // which changes the scale of the regression output.
+        let f = WeightedMajority { weights, hypotheses, };
+        BaseScoreRegressor::new(self.bias, f)
     }
 }
 
@@ -0,0 +1,395 @@
+//! Provides the multi-class Gradient Boosting Machine ([`MultiClassGBM`]).
+use rayon::prelude::*;
+
+use crate::{
+    common::loss_functions::*,
+    common::task::Task,
+    Sample,
+    Booster,
+    WeakLearner,
+    Regressor,
+    WeightedMajority,
+    BaseScoreRegressor,
+    SoftmaxClassifier,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The Gradient Boosting Machine for multi-class classification,
+/// the `K`-class logistic gradient boosting in the following paper:
+///
+/// [Jerome H. Friedman, 2001 - Greedy Function Approximation: A Gradient Boosting Machine](https://projecteuclid.org/journals/annals-of-statistics/volume-29/issue-5/Greedy-function-approximation-A-gradient-boostingmachine/10.1214/aos/1013203451.full)
+///
+/// The labels are assumed to be `0`, `1`, ..., `K-1`.
+/// `MultiClassGBM` keeps a score `F_k(x)` for each class `k`
+/// and minimizes a [`MultiClassLossFunction`],
+/// typically [`SoftmaxLoss`].
+/// In each round, `MultiClassGBM` fits one regressor per class,
+/// so that it obtains `K` regressors per round.
+///
+/// # Working responses and weights
+/// Let `g_k(x)` and `h_k(x)` be the gradient and the (diagonal) Hessian
+/// of the loss w.r.t. `F_k(x)`.
+/// As in [`LogitBoost`](crate::booster::LogitBoost),
+/// the regressor for the class `k` is fitted to
+/// the **working responses** `z = -g_k(x) / h_k(x)`
+/// by the weighted least squares with the **weights** `w = h_k(x)`.
+/// Thus, the weak learner receives a copy of the training sample
+/// whose target values are the working responses,
+/// together with the zero predictions.
+/// `MultiClassGBM` copies the training sample once per round
+/// and overwrites the responses and the weights for each class.
+/// The weights are clipped from below by a tiny positive value
+/// to avoid the division by zero.
+/// [`RegressionTree`] with [`GBMLoss::L2`] is such a weak learner,
+/// since its leaf value `-G / (H + λ)` becomes
+/// `sum( w z ) / ( sum( w ) + λ )`,
+/// the Newton step on the class `k`.
+///
+/// The scores start from [`MultiClassLossFunction::base_score`],
+/// and each round adds the `K` regressors
+/// multiplied by the learning rate (see [`MultiClassGBM::learning_rate`]).
+/// `MultiClassGBM` terminates
+/// if the decrease of the training loss in a round
+/// is at most the tolerance parameter.
+///
+/// The output of `MultiClassGBM` is a [`SoftmaxClassifier`]
+/// that returns the class probabilities.
+///
+/// [`RegressionTree`]: crate::weak_learner::RegressionTree
+///
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// // The labels should be `0, 1, ..., K-1`.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Initialize `MultiClassGBM` with the softmax cross-entropy loss.
+/// let mut booster = MultiClassGBM::init_with_loss(&sample, SoftmaxLoss)
+///     .learning_rate(0.3)
+///     .force_quit_at(100);
+///
+/// // Set the weak learner with setting parameters.
+/// // The loss function of the weak learner must be `GBMLoss::L2`.
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(3)
+///     .loss(GBMLoss::L2)
+///     .build();
+///
+/// // Run `MultiClassGBM` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+///
+/// // Get the class probabilities and the predicted classes.
+/// let probabilities = f.probability_all(&sample);
+/// let predictions = f.predict_class_all(&sample);
+///
+/// // Calculate the training loss.
+/// let n_sample = sample.shape().0 as f64;
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as usize == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+///
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct MultiClassGBM<'a, F, L> {
+    // Training data
+    sample: &'a Sample,
+
+    // The task, `Task::MultiClass(K)`.
+    // The number of classes `K` is determined in `preprocess`.
+    task: Task,
+
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on the rounds.
+    // The `K` hypotheses obtained in a round share the weight.
+    weights: Vec<f64>,
+
+    // `hypotheses[k]` is the hypotheses for the class `k`.
+    hypotheses: Vec<Vec<F>>,
+
+
+    // Some struct that implements `MultiClassLossFunction` trait
+    loss: L,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+
+    // `predictions[k][i]` is the current score
+    // of the class `k` for the `i`th example.
+    predictions: Vec<Vec<f64>>,
+
+
+    // Learning rate (shrinkage parameter).
+    learning_rate: f64,
+
+    // Base scores of the classes used in the current run.
+    bias: Vec<f64>,
+}
+
+
+impl<'a, F, L> MultiClassGBM<'a, F, L> {
+    /// Initialize the `MultiClassGBM`.
+    /// By default, `MultiClassGBM` runs at most `100` rounds
+    /// with the learning rate `1.0`.
+    pub fn init_with_loss(sample: &'a Sample, loss: L) -> Self {
+        Self {
+            sample,
+            task: Task::MultiClass(0),
+            tolerance: 0.0,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            loss,
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            predictions: Vec::new(),
+
+            learning_rate: 1.0,
+            bias: Vec::new(),
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `MultiClassGBM` terminates if the decrease of the training loss
+    /// in a round is at most `tolerance`.
+    /// Default value is `0.0`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the Loss Type.
+    pub fn loss(mut self, loss_type: L) -> Self {
+        self.loss = loss_type;
+        self
+    }
+
+
+    /// Set the learning rate (shrinkage parameter) `eta`.
+    /// The regressors of each round are multiplied by `eta`.
+    /// Default value is `1.0`.
+    pub fn learning_rate(mut self, learning_rate: f64) -> Self {
+        assert!(
+            0.0 < learning_rate && learning_rate <= 1.0,
+            "The learning rate must be in (0, 1]"
+        );
+        self.learning_rate = learning_rate;
+        self
+    }
+
+
+    /// Returns the number of classes `K`.
+    /// This value is `0` before calling `Booster::preprocess`.
+    fn n_class(&self) -> usize {
+        match self.task {
+            Task::MultiClass(k) => k,
+            _ => unreachable!(),
+        }
+    }
+
+
+}
+
+
+/// Sets the working responses `-g / w` and the weights `w = max(h, ε)`
+/// to `sample`.
+/// Since the response and the weight share the clipping,
+/// `w * (-g / w) = -g` holds for every example.
+fn set_working_response(sample: &mut Sample, gradient: &[f64], hessian: &[f64]) {
+    const MIN_WEIGHT: f64 = 1e-12;
+
+    let weight = hessian.iter()
+        .map(|h| h.max(MIN_WEIGHT))
+        .collect::<Vec<_>>();
+    let response = gradient.iter()
+        .zip(&weight[..])
+        .map(|(g, w)| - g / w)
+        .collect::<Vec<_>>();
+
+    sample.replace_target(response);
+    sample.set_weight(weight);
+}
+
+
+impl<F, L> MultiClassGBM<'_, F, L>
+    where F: Clone,
+{
+    /// Returns the current combined hypothesis.
+    fn combined_hypothesis(&self)
+        -> SoftmaxClassifier<BaseScoreRegressor<WeightedMajority<F>>>
+    {
+        let regressors = self.hypotheses.iter()
+            .zip(&self.bias[..])
+            .map(|(hypotheses, &bias)| {
+                let f = WeightedMajority {
+                    weights: self.weights.clone(),
+                    hypotheses: hypotheses.clone(),
+                };
+                BaseScoreRegressor::new(bias, f)
+            })
+            .collect::<Vec<_>>();
+        SoftmaxClassifier::new(regressors)
+    }
+}
+
+
+impl<F, L> Booster<F> for MultiClassGBM<'_, F, L>
+    where F: Regressor + Clone,
+          L: MultiClassLossFunction,
+{
+    type Output = SoftmaxClassifier<BaseScoreRegressor<WeightedMajority<F>>>;
+
+
+    fn name(&self) -> &str {
+        "Multi-class Gradient Boosting Machine"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Task", format!("{}", self.task)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Loss", self.loss.name().to_string()),
+            ("Max iteration", format!("{}", self.max_iter)),
+            ("Learning rate", format!("{}", self.learning_rate)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_class = self.sample.is_valid_multiclass_instance();
+        self.task = Task::MultiClass(n_class);
+
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::with_capacity(self.max_iter);
+        self.hypotheses = vec![Vec::with_capacity(self.max_iter); n_class];
+
+        self.terminated = self.max_iter;
+
+        self.bias = self.loss.base_score(self.sample.target(), n_class);
+        self.predictions = self.bias.iter()
+            .map(|&b| vec![b; n_sample])
+            .collect();
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        let target = self.sample.target();
+        let n_sample = self.sample.shape().0;
+        let gradient = self.loss.gradient(&self.predictions[..], target);
+        let hessian = self.loss.hessian(&self.predictions[..], target);
+
+
+        // Get a new hypothesis for each class.
+        let zeros = vec![0.0; n_sample];
+        let coef = self.learning_rate;
+        let mut new_predictions = self.predictions.clone();
+        let mut hypotheses = Vec::with_capacity(self.n_class());
+        let mut sample = self.sample.clone();
+        for (k, (g, h)) in gradient.iter().zip(&hessian).enumerate() {
+            set_working_response(&mut sample, &g[..], &h[..]);
+            let f = weak_learner.produce(&sample, &zeros[..]);
+
+            new_predictions[k].par_iter_mut()
+                .zip(f.predict_all(self.sample))
+                .for_each(|(p, q)| { *p += coef * q; });
+            hypotheses.push(f);
+        }
+
+
+        let old_loss = self.loss.eval(&self.predictions[..], target);
+        let new_loss = self.loss.eval(&new_predictions[..], target);
+
+
+        // If the new hypotheses increase the training loss,
+        // discard them and terminate the boosting.
+        if new_loss > old_loss {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        self.weights.push(coef);
+        self.hypotheses.iter_mut()
+            .zip(hypotheses)
+            .for_each(|(hs, f)| { hs.push(f); });
+        self.predictions = new_predictions;
+
+
+        // If the new hypotheses do not decrease the training loss
+        // sufficiently, terminate the boosting.
+        if old_loss - new_loss <= self.tolerance {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.combined_hypothesis()
+    }
+}
@@ -0,0 +1,12 @@
+//! The LogitBoost algorithm proposed
+//! by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+//! This algorithm is based on the paper:
+//! [Additive logistic regression: a statistical view of boosting](https://projecteuclid.org/journals/annals-of-statistics/volume-28/issue-2/Additive-logistic-regression--a-statistical-view-of-boosting-With/10.1214/aos/1016218223.full)
+//! by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+//! 
+//! LogitBoost is a boosting algorithm for binary classification
+//! that minimizes the logistic loss by Newton steps.
+//!
+pub mod logitboost_algorithm;
+
+pub use logitboost_algorithm::LogitBoost;
@@ -0,0 +1,301 @@
+//! Provides [`LogitBoost`] by Friedman, Hastie, and Tibshirani, 2000.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Regressor,
+    WeightedMajority,
+    LogisticClassifier,
+    Sample,
+
+    common::loss_functions::{GBMLoss, LossFunction},
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The LogitBoost algorithm
+/// proposed by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+/// 
+/// This struct is based on the paper: 
+///
+/// [
+/// Additive logistic regression: a statistical view of boosting
+/// ](https://projecteuclid.org/journals/annals-of-statistics/volume-28/issue-2/Additive-logistic-regression--a-statistical-view-of-boosting-With/10.1214/aos/1016218223.full)  
+/// by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+/// 
+/// LogitBoost is a boosting algorithm for binary classification
+/// that minimizes the logistic loss
+/// `ln( 1 + exp(-y F(x)) )`
+/// instead of the exponential loss minimized by
+/// [`AdaBoost`](crate::booster::AdaBoost) and
+/// [`MadaBoost`](crate::booster::MadaBoost).
+/// The logistic loss grows linearly in the negative margin,
+/// so `LogitBoost` is more robust to the label noise than `AdaBoost`.
+///
+/// # Working responses and weights
+/// Let `p(x)` be the current estimate of `P(y = +1 | x)`
+/// and `y* = (y + 1) / 2`.
+/// In each round, `LogitBoost` fits a regressor `f` to
+/// the **working responses** `z = (y* - p(x)) / (p(x) (1 - p(x)))`
+/// by the weighted least squares
+/// with the **weights** `w = p(x) (1 - p(x))`,
+/// and then updates the log-odds as `F(x) <- F(x) + f(x)`.
+///
+/// Here, `LogitBoost` follows the same protocol as
+/// [`GBM`](crate::booster::GBM):
+/// the weak learner receives the current log-odds `F(x)` as `dist`.
+/// A weak learner that takes a Newton step
+/// on [`GBMLoss::Logistic`] yields the above weighted least squares fit,
+/// since the Newton step `-G / H` on each leaf equals
+/// `sum( w z ) / sum( w )` over the examples in the leaf.
+/// [`RegressionTree`](crate::weak_learner::RegressionTree)
+/// with `GBMLoss::Logistic` is such a weak learner.
+/// The L2-regularization of `RegressionTree` plays the role
+/// of the threshold on `z` in the original paper.
+///
+/// The output of `LogitBoost` is a [`LogisticClassifier`]
+/// that returns the probability of the label `+1`.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `LogitBoost` and set the maximal number of rounds.
+/// let mut booster = LogitBoost::init(&sample)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// // The loss function must be `GBMLoss::Logistic`.
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::Logistic)
+///     .build();
+/// 
+/// // Run `LogitBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// 
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+/// 
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as i64 == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct LogitBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+
+
+    // Current log-odds `F(x)` for each training example.
+    predictions: Vec<f64>,
+}
+
+
+impl<'a, F> LogitBoost<'a, F> {
+    /// Constructs a new instance of `LogitBoost`.
+    /// By default, `LogitBoost` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            tolerance: 0.0,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            predictions: Vec::new(),
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `LogitBoost` terminates if the decrease of the logistic loss
+    /// in a round is at most `tolerance`.
+    /// Default value is `0.0`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Returns the current combined hypothesis.
+    #[inline]
+    fn combined_hypothesis(&self) -> LogisticClassifier<WeightedMajority<F>>
+        where F: Clone,
+    {
+        let f = WeightedMajority {
+            weights: self.weights.clone(),
+            hypotheses: self.hypotheses.clone(),
+        };
+        LogisticClassifier::new(f)
+    }
+}
+
+
+impl<F> Booster<F> for LogitBoost<'_, F>
+    where F: Regressor + Clone,
+{
+    type Output = LogisticClassifier<WeightedMajority<F>>;
+
+
+    fn name(&self) -> &str {
+        "LogitBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        self.predictions = vec![0.0; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis that fits the working responses.
+        let h = weak_learner.produce(self.sample, &self.predictions[..]);
+        let predictions = h.predict_all(self.sample);
+
+
+        let target = self.sample.target();
+        let old_loss = GBMLoss::Logistic.eval(&self.predictions[..], target);
+        let new_predictions = self.predictions.par_iter()
+            .zip(predictions)
+            .map(|(p, q)| p + q)
+            .collect::<Vec<_>>();
+        let new_loss = GBMLoss::Logistic.eval(&new_predictions[..], target);
+
+
+        // If the new hypothesis increases the logistic loss,
+        // discard it and terminate the boosting.
+        if new_loss > old_loss {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        self.weights.push(1.0);
+        self.hypotheses.push(h);
+        self.predictions = new_predictions;
+
+
+        // If the new hypothesis does not decrease the logistic loss
+        // sufficiently, terminate the boosting.
+        if old_loss - new_loss <= self.tolerance {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.combined_hypothesis()
+    }
+}
+
+
+impl<H> Research for LogitBoost<'_, H>
+    where H: Regressor + Clone,
+{
+    type Output = LogisticClassifier<WeightedMajority<H>>;
+    fn current_hypothesis(&self) -> Self::Output {
+        self.combined_hypothesis()
+    }
+}
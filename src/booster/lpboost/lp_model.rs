@@ -6,6 +6,7 @@ use clarabel::{
 use crate::{
     Sample,
     common::utils,
+    common::active_set::{ActiveSet, ZERO_WEIGHT},
 };
 use crate::hypothesis::Classifier;
 
@@ -56,17 +57,30 @@ use crate::hypothesis::Classifier;
 ///
 /// Since the `clarabel` crate solves the minimization problems,
 /// we need to negate the objective function.
+///
+/// # Active set
+/// The matrix above only contains the active columns `w_j`
+/// and the active margin constraints (with the variables `ξ_i`).
+/// - A hypothesis (column) with zero weight for `max_column_age`
+///   consecutive solves is dropped.
+///   Columns are dropped only after the optimal value strictly increases
+///   so that the column generation does not cycle.
+/// - A margin constraint with zero dual weight `d_i`
+///   for `max_row_age` consecutive solves is dropped.
+///   After each solve, the dropped constraints violated by
+///   the current solution are restored and the LP is solved again.
+///   Thus, the solution is optimal for the LP with all the constraints.
+///
+/// Since the dropped columns have zero weights in the current solution,
+/// the optimal value never decreases.
 pub(super) struct LPModel {
-    // -----
-    // clarabel settings
-    pub(self) lin_obj: Vec<f64>,        // LP objective
-    pub(self) nonzero: Vec<f64>,        // non-zero values in constraint matrix
-    pub(self) col_ptr: Vec<usize>,      // column pointer
-    pub(self) row_val: Vec<usize>,      // row value
-    // End of clarabel setting
-    // -----
-    pub(self) n_examples: usize,        // number of columns
-    pub(self) n_hypotheses: usize,      // number of rows
+    pub(self) n_examples: usize,        // number of examples
+    pub(self) upper_bound: f64,         // capping parameter
+    pub(self) margins: Vec<Vec<f64>>,   // margin vector of each hypothesis
+    pub(self) columns: ActiveSet,       // active hypotheses
+    pub(self) rows: ActiveSet,          // active margin constraints
+    pub(self) optimal_value: f64,       // optimal value at the last solve
+    pub(self) rho: f64,                 // margin `ρ` at the last solve
     pub(self) weights: Vec<f64>,        // weight on hypothesis
     pub(self) dist: Vec<f64>,           // distribution over examples
 }
@@ -78,86 +92,142 @@ impl LPModel {
     /// - `size`: Number of variables (Number of examples).
     /// - `upper_bound`: Capping parameter. `[1, size]`.
     pub(super) fn init(size: usize, upper_bound: f64) -> Self {
-        let n_examples = size;
+        Self {
+            n_examples:    size,
+            upper_bound,
+            margins:       Vec::new(),
+            columns:       ActiveSet::new(None),
+            rows:          ActiveSet::with_size(None, size),
+            optimal_value: f64::MIN,
+            rho:           0f64,
+            weights:       Vec::with_capacity(0usize),
+            dist:          Vec::with_capacity(0usize),
+        }
+    }
+
+
+    /// Set the maximal ages of the columns and the margin constraints.
+    /// `None` means that the columns (constraints) are never dropped.
+    pub(super) fn active_set(
+        &mut self,
+        max_column_age: Option<usize>,
+        max_row_age: Option<usize>,
+    )
+    {
+        self.columns = ActiveSet::new(max_column_age);
+        self.rows = ActiveSet::with_size(max_row_age, self.n_examples);
+    }
+
+
+    /// Solve the edge minimization problem 
+    /// over the hypotheses `h1, ..., ht` 
+    /// and outputs the optimal value.
+    pub(super) fn update<F>(
+        &mut self,
+        sample: &Sample,
+        clf: &F
+    ) -> f64
+        where F: Classifier
+    {
+        self.margins.push(utils::margins_of_hypothesis(sample, clf));
+        self.columns.push();
+
+        let mut objval = self.solve();
+        while self.restore_violated_rows() {
+            objval = self.solve();
+        }
+
+
+        let wsum = self.weights.iter().sum::<f64>();
+        if (wsum - 1f64).abs() > 1e-6 {
+            eprintln!(
+                "[WRN] weight sum on hypotheses far from 1. sum is: {wsum}"
+            );
+        }
+        let dsum = self.dist.iter().sum::<f64>();
+        if (dsum - 1f64).abs() > 1e-6 {
+            eprintln!(
+                "[WRN] dist sum on examples far from 1. sum is: {dsum}"
+            );
+        }
+
+
+        // Update the active set.
+        self.columns.grow_older(&self.weights[..]);
+        self.rows.grow_older(&self.dist[..]);
+        if objval > self.optimal_value + ZERO_WEIGHT {
+            self.columns.drop_aged();
+        }
+        self.rows.drop_aged();
+        self.optimal_value = self.optimal_value.max(objval);
+
+        objval
+    }
+
+
+    /// Solve the LP over the active columns and constraints
+    /// and returns the optimal value.
+    fn solve(&mut self) -> f64 {
+        let cols = self.columns.indices();
+        let rows = self.rows.indices();
+        let n_cols = cols.len();
+        let n_rows = rows.len();
+
         // Set the linear part of the objective function 
         // as the minimization form
         // - ρ + (1/ν) Σ_i ξ_i
-        let mut lin_obj = vec![1f64/upper_bound; n_examples+1];
+        let mut lin_obj = vec![1f64/self.upper_bound; n_rows+1];
         lin_obj[0] = -1f64;
+        lin_obj.resize(1 + n_rows + n_cols, 0f64);
 
-        let mut col_ptr = vec![0usize];
-        let mut row_val = (0usize..n_examples).collect::<Vec<usize>>();
 
+        let mut col_ptr = vec![0usize];
+        let mut row_val = (0usize..n_rows).collect::<Vec<usize>>();
+        let mut nonzero = vec![1f64; n_rows];
 
-        let mut nonzero = vec![1f64; n_examples];
         // Adding the constraint column vectors for ξ.
-        for r in 0..n_examples {
+        for r in 0..n_rows {
             col_ptr.push(row_val.len());
             row_val.push(r);
             nonzero.push(-1f64);
-            row_val.push(n_examples + 1 + r);
+            row_val.push(n_rows + 1 + r);
             nonzero.push(-1f64);
         }
 
-        Self {
-            lin_obj,
-            nonzero,
-            col_ptr,
-            row_val,
-            n_examples,
-            n_hypotheses: 0usize,
-            weights:      Vec::with_capacity(0usize),
-            dist:         Vec::with_capacity(0usize),
+        // Adding the constraint column vectors for w.
+        for (k, &j) in cols.iter().enumerate() {
+            col_ptr.push(row_val.len());
+            for (r, &i) in rows.iter().enumerate() {
+                row_val.push(r);
+                nonzero.push(-self.margins[j][i]);
+            }
+            // append 1 for equality constraint.
+            row_val.push(n_rows);
+            nonzero.push(1f64);
+            // append 1 for non-negative constraint of weight on `clf.`
+            row_val.push(2*n_rows + 1 + k);
+            nonzero.push(-1f64);
         }
-    }
+        col_ptr.push(row_val.len());
 
 
-    /// Solve the edge minimization problem 
-    /// over the hypotheses `h1, ..., ht` 
-    /// and outputs the optimal value.
-    pub(super) fn update<F>(
-        &mut self,
-        sample: &Sample,
-        clf: &F
-    ) -> f64
-        where F: Classifier
-    {
-        self.n_hypotheses += 1;
-        let margins = utils::margins_of_hypothesis(sample, clf);
-        self.col_ptr.push(self.row_val.len());
-        for (i, yh) in margins.into_iter().enumerate() {
-            self.row_val.push(i);
-            self.nonzero.push(-yh);
-        }
-        // append 1 for equality constraint.
-        self.row_val.push(self.n_examples);
-        self.nonzero.push(1f64);
-        // append 1 for non-negative constraint of weight on `clf.`
-        self.row_val.push(2*self.n_examples + self.n_hypotheses);
-        self.nonzero.push(-1f64);
-
-        // In the CSC format, the following is equired:
-        let n_rows = 2 * self.n_examples + self.n_hypotheses + 1;
-        let n_cols = self.n_examples + self.n_hypotheses + 1;
-        let mut col_ptr = self.col_ptr.clone();
-        col_ptr.push(self.row_val.len());
-        let row_val = self.row_val.clone();
-        let nonzero = self.nonzero.clone();
+        let n_constraints = 2 * n_rows + n_cols + 1;
+        let n_variables = 1 + n_rows + n_cols;
         let constraint_matrix = CscMatrix::new(
-            n_rows,  // # of rows
-            n_cols,  // # of cols
-            col_ptr, // col ptr
-            row_val, // row val
-            nonzero, // non-zero values
+            n_constraints, // # of rows
+            n_variables,   // # of cols
+            col_ptr,       // col ptr
+            row_val,       // row val
+            nonzero,       // non-zero values
         );
 
-        let mut rhs = vec![0f64; 2*self.n_examples + self.n_hypotheses + 1];
-        rhs[self.n_examples] = 1f64;
+        let mut rhs = vec![0f64; n_constraints];
+        rhs[n_rows] = 1f64;
         let cones = [
-            NonnegativeConeT(self.n_examples),
+            NonnegativeConeT(n_rows),
             ZeroConeT(1),
-            NonnegativeConeT(self.n_examples),
-            NonnegativeConeT(self.n_hypotheses),
+            NonnegativeConeT(n_rows),
+            NonnegativeConeT(n_cols),
         ];
 
         let settings = DefaultSettingsBuilder::default()
@@ -166,12 +236,10 @@ impl LPModel {
             .build()
             .unwrap();
 
-        let n_variables = 1 + self.n_examples + self.n_hypotheses;
         let zero_mat = CscMatrix::<f64>::zeros((n_variables, n_variables));
-        self.lin_obj.push(0f64);
         let mut solver = DefaultSolver::new(
             &zero_mat,
-            &self.lin_obj,
+            &lin_obj,
             &constraint_matrix,
             &rhs[..],
             &cones,
@@ -184,21 +252,15 @@ impl LPModel {
         //         here
         //          ↓
         // [ ρ, ξ, w[0], w[1], ..., w[T] ]
-        let size = 1 + self.n_examples;
-        self.weights = solver.solution.x[size..].to_vec();
-        self.dist = solver.solution.z[..self.n_examples].to_vec();
-
-        let wsum = self.weights.iter().sum::<f64>();
-        if (wsum - 1f64).abs() > 1e-6 {
-            eprintln!(
-                "[WRN] weight sum on hypotheses far from 1. sum is: {wsum}"
-            );
+        let size = 1 + n_rows;
+        self.rho = solver.solution.x[0];
+        self.weights = vec![0f64; self.columns.len()];
+        for (&j, w) in cols.iter().zip(&solver.solution.x[size..]) {
+            self.weights[j] = *w;
         }
-        let dsum = self.dist.iter().sum::<f64>();
-        if (dsum - 1f64).abs() > 1e-6 {
-            eprintln!(
-                "[WRN] dist sum on examples far from 1. sum is: {dsum}"
-            );
+        self.dist = vec![0f64; self.n_examples];
+        for (&i, d) in rows.iter().zip(&solver.solution.z[..n_rows]) {
+            self.dist[i] = *d;
         }
 
         // Since this method solves 
@@ -207,6 +269,36 @@ impl LPModel {
         - solver.solution.obj_val
     }
 
+
+    /// Restore the dropped margin constraints
+    /// violated by the current solution.
+    /// Returns `true` if some constraint is restored.
+    fn restore_violated_rows(&mut self) -> bool {
+        let inactive = self.rows.inactive_indices();
+        if inactive.is_empty() {
+            return false;
+        }
+
+        // A dropped constraint is violated
+        // if the example needs a positive slack `ξ_i`.
+        let margin = |i: usize| {
+            self.weights.iter()
+                .zip(&self.margins[..])
+                .map(|(w, yh)| w * yh[i])
+                .sum::<f64>()
+        };
+
+        let violated = inactive.into_iter()
+            .filter(|&i| margin(i) < self.rho - ZERO_WEIGHT)
+            .collect::<Vec<_>>();
+
+        for &i in violated.iter() {
+            self.rows.activate(i);
+        }
+        !violated.is_empty()
+    }
+
+
     /// Returns the distribution over examples.
     pub(super) fn distribution(&self)
         -> Vec<f64>
@@ -221,4 +313,3 @@ impl LPModel {
         self.weights.iter().copied()
     }
 }
-
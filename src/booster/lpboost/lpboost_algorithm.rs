@@ -55,6 +55,31 @@ use std::ops::ControlFlow;
 /// `LPBoost` takes `Ω( m )` iterations for the worst case.
 ///
 ///
+/// # Active set
+/// By default, `LPBoost` adds a column to the LP in each round
+/// and never removes it, so that each round gets slower.
+/// [`LPBoost::drop_columns_after`] drops the hypotheses
+/// with zero weight over several rounds from the LP,
+/// and [`LPBoost::drop_constraints_after`] drops the margin constraints
+/// with zero dual weight over several rounds.
+/// A dropped constraint is restored as soon as
+/// the current solution violates it,
+/// and the columns are dropped only when the optimal value
+/// strictly increases.
+/// Thus, the termination guarantee is the same as the one without dropping.
+/// Note that these options are ignored with the `gurobi` feature.
+///
+/// The following drops a hypothesis (resp. a margin constraint)
+/// if it has zero weight in `10` (resp. `50`) consecutive rounds.
+/// ```no_run
+/// # use miniboosts::prelude::*;
+/// # let sample = SampleReader::default().file("a.csv").read().unwrap();
+/// let mut booster = LPBoost::init(&sample)
+///     .tolerance(0.01)
+///     .drop_columns_after(10)
+///     .drop_constraints_after(50);
+/// ```
+///
 /// # Related information
 /// - Currently (2023), `LPBoost` has no convergence guarantee.
 /// - [`ERLPBoost`](crate::booster::ERLPBoost), 
@@ -141,6 +166,12 @@ pub struct LPBoost<'a, F> {
     lp_model: Option<RefCell<LPModel>>,
 
 
+    // The number of rounds with zero weight
+    // after which a column (resp. a margin constraint) is dropped.
+    max_column_age: Option<usize>,
+    max_row_age: Option<usize>,
+
+
     hypotheses: Vec<F>,
     weights: Vec<f64>,
 
@@ -170,6 +201,9 @@ impl<'a, F> LPBoost<'a, F>
             nu: 1.0,
             lp_model: None,
 
+            max_column_age: None,
+            max_row_age: None,
+
             hypotheses: Vec::new(),
             weights: Vec::new(),
 
@@ -191,6 +225,34 @@ impl<'a, F> LPBoost<'a, F>
     }
 
 
+    /// Drop a hypothesis from the LP
+    /// if it has zero weight in `rounds` consecutive rounds.
+    /// The dropped hypothesis keeps zero weight
+    /// in the combined hypothesis.
+    /// By default, `LPBoost` never drops hypotheses.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn drop_columns_after(mut self, rounds: usize) -> Self {
+        assert!(rounds > 0);
+        self.max_column_age = Some(rounds);
+        self
+    }
+
+
+    /// Drop the margin constraint of an example from the LP
+    /// if the example has zero weight in `rounds` consecutive rounds.
+    /// The dropped constraint is restored
+    /// when the current solution violates it.
+    /// By default, `LPBoost` never drops constraints.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn drop_constraints_after(mut self, rounds: usize) -> Self {
+        assert!(rounds > 0);
+        self.max_row_age = Some(rounds);
+        self
+    }
+
+
     /// Initializes the LP solver.
     fn init_solver(&mut self) {
         let n_sample = self.sample.shape().0 as f64;
@@ -198,7 +260,11 @@ impl<'a, F> LPBoost<'a, F>
 
         let upper_bound = 1.0 / self.nu;
 
-        let lp_model = RefCell::new(LPModel::init(self.n_sample, upper_bound));
+        #[allow(unused_mut)]
+        let mut lp_model = LPModel::init(self.n_sample, upper_bound);
+        #[cfg(not(feature="gurobi"))]
+        lp_model.active_set(self.max_column_age, self.max_row_age);
+        let lp_model = RefCell::new(lp_model);
 
         self.lp_model = Some(lp_model);
     }
@@ -167,6 +167,10 @@ pub struct MLPBoost<'a, F> {
     // Secondary (LPBoost) update
     secondary: Option<RefCell<LPModel>>,
 
+    // The number of rounds with zero weight
+    // after which a hypothesis is dropped from the secondary LP.
+    max_column_age: Option<usize>,
+
 
     // Weights on hypotheses
     weights: Vec<f64>,
@@ -208,6 +212,7 @@ impl<'a, F> MLPBoost<'a, F> {
 
             primary,
             secondary: None,
+            max_column_age: None,
 
             weights: Vec::new(),
             hypotheses: Vec::new(),
@@ -253,6 +258,23 @@ impl<'a, F> MLPBoost<'a, F> {
     }
 
 
+    /// Drop a hypothesis from the LP of the secondary update
+    /// if it has zero weight in `rounds` consecutive secondary updates.
+    /// In the LP, each hypothesis corresponds to an edge constraint,
+    /// which is restored as soon as the current solution violates it.
+    /// Since the primary (Frank-Wolfe) update keeps all the hypotheses,
+    /// the termination guarantee does not change.
+    /// By default, `MLPBoost` never drops hypotheses.
+    /// Note that this option is ignored with the `gurobi` feature.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn drop_columns_after(mut self, rounds: usize) -> Self {
+        assert!(rounds > 0);
+        self.max_column_age = Some(rounds);
+        self
+    }
+
+
     /// Set the regularization parameter.
     /// 
     /// Time complexity: `O(1)`.
@@ -271,7 +293,11 @@ impl<'a, F> MLPBoost<'a, F> {
         // `ub` is the upper-bound of distribution for each example.
         let ub = 1.0 / self.nu;
 
-        let lp_model = RefCell::new(LPModel::init(self.eta, self.n_sample, ub));
+        #[allow(unused_mut)]
+        let mut lp_model = LPModel::init(self.eta, self.n_sample, ub);
+        #[cfg(not(feature="gurobi"))]
+        lp_model.active_set(self.max_column_age);
+        let lp_model = RefCell::new(lp_model);
 
         self.secondary = Some(lp_model);
     }
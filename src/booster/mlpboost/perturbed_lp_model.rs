@@ -9,6 +9,7 @@ use rand::prelude::*;
 use crate::{
     Sample,
     common::utils,
+    common::active_set::{ActiveSet, ZERO_WEIGHT},
 };
 
 use crate::hypothesis::Classifier;
@@ -61,10 +62,19 @@ const SEED: u64 = 7777;
 /// # of
 /// cols     1 ┃               m
 /// ```
+///
+/// # Active set
+/// The last `H` rows only contain the active hypotheses.
+/// A hypothesis with zero weight (dual variable)
+/// for `max_age` consecutive solves is dropped.
+/// After each solve, the dropped hypotheses whose edge exceeds `γ`
+/// are restored and the LP is solved again.
 pub(super) struct LPModel {
     pub(self) n_examples: usize,        // number of columns
     pub(self) n_hypotheses: usize,      // number of rows
     pub(self) margins: Vec<Vec<f64>>,   // margin vectors
+    pub(self) active: ActiveSet,        // active hypotheses
+    pub(self) gamma: f64,               // edge bound `γ` at the last solve
     pub(self) weights: Vec<f64>,        // weight on hypothesis
     pub(self) rng: StdRng,              // Rng
     pub(self) dist: Vec<f64>,           // distribution over examples
@@ -85,6 +95,8 @@ impl LPModel {
             n_hypotheses: 0usize,
             rng:          rand::SeedableRng::seed_from_u64(SEED),
             margins,
+            active:       ActiveSet::new(None),
+            gamma:        1f64,
             weights:      Vec::with_capacity(0usize),
             dist:         Vec::with_capacity(0usize),
             cap_inv:      upper_bound,
@@ -93,6 +105,13 @@ impl LPModel {
     }
 
 
+    /// Set the maximal age of the hypotheses.
+    /// `None` means that the hypotheses are never dropped.
+    pub(super) fn active_set(&mut self, max_age: Option<usize>) {
+        self.active = ActiveSet::new(max_age);
+    }
+
+
     /// Solve the edge minimization problem 
     /// over the hypotheses `h1, ..., ht` 
     /// and outputs the optimal value.
@@ -109,40 +128,81 @@ impl LPModel {
             self.margins.iter_mut()
                 .zip(margins)
                 .for_each(|(mvec, yh)| { mvec.push(yh); });
-            let constraint_matrix = self.build_constraint_matrix();
-            let sense = self.build_sense();
-            let rhs = self.build_rhs();
-
-
-            let settings = DefaultSettingsBuilder::default()
-                .equilibrate_enable(true)
-                .verbose(false)
-                .build()
-                .unwrap();
-            let linear = self.build_linear_part_objective();
-            let n_variables = self.n_examples + 1;
-            let zero_mat = CscMatrix::<f64>::zeros((n_variables, n_variables));
-            let mut solver = DefaultSolver::new(
-                &zero_mat,
-                &linear,
-                &constraint_matrix,
-                &rhs,
-                &sense[..],
-                settings
-            )
-            .expect("failed to build Clarabel LP solver");
-
-            solver.solve();
-
-            let ix = 2 * self.n_examples + 1;
-            self.weights = solver.solution.z[ix..].to_vec();
-            self.dist = solver.solution.x[1..].to_vec();
+            self.active.push();
+
+            self.solve();
+            while self.restore_violated_hypotheses() {
+                self.solve();
+            }
+
+            self.active.grow_older(&self.weights[..]);
+            self.active.drop_aged();
         }
 
         self.weights.clone()
     }
 
 
+    /// Solve the LP over the active hypotheses.
+    pub(self) fn solve(&mut self) {
+        let constraint_matrix = self.build_constraint_matrix();
+        let sense = self.build_sense();
+        let rhs = self.build_rhs();
+
+
+        let settings = DefaultSettingsBuilder::default()
+            .equilibrate_enable(true)
+            .verbose(false)
+            .build()
+            .unwrap();
+        let linear = self.build_linear_part_objective();
+        let n_variables = self.n_examples + 1;
+        let zero_mat = CscMatrix::<f64>::zeros((n_variables, n_variables));
+        let mut solver = DefaultSolver::new(
+            &zero_mat,
+            &linear,
+            &constraint_matrix,
+            &rhs,
+            &sense[..],
+            settings
+        )
+        .expect("failed to build Clarabel LP solver");
+
+        solver.solve();
+
+        let ix = 2 * self.n_examples + 1;
+        self.weights = vec![0f64; self.n_hypotheses];
+        let active = self.active.indices();
+        for (&j, w) in active.iter().zip(&solver.solution.z[ix..]) {
+            self.weights[j] = *w;
+        }
+        self.gamma = solver.solution.x[0];
+        self.dist = solver.solution.x[1..].to_vec();
+    }
+
+
+    /// Restore the dropped hypotheses
+    /// whose edges exceed `γ` at the current solution.
+    /// Returns `true` if some hypothesis is restored.
+    pub(self) fn restore_violated_hypotheses(&mut self) -> bool {
+        let violated = self.active.inactive_indices()
+            .into_iter()
+            .filter(|&j| {
+                let edge = self.margins.iter()
+                    .zip(&self.dist[..])
+                    .map(|(yh, d)| d * yh[j])
+                    .sum::<f64>();
+                edge > self.gamma + ZERO_WEIGHT
+            })
+            .collect::<Vec<_>>();
+
+        for &j in violated.iter() {
+            self.active.activate(j);
+        }
+        !violated.is_empty()
+    }
+
+
     pub(self) fn build_linear_part_objective(&mut self) -> Vec<f64> {
         std::iter::once(1f64)
             .chain((0..self.n_examples).map(|_| {
@@ -155,7 +215,8 @@ impl LPModel {
 
     /// Build the constraint matrix in the 0-indexed CSC form.
     pub(self) fn build_constraint_matrix(&self) -> CscMatrix::<f64> {
-        let n_rows = 1 + 2*self.n_examples + self.n_hypotheses;
+        let active = self.active.indices();
+        let n_rows = 1 + 2*self.n_examples + active.len();
         let n_cols = 1 + self.n_examples;
 
         let mut col_ptr = Vec::new();
@@ -183,9 +244,9 @@ impl LPModel {
             nonzero.push(self.cap_inv);
 
             // margin constraints of `i`-th column
-            for (i, &yh) in (0..).zip(margins) {
-                row_val.push(gam + i);
-                nonzero.push(yh);
+            for (k, &t) in active.iter().enumerate() {
+                row_val.push(gam + k);
+                nonzero.push(margins[t]);
             }
         }
         col_ptr.push(row_val.len());
@@ -202,7 +263,7 @@ impl LPModel {
 
     /// Build the vector of constraint sense: `[=, ≤, ≥, ...].`
     pub(self) fn build_sense(&self) -> Vec<SupportedConeT<f64>> {
-        let n_ineq = 2*self.n_examples + self.n_hypotheses;
+        let n_ineq = 2*self.n_examples + self.active.indices().len();
         vec![
             ZeroConeT(1),
             NonnegativeConeT(n_ineq),
@@ -212,12 +273,13 @@ impl LPModel {
 
     /// Build the right-hand-side of the constraints.
     pub(self) fn build_rhs(&self) -> Vec<f64> {
-        let n_constraints = 1 + 2*self.n_examples + self.n_hypotheses;
+        let n_active = self.active.indices().len();
+        let n_constraints = 1 + 2*self.n_examples + n_active;
         let mut rhs = Vec::with_capacity(n_constraints);
         rhs.push(1f64);
         rhs.extend(iter::repeat(0f64).take(self.n_examples));
         rhs.extend(iter::repeat(self.cap_inv).take(self.n_examples));
-        rhs.extend(iter::repeat(0f64).take(self.n_hypotheses));
+        rhs.extend(iter::repeat(0f64).take(n_active));
         rhs
     }
 }
@@ -0,0 +1,10 @@
+//! The online boosting algorithm proposed
+//! by Nikunj C. Oza and Stuart Russell.
+//! This algorithm is based on the paper: 
+//! [
+//! Online Bagging and Boosting
+//! ](https://proceedings.mlr.press/r3/oza01a.html)
+//! 
+pub mod ozaboost_algorithm;
+
+pub use ozaboost_algorithm::OzaBoost;
@@ -0,0 +1,243 @@
+//! Provides [`OzaBoost`] by Oza and Russell, 2001.
+use rand::prelude::*;
+use rand_distr::Poisson;
+
+
+use crate::{
+    Classifier,
+    Sample,
+    OnlineWeakLearner,
+};
+
+
+/// The online boosting algorithm
+/// proposed by Nikunj C. Oza and Stuart Russell.
+/// 
+/// This algorithm is based on the paper: 
+///
+/// [
+/// Online Bagging and Boosting
+/// ](https://proceedings.mlr.press/r3/oza01a.html)
+/// by Nikunj C. Oza and Stuart Russell.
+/// 
+/// Unlike the other boosting algorithms in this crate,
+/// `OzaBoost` does not implement [`Booster`](crate::Booster)
+/// since it never sees the whole training sample.
+/// Instead, `OzaBoost` consumes the examples one at a time
+/// by [`OzaBoost::update`]
+/// and holds a fixed number of [`OnlineWeakLearner`]s.
+/// 
+/// # Update rule
+/// Given an example `(x, y)`, `OzaBoost` sets `λ = 1`
+/// and visits the weak learners in order.
+/// The `m`th weak learner is updated by `(x, y)`
+/// with the weight `k ~ Poisson(λ)`,
+/// including `k = 0`.
+/// After that, `OzaBoost` increases
+/// the weight `λ` if the `m`th weak learner misclassifies `(x, y)`,
+/// and decreases `λ` otherwise,
+/// as [`AdaBoost`](crate::booster::AdaBoost) does.
+/// 
+/// # Drift
+/// By default, `OzaBoost` weighs all the past examples equally.
+/// For a drifting stream, [`OzaBoost::decay`] sets a forgetting factor
+/// on the error statistics of the weak learners,
+/// so that the weights on the weak learners follow their recent accuracy.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Initialize `OzaBoost` with `10` Hoeffding tree stumps.
+/// let mut booster = OzaBoost::init(HoeffdingStump::init(), 10)
+///     .seed(777)
+///     .decay(0.999);
+/// 
+/// // Consume a stream of examples.
+/// for (x, y) in stream {
+///     booster.update(&x[..], y);
+/// }
+/// 
+/// // Predict the label of a new instance.
+/// let y = booster.predict_on(&[0.0, 1.0]);
+/// ```
+pub struct OzaBoost<L> {
+    // Weak learners.
+    learners: Vec<L>,
+
+    // Total weight `λ` of the examples
+    // that each weak learner classifies correctly.
+    lambda_correct: Vec<f64>,
+
+    // Total weight `λ` of the examples
+    // that each weak learner misclassifies.
+    lambda_wrong: Vec<f64>,
+
+    // The (discounted) number of observed examples.
+    n_seen: f64,
+
+    // Forgetting factor for the error statistics.
+    decay: f64,
+
+    // Seed of the randomness for the Poisson weights.
+    seed: u64,
+
+    // Random number generator for the Poisson weights.
+    rng: StdRng,
+}
+
+
+impl<L> OzaBoost<L>
+    where L: OnlineWeakLearner + Clone,
+{
+    /// Constructs a new instance of `OzaBoost`
+    /// with `n_learners` copies of `learner`.
+    /// 
+    /// Time complexity: `O(n_learners)`.
+    pub fn init(learner: L, n_learners: usize) -> Self {
+        assert!(n_learners > 0, "The number of weak learners must be positive");
+        let seed = 1234;
+        Self {
+            learners: vec![learner; n_learners],
+            lambda_correct: vec![0.0; n_learners],
+            lambda_wrong: vec![0.0; n_learners],
+
+            n_seen: 0.0,
+            decay: 1.0,
+
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+
+impl<L> OzaBoost<L>
+    where L: OnlineWeakLearner,
+{
+    /// Set the seed of the randomness for the Poisson weights.
+    /// Default vaule is `1234.`
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+
+    /// Set the forgetting factor in `(0, 1]`.
+    /// Before each update, `OzaBoost` multiplies
+    /// the error statistics of the weak learners by `decay`.
+    /// Default value is `1.0`, *i.e.,* no forgetting.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn decay(mut self, decay: f64) -> Self {
+        assert!(
+            0.0 < decay && decay <= 1.0,
+            "The forgetting factor must be in `(0, 1]`."
+        );
+        self.decay = decay;
+        self
+    }
+
+
+    /// Returns the weak learners.
+    pub fn learners(&self) -> &[L] {
+        &self.learners[..]
+    }
+
+
+    /// Updates the weak learners by the example `(x, y)`,
+    /// where `y` is `+1` or `-1`.
+    /// 
+    /// Time complexity: `O(M)` calls of `OnlineWeakLearner::update`,
+    /// where `M` is the number of weak learners.
+    pub fn update(&mut self, x: &[f64], y: f64) {
+        self.n_seen = self.decay * self.n_seen + 1.0;
+
+        let mut lambda = 1.0;
+        let iter = self.learners.iter_mut()
+            .zip(&mut self.lambda_correct[..])
+            .zip(&mut self.lambda_wrong[..]);
+        for ((learner, correct), wrong) in iter {
+            *correct *= self.decay;
+            *wrong *= self.decay;
+
+            // Update the learner even if `k == 0`
+            // so that it forgets once per example.
+            let k: f64 = Poisson::new(lambda)
+                .expect("The weight of an example must be positive")
+                .sample(&mut self.rng);
+            learner.update(x, y, k);
+
+
+            if learner.predict_on(x) as f64 == y {
+                *correct += lambda;
+                lambda *= self.n_seen / (2.0 * *correct);
+            } else {
+                *wrong += lambda;
+                lambda *= self.n_seen / (2.0 * *wrong);
+            }
+        }
+    }
+
+
+    /// Returns the weights on the weak learners.
+    /// A weak learner with error at least `0.5` has weight `0.0`.
+    /// 
+    /// Time complexity: `O(M)`,
+    /// where `M` is the number of weak learners.
+    pub fn weights(&self) -> Vec<f64> {
+        self.lambda_correct.iter()
+            .zip(&self.lambda_wrong[..])
+            .map(|(&correct, &wrong)| {
+                if correct + wrong <= 0.0 || correct <= wrong {
+                    return 0.0;
+                }
+                // Prevent the infinite weight.
+                let wrong = wrong.max(f64::EPSILON * correct);
+                (correct / wrong).ln()
+            })
+            .collect()
+    }
+
+
+    /// Computes the confidence for the instance `x`.
+    /// This method returns a value in `[-1.0, 1.0]`.
+    /// 
+    /// As [`WeightedMajority`](crate::WeightedMajority) does,
+    /// `OzaBoost` combines the confidences of the weak learners.
+    /// 
+    /// Time complexity: `O(M)` calls of `OnlineWeakLearner::confidence_on`,
+    /// where `M` is the number of weak learners.
+    pub fn confidence_on(&self, x: &[f64]) -> f64 {
+        let weights = self.weights();
+        let total = weights.iter().sum::<f64>();
+        if total <= 0.0 { return 0.0; }
+
+        weights.into_iter()
+            .zip(&self.learners[..])
+            .filter(|(w, _)| *w > 0.0)
+            .map(|(w, learner)| w * learner.confidence_on(x))
+            .sum::<f64>()
+            / total
+    }
+
+
+    /// Predicts the label of the instance `x`.
+    pub fn predict_on(&self, x: &[f64]) -> i64 {
+        let conf = self.confidence_on(x);
+        if conf >= 0.0 { 1 } else { -1 }
+    }
+}
+
+
+impl<L> Classifier for OzaBoost<L>
+    where L: OnlineWeakLearner,
+{
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let (x, _) = sample.at(row);
+        self.confidence_on(&x[..])
+    }
+}
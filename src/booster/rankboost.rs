@@ -0,0 +1,12 @@
+//! The RankBoost algorithm proposed
+//! by Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+//! This algorithm is based on the paper:
+//! [An Efficient Boosting Algorithm for Combining Preferences](https://www.jmlr.org/papers/v4/freund03a.html)
+//! by Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+//! 
+//! This module provides the bipartite version of RankBoost,
+//! which ranks the positive examples above the negative ones.
+//!
+pub mod rankboost_algorithm;
+
+pub use rankboost_algorithm::RankBoost;
@@ -0,0 +1,333 @@
+//! Provides [`RankBoost`] by Freund, Iyer, Schapire, and Singer, 2003.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The RankBoost algorithm
+/// proposed by Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+/// 
+/// This struct is based on the paper: 
+///
+/// [
+/// An Efficient Boosting Algorithm for Combining Preferences
+/// ](https://www.jmlr.org/papers/v4/freund03a.html)  
+/// by Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+/// 
+/// `RankBoost` solves the bipartite ranking problem,
+/// that is, it finds a scoring function `F`
+/// that ranks the positive examples above the negative ones.
+/// It keeps a distribution `D` over the pairs `(x0, x1)`
+/// of a negative example `x0` and a positive example `x1`,
+/// and minimizes the ranking loss
+/// `sum( D(x0, x1) exp( F(x0) - F(x1) ) )`,
+/// which upper-bounds `1 - AUC`.
+/// 
+/// # Bipartite factorization
+/// In the bipartite setting, the distribution over pairs factorizes as
+/// `D(x0, x1) = v(x0) v(x1)`,
+/// where `v` is a distribution over the negative examples
+/// and over the positive examples, respectively.
+/// Thus, `RankBoost` keeps only `m` weights, not `m0 * m1` weights.
+/// The weak learner receives the distribution `v / 2`,
+/// so that each class has the total weight `1/2`.
+/// A hypothesis with high weighted accuracy on `v / 2`
+/// has high ranking edge `sum( D(x0, x1) (h(x1) - h(x0)) / 2 )`.
+/// Hence, any weak learner for [`AdaBoost`](crate::booster::AdaBoost),
+/// such as [`DecisionTree`](crate::weak_learner::DecisionTree),
+/// works as a weak learner for `RankBoost`.
+/// 
+/// The output of `RankBoost` is a [`WeightedMajority`].
+/// [`Classifier::confidence`] returns the score of each example.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `RankBoost` and set the maximal number of rounds.
+/// let mut booster = RankBoost::init(&sample)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `RankBoost` and obtain the resulting scoring function `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the scores on the training set.
+/// let scores = f.confidence_all(&sample);
+/// 
+/// // Calculate the AUC.
+/// let target = sample.target();
+/// let (mut n_correct, mut n_pair) = (0.0, 0.0);
+/// for (s1, _) in scores.iter().zip(target).filter(|(_, &y)| y > 0.0) {
+///     for (s0, _) in scores.iter().zip(target).filter(|(_, &y)| y <= 0.0) {
+///         n_pair += 1.0;
+///         if s1 > s0 { n_correct += 1.0; }
+///         else if s1 == s0 { n_correct += 0.5; }
+///     }
+/// }
+/// let auc = n_correct / n_pair;
+/// 
+/// println!("AUC is: {auc}");
+/// ```
+pub struct RankBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Distribution over training examples.
+    // Each class has the total weight `1/2`.
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> RankBoost<'a, F> {
+    /// Constructs a new instance of `RankBoost`.
+    /// By default, `RankBoost` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns a weight on the new hypothesis.
+    /// `update_params` also updates `self.dist`.
+    /// 
+    /// The weights on the positive and negative examples
+    /// are normalized separately,
+    /// which corresponds to the normalization of
+    /// the distribution over pairs.
+    /// 
+    /// Time complexity: `O( m )`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    fn update_params(
+        &mut self,
+        margins: Vec<f64>,
+        edge: f64
+    ) -> f64
+    {
+        // Compute the weight on new hypothesis.
+        // This is the returned value of this function.
+        // Since `h(x1) - h(x0)` lies in `[-2, 2]`,
+        // the weight is the half of the one for `AdaBoost`.
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 4.0;
+
+
+        // To prevent overflow, take the logarithm.
+        self.dist.par_iter_mut()
+            .zip(margins)
+            .for_each(|(d, p)| *d = d.ln() - weight * p);
+
+
+        let target = self.sample.target();
+        for positive in [true, false] {
+            let is_target = |y: f64| (y > 0.0) == positive;
+            let max = self.dist.iter()
+                .zip(target)
+                .filter_map(|(d, &y)| is_target(y).then_some(*d))
+                .fold(f64::MIN, f64::max);
+            let normalizer = max + self.dist.iter()
+                .zip(target)
+                .filter_map(|(d, &y)| is_target(y).then_some((d - max).exp()))
+                .sum::<f64>()
+                .ln();
+
+
+            // Each class has the total weight `1/2`.
+            let normalizer = normalizer + 2f64.ln();
+            self.dist.par_iter_mut()
+                .zip(target)
+                .filter(|(_, &y)| is_target(y))
+                .for_each(|(d, _)| *d = (*d - normalizer).exp());
+        }
+
+
+        weight
+    }
+}
+
+
+impl<F> Booster<F> for RankBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "RankBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let n_positive = self.sample.target()
+            .iter()
+            .filter(|&&y| y > 0.0)
+            .count();
+        let n_pair = n_positive * (n_sample - n_positive);
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("# of pairs", format!("{}", n_pair)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        // Initialize parameters
+        // `D(x0, x1)` is the uniform distribution over pairs,
+        // which is the product of the uniform distributions
+        // over the negative and positive examples.
+        let target = self.sample.target();
+        let n_positive = target.iter()
+            .filter(|&&y| y > 0.0)
+            .count() as f64;
+        let n_negative = target.len() as f64 - n_positive;
+        self.dist = target.iter()
+            .map(|&y| {
+                let n = if y > 0.0 { n_positive } else { n_negative };
+                0.5 / n
+            })
+            .collect();
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis
+        let h = weak_learner.produce(self.sample, &self.dist);
+
+
+        // Each element in `margins` is the product of
+        // the predicted vector and the correct vector
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+
+
+        // The ranking edge
+        // `sum( D(x0, x1) (h(x1) - h(x0)) / 2 )`
+        // equals to the edge on `self.dist`.
+        let edge = utils::inner_product(&margins, &self.dist);
+
+
+        // If `h` ranks all the pairs correctly,
+        // use it as the scoring function.
+        if edge.abs() >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![edge.signum()];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // Compute the weight on the new hypothesis
+        let weight = self.update_params(margins, edge);
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for RankBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,10 @@
+//! The RUSBoost algorithm proposed
+//! by Seiffert, Khoshgoftaar, Van Hulse, and Napolitano.
+//! This algorithm is based on the paper: 
+//! [
+//! RUSBoost: A Hybrid Approach to Alleviating Class Imbalance
+//! ](https://ieeexplore.ieee.org/document/5299216)
+//! 
+pub mod rusboost_algorithm;
+
+pub use rusboost_algorithm::RUSBoost;
@@ -0,0 +1,403 @@
+//! Provides [`RUSBoost`]
+//! by Seiffert, Khoshgoftaar, Van Hulse, and Napolitano, 2010.
+use rayon::prelude::*;
+use rand::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The RUSBoost algorithm 
+/// proposed by Chris Seiffert, Taghi M. Khoshgoftaar,
+/// Jason Van Hulse, and Amri Napolitano.
+/// 
+/// This algorithm is based on the paper: 
+///
+/// [
+/// RUSBoost: A Hybrid Approach to Alleviating Class Imbalance
+/// ](https://ieeexplore.ieee.org/document/5299216)
+/// by Chris Seiffert, Taghi M. Khoshgoftaar,
+/// Jason Van Hulse, and Amri Napolitano.
+/// 
+/// RUSBoost is a boosting algorithm for imbalanced binary classification.
+/// In each round, `RUSBoost` randomly undersamples the majority class
+/// and passes the sub-sample to the weak learner
+/// with the distribution restricted to the sub-sample.
+/// After that, `RUSBoost` updates the distribution
+/// over the full sample as [`AdaBoost`](crate::booster::AdaBoost) does.
+///
+/// By default, `RUSBoost` keeps as many majority examples
+/// as minority examples.
+/// See [`RUSBoost::sampling_ratio`].
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `RUSBoost` and set the seed for the undersampling.
+/// let mut booster = RUSBoost::init(&sample)
+///     .sampling_ratio(1.0)
+///     .seed(777)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `RUSBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// 
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+/// 
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as i64 == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct RUSBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Distribution on sample.
+    dist: Vec<f64>,
+
+    // The number of majority examples kept in each round
+    // is `sampling_ratio` times the number of minority examples.
+    sampling_ratio: f64,
+
+    // Seed of the randomness for the undersampling.
+    seed: u64,
+
+    // Random number generator for the undersampling.
+    rng: StdRng,
+
+    // Indices of the minority examples.
+    minority: Vec<usize>,
+
+    // Indices of the majority examples.
+    majority: Vec<usize>,
+
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> RUSBoost<'a, F> {
+    /// Constructs a new instance of `RUSBoost`.
+    /// By default, `RUSBoost` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let seed = 1234;
+        Self {
+            sample,
+
+            dist: Vec::new(),
+
+            sampling_ratio: 1.0,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+
+            minority: Vec::new(),
+            majority: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the ratio of the majority examples to the minority examples
+    /// after the undersampling.
+    /// If the ratio exceeds the original one,
+    /// `RUSBoost` keeps all the majority examples.
+    /// Default value is `1.0`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn sampling_ratio(mut self, ratio: f64) -> Self {
+        assert!(ratio > 0.0, "The sampling ratio must be positive");
+        self.sampling_ratio = ratio;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the undersampling.
+    /// Default vaule is `1234.`
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the indices of the randomly undersampled examples.
+    /// The returned indices are sorted in ascending order.
+    /// 
+    /// Time complexity: `O( m ln(m) )`,
+    /// where `m` is the number of training examples.
+    fn undersample(&mut self) -> Vec<usize> {
+        let n_minority = self.minority.len() as f64;
+        let n_keep = (self.sampling_ratio * n_minority).round() as usize;
+        let n_keep = n_keep.clamp(1, self.majority.len());
+
+        let mut ix = self.majority
+            .choose_multiple(&mut self.rng, n_keep)
+            .copied()
+            .chain(self.minority.iter().copied())
+            .collect::<Vec<_>>();
+        ix.sort_unstable();
+        ix
+    }
+
+
+    /// Returns a weight on the new hypothesis.
+    /// `update_params` also updates `self.dist`.
+    /// 
+    /// `RUSBoost` uses exponential update,
+    /// which is numerically unstable so that I adopt a logarithmic computation.
+    /// 
+    /// Time complexity: `O( m ln(m) )`,
+    /// where `m` is the number of training examples.
+    /// The additional `ln(m)` term comes from the numerical stabilization.
+    #[inline]
+    fn update_params(
+        &mut self,
+        margins: Vec<f64>,
+        edge: f64
+    ) -> f64
+    {
+        let n_sample = self.sample.shape().0;
+
+
+        // Compute the weight on new hypothesis.
+        // This is the returned value of this function.
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+
+
+        // To prevent overflow, take the logarithm.
+        self.dist.par_iter_mut()
+            .zip(margins)
+            .for_each(|(d, p)| *d = d.ln() - weight * p);
+
+
+        // Sort indices by ascending order
+        let mut indices = (0..n_sample).into_par_iter()
+            .collect::<Vec<usize>>();
+        indices.sort_unstable_by(|&i, &j| {
+            self.dist[i].partial_cmp(&self.dist[j]).unwrap()
+        });
+
+
+        let mut normalizer = self.dist[indices[0]];
+        for i in indices.into_iter().skip(1) {
+            let mut a = normalizer;
+            let mut b = self.dist[i];
+            if a < b {
+                std::mem::swap(&mut a, &mut b);
+            }
+
+            normalizer = a + (1.0 + (b - a).exp()).ln();
+        }
+
+
+
+        // Update distribution over training examples.
+        self.dist.par_iter_mut()
+            .for_each(|d| *d = (*d - normalizer).exp());
+
+
+        weight
+    }
+}
+
+
+impl<F> Booster<F> for RUSBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "RUSBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("Sampling ratio", format!("{}", self.sampling_ratio)),
+            ("Seed", format!("{}", self.seed)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        let uni = 1.0 / n_sample as f64;
+        self.dist = vec![uni; n_sample];
+
+        self.rng = StdRng::seed_from_u64(self.seed);
+
+
+        // Split the indices into the positive and negative ones.
+        // The smaller one is the minority class.
+        let (positive, negative): (Vec<usize>, Vec<usize>) = (0..n_sample)
+            .partition(|&i| self.sample.target()[i] > 0.0);
+        if positive.len() <= negative.len() {
+            self.minority = positive;
+            self.majority = negative;
+        } else {
+            self.minority = negative;
+            self.majority = positive;
+        }
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Undersample the majority class
+        // and restrict the distribution to the sub-sample.
+        let ix = self.undersample();
+        let sub_sample = self.sample.subsample(&ix);
+        let mut sub_dist = ix.iter()
+            .map(|&i| self.dist[i])
+            .collect::<Vec<_>>();
+        utils::normalize(&mut sub_dist[..]);
+
+
+        // Get a new hypothesis
+        let h = weak_learner.produce(&sub_sample, &sub_dist[..]);
+
+
+        // Each element in `margins` is the product of
+        // the predicted vector and the correct vector
+        // over the full sample.
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+
+
+        let edge = utils::inner_product(&margins, &self.dist);
+
+
+        // If `h` predicted all the examples in `sample` correctly,
+        // use it as the combined classifier.
+        if edge.abs() >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![edge.signum()];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // Compute the weight on the new hypothesis
+        let weight = self.update_params(margins, edge);
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for RUSBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,12 @@
+//! The SAMME algorithm proposed
+//! by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+//! This algorithm is based on the paper:
+//! [Multi-class AdaBoost](https://www.intlpress.com/site/pub/pages/journals/items/sii/content/vols/0002/0003/a008/)
+//! by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+//! 
+//! SAMME is a boosting algorithm for multi-class classification
+//! that minimizes the multi-class exponential loss.
+//!
+pub mod samme_algorithm;
+
+pub use samme_algorithm::SAMME;
@@ -0,0 +1,324 @@
+//! Provides [`SAMME`] by Zhu, Zou, Rosset, and Hastie, 2009.
+use rayon::prelude::*;
+
+
+use crate::{
+    Booster,
+    WeakLearner,
+    MultiClassifier,
+    WeightedMajority,
+    Sample,
+
+    common::{utils, task::Task},
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The SAMME algorithm
+/// (Stagewise Additive Modeling using a Multi-class Exponential loss)
+/// proposed by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+/// 
+/// This struct is based on the paper: 
+///
+/// [
+/// Multi-class AdaBoost
+/// ](https://www.intlpress.com/site/pub/pages/journals/items/sii/content/vols/0002/0003/a008/)  
+/// by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+/// 
+/// SAMME is a natural extension of [`AdaBoost`](crate::booster::AdaBoost)
+/// to multi-class classification.
+/// The labels are assumed to be `0`, `1`, ..., `K-1`.
+/// Each weak hypothesis must implement [`MultiClassifier`].
+/// `SAMME` reduces to `AdaBoost` when `K = 2`.
+///
+/// The weight on the hypothesis `h` with weighted error `err` is
+/// ```txt
+/// ln( (1 - err) / err ) + ln( K - 1 ),
+/// ```
+/// so that `h` only needs to be better than the random guessing,
+/// i.e., `err < 1 - 1/K`.
+/// 
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// // The labels should be `0, 1, ..., K-1`.
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// // Initialize `SAMME` and set the maximal number of rounds as `100`.
+/// let mut booster = SAMME::init(&sample)
+///     .force_quit_at(100);
+/// 
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(3)
+///     .criterion(Criterion::Entropy)
+///     .build();
+/// 
+/// // Run `SAMME` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_class_all(&sample);
+/// 
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+/// 
+/// // Calculate the training loss.
+/// let target = sample.target();
+/// let training_loss = target.into_iter()
+///     .zip(predictions)
+///     .map(|(&y, fx)| if y as usize == fx { 0.0 } else { 1.0 })
+///     .sum::<f64>()
+///     / n_sample;
+/// 
+///
+/// println!("Training Loss is: {training_loss}");
+/// ```
+pub struct SAMME<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The task, `Task::MultiClass(K)`.
+    // The number of classes `K` is determined in `preprocess`.
+    task: Task,
+
+    // Distribution on sample.
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Max iteration.
+    max_iter: usize,
+
+
+    // Terminated iteration.
+    // SAMME terminates in eary step
+    // if the weak learner returns a perfect hypothesis or
+    // a hypothesis no better than the random guessing.
+    terminated: usize,
+}
+
+
+impl<'a, F> SAMME<'a, F> {
+    /// Constructs a new instance of `SAMME`.
+    /// By default, `SAMME` runs at most `100` rounds.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            task: Task::MultiClass(0),
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default value is `100`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the number of classes `K`.
+    /// This value is `0` before calling `Booster::preprocess`.
+    fn n_class(&self) -> usize {
+        match self.task {
+            Task::MultiClass(k) => k,
+            _ => unreachable!(),
+        }
+    }
+
+
+    /// Returns a weight on the new hypothesis.
+    /// `update_params` also updates `self.dist`.
+    /// 
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    fn update_params(
+        &mut self,
+        mistakes: Vec<bool>,
+        error: f64,
+    ) -> f64
+    {
+        let n_class = self.n_class() as f64;
+
+        // Compute the weight on new hypothesis.
+        // This is the returned value of this function.
+        let weight = ((1.0 - error) / error).ln() + (n_class - 1.0).ln();
+
+
+        // Update distribution over training examples.
+        // Since the mis-classified examples are multiplied by
+        // `exp(weight) = (1 - err) (K - 1) / err`,
+        // we can normalize them without overflow.
+        let scale = weight.exp();
+        self.dist.par_iter_mut()
+            .zip(mistakes)
+            .for_each(|(d, miss)| if miss { *d *= scale; });
+        utils::normalize(&mut self.dist[..]);
+
+        weight
+    }
+}
+
+
+impl<F> Booster<F> for SAMME<'_, F>
+    where F: MultiClassifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "SAMME"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{}", n_sample)),
+            ("# of features", format!("{}", n_feature)),
+            ("Task", format!("{}", self.task)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_class = self.sample.is_valid_multiclass_instance();
+        self.task = Task::MultiClass(n_class);
+
+        // Initialize parameters
+        let n_sample = self.sample.shape().0;
+        let uni = 1.0 / n_sample as f64;
+        self.dist = vec![uni; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Get a new hypothesis
+        let h = weak_learner.produce(self.sample, &self.dist);
+
+
+        // `mistakes[i]` is `true` if `h` mis-classifies the `i`th example.
+        let mistakes = self.sample.target()
+            .iter()
+            .enumerate()
+            .map(|(i, y)| h.predict_class(self.sample, i) != *y as usize)
+            .collect::<Vec<_>>();
+
+
+        let error = self.dist.iter()
+            .zip(&mistakes[..])
+            .filter_map(|(d, miss)| if *miss { Some(d) } else { None })
+            .sum::<f64>();
+
+
+        // If `h` predicted all the examples in `sample` correctly,
+        // use it as the combined classifier.
+        if error <= 0.0 {
+            self.terminated = iteration;
+            self.weights = vec![1.0];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // If `h` is no better than the random guessing,
+        // the weight on `h` becomes non-positive.
+        // In this case, the weak learner cannot improve the current
+        // combined hypothesis, so we terminate the boosting.
+        // If `h` is the first hypothesis,
+        // we use it as the combined classifier
+        // since the empty combination cannot predict anything.
+        let n_class = self.n_class() as f64;
+        if error >= 1.0 - 1.0 / n_class {
+            if self.hypotheses.is_empty() {
+                self.terminated = iteration;
+                self.weights = vec![1.0];
+                self.hypotheses = vec![h];
+            } else {
+                self.terminated = iteration - 1;
+            }
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // Compute the weight on the new hypothesis
+        let weight = self.update_params(mistakes, error);
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<H> Research for SAMME<'_, H>
+    where H: MultiClassifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
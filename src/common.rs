@@ -9,8 +9,14 @@ pub mod utils;
 /// Defines the Frank-Wolfe algorithms.
 pub mod frank_wolfe;
 
+/// Defines the privacy accountant and the noise mechanisms.
+pub mod privacy;
+
 /// Defines some checker functions.
 pub(crate) mod checker;
 
+/// Defines the active set management for the LP-based boosting.
+pub(crate) mod active_set;
+
 /// Defines machine learning tasks.
 pub(crate) mod task;
@@ -0,0 +1,112 @@
+//! This file defines the active set management
+//! for the restricted master problems of the LP-based boosting.
+
+
+/// A value at most `ZERO_WEIGHT` is regarded as zero.
+/// Interior point methods do not return exact zeros.
+pub(crate) const ZERO_WEIGHT: f64 = 1e-7;
+
+
+/// Manages the active columns (or constraints) of a linear program.
+/// Each item has an age, the number of consecutive solves
+/// in which the item has zero weight.
+/// An item is dropped from the program
+/// once its age reaches `max_age`.
+/// If `max_age` is `None`, no item is dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct ActiveSet {
+    max_age: Option<usize>,
+    active: Vec<bool>,
+    age: Vec<usize>,
+}
+
+
+impl ActiveSet {
+    /// Construct an empty active set.
+    #[inline]
+    pub(crate) fn new(max_age: Option<usize>) -> Self {
+        if let Some(age) = max_age {
+            assert!(age > 0, "The maximal age must be positive");
+        }
+        Self { max_age, active: Vec::new(), age: Vec::new(), }
+    }
+
+
+    /// Construct an active set with `size` active items.
+    #[inline]
+    pub(crate) fn with_size(max_age: Option<usize>, size: usize) -> Self {
+        let mut set = Self::new(max_age);
+        set.active = vec![true; size];
+        set.age = vec![0; size];
+        set
+    }
+
+
+    /// Returns the number of items, including the inactive ones.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.active.len()
+    }
+
+
+    /// Append a new active item.
+    #[inline]
+    pub(crate) fn push(&mut self) {
+        self.active.push(true);
+        self.age.push(0);
+    }
+
+
+    /// Returns the indices of the active items in the ascending order.
+    #[inline]
+    pub(crate) fn indices(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&i| self.active[i]).collect()
+    }
+
+
+    /// Returns the indices of the inactive items in the ascending order.
+    #[inline]
+    pub(crate) fn inactive_indices(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&i| !self.active[i]).collect()
+    }
+
+
+    /// Activate the `i`th item and reset its age.
+    #[inline]
+    pub(crate) fn activate(&mut self, i: usize) {
+        self.active[i] = true;
+        self.age[i] = 0;
+    }
+
+
+    /// Update the ages of the active items
+    /// from the weights `weights[i]` on the `i`th items.
+    /// The weights on the inactive items are ignored.
+    pub(crate) fn grow_older(&mut self, weights: &[f64]) {
+        assert_eq!(weights.len(), self.len());
+        for (i, w) in weights.iter().enumerate() {
+            if !self.active[i] { continue; }
+            if *w <= ZERO_WEIGHT {
+                self.age[i] += 1;
+            } else {
+                self.age[i] = 0;
+            }
+        }
+    }
+
+
+    /// Deactivate the items whose age reaches `max_age`
+    /// and returns the number of the deactivated items.
+    pub(crate) fn drop_aged(&mut self) -> usize {
+        let Some(max_age) = self.max_age else { return 0; };
+
+        let mut n_dropped = 0;
+        for (active, age) in self.active.iter_mut().zip(&self.age) {
+            if *active && *age >= max_age {
+                *active = false;
+                n_dropped += 1;
+            }
+        }
+        n_dropped
+    }
+}
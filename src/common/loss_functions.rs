@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+
 /// This trait defines the loss functions.
 pub trait LossFunction {
     /// The name of the loss function.
@@ -30,17 +33,146 @@ pub trait LossFunction {
     fn hessian(&self, predictions: &[f64], target: &[f64]) -> Vec<f64>;
 
 
+    /// Returns `true` if [`LossFunction::hessian`] is
+    /// the second derivative of the loss,
+    /// so that `-G / (H + λ)` is a Newton step.
+    /// [`GBM::newton`](crate::booster::GBM::newton) falls back to
+    /// the line search for the losses that return `false`.
+    /// By default, this method returns `true`.
+    fn has_hessian(&self) -> bool {
+        true
+    }
+
+
     /// Best coffecient for the newly-attained hypothesis.
     fn best_coefficient(
         &self, 
         residuals: &[f64],
         predictions: &[f64],
     ) -> f64;
+
+
+    /// Line search for the newly-attained hypothesis.
+    /// Returns the coefficient `c` that minimizes the loss of
+    /// `current[i] + c * direction[i]`,
+    /// where `current` is the predictions of the current ensemble
+    /// and `direction` is the predictions of the new hypothesis.
+    /// By default, this method ignores `current`
+    /// and returns `LossFunction::best_coefficient`.
+    fn line_search(
+        &self,
+        _current: &[f64],
+        direction: &[f64],
+        target: &[f64],
+    ) -> f64
+    {
+        self.best_coefficient(target, direction)
+    }
+
+
+    /// The initial prediction of the boosting algorithms,
+    /// the constant that minimizes the loss for `target`.
+    /// By default, this method returns `0`.
+    fn base_score(&self, _target: &[f64]) -> f64 {
+        0.0
+    }
+
+
+    /// Check that `target` is in the domain of the loss.
+    /// This method panics if some target value is out of the domain.
+    /// By default, this method accepts any target.
+    fn check_target(&self, _target: &[f64]) {}
+
+
+    /// Loss value for the examples grouped by queries.
+    /// `group[i]` is the query of the `i`th example,
+    /// and `group` is empty if the queries are not specified.
+    /// By default, this method ignores `group`
+    /// and returns `LossFunction::eval`.
+    fn eval_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        _group: &[usize],
+    ) -> f64
+    {
+        self.eval(predictions, target)
+    }
+
+
+    /// Gradient vector for the examples grouped by queries.
+    /// By default, this method ignores `group`
+    /// and returns `LossFunction::gradient`.
+    fn gradient_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        _group: &[usize],
+    ) -> Vec<f64>
+    {
+        self.gradient(predictions, target)
+    }
+
+
+    /// Hessian for the examples grouped by queries.
+    /// By default, this method ignores `group`
+    /// and returns `LossFunction::hessian`.
+    fn hessian_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        _group: &[usize],
+    ) -> Vec<f64>
+    {
+        self.hessian(predictions, target)
+    }
+
+
+    /// Line search for the examples grouped by queries.
+    /// By default, this method ignores `group`
+    /// and returns `LossFunction::line_search`.
+    fn line_search_grouped(
+        &self,
+        current: &[f64],
+        direction: &[f64],
+        target: &[f64],
+        _group: &[usize],
+    ) -> f64
+    {
+        self.line_search(current, direction, target)
+    }
+
+
+    /// The pair of the gradient vector and the Hessian
+    /// for the examples grouped by queries.
+    /// By default, this method returns
+    /// `LossFunction::gradient_grouped` and `LossFunction::hessian_grouped`.
+    /// Override this method if the two share the computation.
+    fn gradient_and_hessian_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> (Vec<f64>, Vec<f64>)
+    {
+        let gradient = self.gradient_grouped(predictions, target, group);
+        let hessian = self.hessian_grouped(predictions, target, group);
+        (gradient, hessian)
+    }
 }
 
 
 /// Some well-known loss functions.
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// 
+/// The Huber and the quantile losses have zero second derivatives
+/// on (a part of) the real line,
+/// which makes the Newton steps `-G / (H + λ)` of
+/// [`RegressionTree`](crate::weak_learner::RegressionTree) unstable.
+/// Following LightGBM, [`LossFunction::hessian`] of these losses
+/// returns `1` for all examples,
+/// so that the trees fit the (clipped) residuals by least squares
+/// and [`LossFunction::line_search`] determines the scale.
+#[derive(Clone, Copy, PartialEq)]
 pub enum GBMLoss {
     /// `L1`-loss.
     /// This loss function is also known as
@@ -53,15 +185,95 @@ pub enum GBMLoss {
     L2,
 
 
-    // /// Huber loss with parameter `delta`.
-    // /// Huber loss maps the given scalar `z` to
-    // /// `0.5 * z.powi(2)` if `z.abs() < delta`,
-    // /// `delta * (z.abs() - 0.5 * delta)`, otherwise.
-    // Huber(f64),
+    /// Logistic loss, also known as the **binomial deviance**.
+    /// The target values are assumed to be `+1` or `-1`,
+    /// and the predictions are regarded as the log-odds of `+1`.
+    /// That is, this loss maps the prediction `F` for label `y` to
+    /// `ln( 1 + exp(-y * F) )`.
+    /// Wrap the output of [`GBM`](crate::booster::GBM)
+    /// by [`LogisticClassifier`](crate::LogisticClassifier)
+    /// to get the probabilities of `+1`.
+    Logistic,
+
 
+    /// Exponential loss.
+    /// The target values are assumed to be `+1` or `-1`.
+    /// This loss maps the prediction `F` for label `y` to
+    /// `exp(-y * F)`.
+    Exponential,
 
-    // /// Quantile loss
-    // Quantile(f64),
+
+    /// Huber loss with parameter `delta > 0`.
+    /// Huber loss maps the residual `z = y - F` to
+    /// `0.5 * z.powi(2)` if `z.abs() <= delta`,
+    /// `delta * (z.abs() - 0.5 * delta)`, otherwise.
+    /// The examples with large residuals affect the trees
+    /// only through the clipped residuals `delta * z.signum()`.
+    Huber(f64),
+
+
+    /// Quantile loss, also known as the **pinball loss**,
+    /// with the quantile level `alpha` in `(0, 1)`.
+    /// This loss maps the residual `z = y - F` to
+    /// `alpha * z` if `z >= 0`,
+    /// `(alpha - 1) * z`, otherwise.
+    /// The minimizer of this loss is the `alpha`-quantile
+    /// of the target values;
+    /// `Quantile(0.5)` is the half of the `L1`-loss.
+    Quantile(f64),
+
+
+    /// Poisson deviance for count targets `y >= 0`
+    /// with the log link.
+    /// The predictions are regarded as the log-mean `F = ln(mu)`,
+    /// and this loss maps `F` to `exp(F) - y * F`.
+    /// Wrap the output of [`GBM`](crate::booster::GBM)
+    /// by [`LogLinkRegressor`](crate::LogLinkRegressor)
+    /// to predict the mean `exp(F)`.
+    Poisson,
+
+
+    /// Gamma deviance for positive targets `y > 0`
+    /// with the log link.
+    /// This loss maps the log-mean `F` to `y * exp(-F) + F`.
+    Gamma,
+
+
+    /// Tweedie deviance with the power `rho` in `(1, 2)`
+    /// for non-negative targets with the log link.
+    /// This loss maps the log-mean `F` to
+    /// `- y * exp((1 - rho) * F) / (1 - rho) + exp((2 - rho) * F) / (2 - rho)`.
+    /// The Tweedie distribution interpolates
+    /// the Poisson (`rho -> 1`) and the Gamma (`rho -> 2`) distributions,
+    /// and it is a standard model for insurance claims,
+    /// which are zero with a positive probability.
+    Tweedie(f64),
+}
+
+
+impl GBMLoss {
+    /// Check the parameter of `self`.
+    #[inline]
+    fn check_parameter(&self) {
+        match self {
+            Self::Huber(delta) => {
+                assert!(*delta > 0.0, "The Huber loss requires `delta > 0`");
+            },
+            Self::Quantile(alpha) => {
+                assert!(
+                    0.0 < *alpha && *alpha < 1.0,
+                    "The quantile loss requires `0 < alpha < 1`"
+                );
+            },
+            Self::Tweedie(rho) => {
+                assert!(
+                    1.0 < *rho && *rho < 2.0,
+                    "The Tweedie loss requires `1 < rho < 2`"
+                );
+            },
+            _ => {},
+        }
+    }
 }
 
 
@@ -70,7 +282,13 @@ impl LossFunction for GBMLoss {
         match self {
             Self::L1 => "L1 loss",
             Self::L2 => "L2 loss",
-            // Self::Huber(_) => "Huber loss",
+            Self::Logistic => "Logistic loss",
+            Self::Exponential => "Exponential loss",
+            Self::Huber(_) => "Huber loss",
+            Self::Quantile(_) => "Quantile loss",
+            Self::Poisson => "Poisson loss",
+            Self::Gamma => "Gamma loss",
+            Self::Tweedie(_) => "Tweedie loss",
         }
     }
 
@@ -79,14 +297,28 @@ impl LossFunction for GBMLoss {
         match self {
             Self::L1 => (prediction - true_value).abs(),
             Self::L2 => (prediction - true_value).powi(2),
-            // Self::Huber(delta) => {
-            //     let diff = (prediction - true_value).abs();
-            //     if diff < *delta {
-            //         0.5 * diff.powi(2)
-            //     } else {
-            //         delta * (diff - 0.5 * delta)
-            //     }
-            // },
+            Self::Logistic => softplus(-true_value * prediction),
+            Self::Exponential => (-true_value * prediction).exp(),
+            Self::Huber(delta) => {
+                let diff = (true_value - prediction).abs();
+                if diff <= *delta {
+                    0.5 * diff.powi(2)
+                } else {
+                    delta * (diff - 0.5 * delta)
+                }
+            },
+            Self::Quantile(alpha) => {
+                let diff = true_value - prediction;
+                if diff >= 0.0 { alpha * diff } else { (alpha - 1.0) * diff }
+            },
+            Self::Poisson => prediction.exp() - true_value * prediction,
+            Self::Gamma => true_value * (-prediction).exp() + prediction,
+            Self::Tweedie(rho) => {
+                let a = 1.0 - rho;
+                let b = 2.0 - rho;
+                - true_value * (a * prediction).exp() / a
+                    + (b * prediction).exp() / b
+            },
         }
     }
 
@@ -101,7 +333,7 @@ impl LossFunction for GBMLoss {
             Self::L1 => {
                 target.iter()
                     .zip(predictions)
-                    .map(|(y, p)| (y - p).signum())
+                    .map(|(y, p)| (p - y).signum())
                     .collect()
             },
             Self::L2 => {
@@ -110,19 +342,60 @@ impl LossFunction for GBMLoss {
                     .map(|(y, p)| p - y)
                     .collect()
             },
-            // Self::Huber(delta) => {
-            //     target.iter()
-            //         .zip(predictions)
-            //         .map(|(y, p)| {
-            //             let diff = y - p;
-            //             if diff.abs() < *delta {
-            //                 -diff
-            //             } else {
-            //                 delta * diff.signum()
-            //             }
-            //         })
-            //         .collect::<Vec<_>>()
-            // },
+            Self::Logistic => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| -y * sigmoid(-y * p))
+                    .collect()
+            },
+            Self::Exponential => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| -y * (-y * p).exp())
+                    .collect()
+            },
+            Self::Huber(delta) => {
+                self.check_parameter();
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| {
+                        let diff = y - p;
+                        if diff.abs() <= *delta {
+                            -diff
+                        } else {
+                            -delta * diff.signum()
+                        }
+                    })
+                    .collect()
+            },
+            Self::Quantile(alpha) => {
+                self.check_parameter();
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| if y > p { -alpha } else { 1.0 - alpha })
+                    .collect()
+            },
+            Self::Poisson => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| p.exp() - y)
+                    .collect()
+            },
+            Self::Gamma => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| 1.0 - y * (-p).exp())
+                    .collect()
+            },
+            Self::Tweedie(rho) => {
+                self.check_parameter();
+                let a = 1.0 - rho;
+                let b = 2.0 - rho;
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| - y * (a * p).exp() + (b * p).exp())
+                    .collect()
+            },
         }
     }
 
@@ -143,19 +416,55 @@ impl LossFunction for GBMLoss {
                     .take(n_sample)
                     .collect()
             },
-            // Self::Huber(delta) => {
-            //     target.iter()
-            //         .zip(predictions)
-            //         .map(|(y, p)| {
-            //             let diff = (y - p).abs();
-            //             if diff < *delta { 1f64 } else { 0f64 }
-            //         })
-            //         .collect::<Vec<_>>()
-            // },
+            Self::Logistic => {
+                predictions.iter()
+                    .map(|p| {
+                        let prob = sigmoid(*p);
+                        prob * (1f64 - prob)
+                    })
+                    .collect()
+            },
+            Self::Exponential => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| (-y * p).exp())
+                    .collect()
+            },
+            Self::Huber(_) | Self::Quantile(_) => {
+                // See the documentation of `GBMLoss`.
+                vec![1f64; n_sample]
+            },
+            Self::Poisson => {
+                predictions.iter()
+                    .map(|p| p.exp())
+                    .collect()
+            },
+            Self::Gamma => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| y * (-p).exp())
+                    .collect()
+            },
+            Self::Tweedie(rho) => {
+                self.check_parameter();
+                let a = 1.0 - rho;
+                let b = 2.0 - rho;
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| - a * y * (a * p).exp() + b * (b * p).exp())
+                    .collect()
+            },
         }
     }
 
 
+    fn has_hessian(&self) -> bool {
+        // `L1` has zero second derivative, and
+        // the Huber and the quantile losses return `1` instead of it.
+        !matches!(self, Self::L1 | Self::Huber(_) | Self::Quantile(_))
+    }
+
+
     fn best_coefficient(
         &self, 
         targets: &[f64],
@@ -181,8 +490,1087 @@ impl LossFunction for GBMLoss {
 
                 y_sum / p_sum
             },
+            Self::Logistic => {
+                // A single Newton step from the zero coefficient.
+                let yp_sum = targets.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| y * p)
+                    .sum::<f64>();
+                let pp_sum = predictions.iter()
+                    .map(|p| p * p)
+                    .sum::<f64>();
+
+                if pp_sum == 0.0 { return 0.0; }
+
+                2.0 * yp_sum / pp_sum
+            },
+            Self::Exponential => {
+                // A single Newton step from the zero coefficient.
+                let yp_sum = targets.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| y * p)
+                    .sum::<f64>();
+                let pp_sum = predictions.iter()
+                    .map(|p| p * p)
+                    .sum::<f64>();
+
+                if pp_sum == 0.0 { return 0.0; }
+
+                yp_sum / pp_sum
+            },
+            Self::Huber(_) | Self::Quantile(_)
+                | Self::Poisson | Self::Gamma | Self::Tweedie(_) =>
+            {
+                let current = vec![0f64; targets.len()];
+                self.line_search(&current[..], predictions, targets)
+            },
+        }
+    }
+
+
+    /// The optimal constant for `self`.
+    /// For example, the mean for `L2`, the median for `L1`,
+    /// the log-odds for `Logistic`, and the log-mean for `Poisson`.
+    fn base_score(&self, target: &[f64]) -> f64 {
+        let n_sample = target.len();
+        if n_sample == 0 { return 0.0; }
+
+        let current = vec![0f64; n_sample];
+        let direction = vec![1f64; n_sample];
+        self.line_search(&current[..], &direction[..], target)
+    }
+
+
+    fn check_target(&self, target: &[f64]) {
+        match self {
+            Self::Poisson | Self::Tweedie(_) => {
+                assert!(
+                    target.iter().all(|y| *y >= 0.0),
+                    "{} requires non-negative targets `y >= 0`",
+                    self.name()
+                );
+            },
+            Self::Gamma => {
+                assert!(
+                    target.iter().all(|y| *y > 0.0),
+                    "{} requires positive targets `y > 0`",
+                    self.name()
+                );
+            },
+            _ => {},
+        }
+    }
+
+
+    fn line_search(
+        &self,
+        current: &[f64],
+        direction: &[f64],
+        target: &[f64],
+    ) -> f64
+    {
+        let n_sample = current.len();
+        assert_eq!(n_sample, direction.len());
+        assert_eq!(n_sample, target.len());
+
+        match self {
+            Self::L1 => {
+                // The weighted median of the residual ratios.
+                let mut items = target.iter()
+                    .zip(current)
+                    .zip(direction)
+                    .filter_map(|((y, f), &d)| {
+                        if d == 0.0 { None } else { Some((d.abs(), (y - f) / d)) }
+                    })
+                    .collect::<Vec<_>>();
+
+                if items.is_empty() { return 0.0; }
+
+                weighted_median(&mut items[..])
+            },
+            Self::L2 => {
+                // The least-squares fit of the residuals.
+                let rd_sum = target.iter()
+                    .zip(current)
+                    .zip(direction)
+                    .map(|((y, f), d)| (y - f) * d)
+                    .sum::<f64>();
+                let dd_sum = direction.iter()
+                    .map(|d| d * d)
+                    .sum::<f64>();
+
+                if dd_sum == 0.0 { return 0.0; }
+
+                rd_sum / dd_sum
+            },
+            Self::Logistic | Self::Exponential
+                | Self::Poisson | Self::Gamma | Self::Tweedie(_) =>
+            {
+                newton_line_search(self, current, direction, target, &[])
+            },
+            Self::Huber(delta) => {
+                self.check_parameter();
+                huber_line_search(*delta, current, direction, target)
+            },
+            Self::Quantile(alpha) => {
+                self.check_parameter();
+                // Along the direction `d`, the loss of the `i`th example
+                // changes its slope at `c = (y - F) / d`
+                // from `-alpha * d` to `(1 - alpha) * d` if `d > 0`,
+                // from `(alpha - 1) * d` to `-alpha * d` if `d < 0`.
+                let mut items = target.iter()
+                    .zip(current)
+                    .zip(direction)
+                    .filter_map(|((y, f), &d)| {
+                        if d == 0.0 { return None; }
+                        let (before, after) = if d > 0.0 {
+                            (alpha * d, (1.0 - alpha) * d)
+                        } else {
+                            ((1.0 - alpha) * -d, alpha * -d)
+                        };
+                        Some(((y - f) / d, before, after))
+                    })
+                    .collect::<Vec<_>>();
+
+                if items.is_empty() { return 0.0; }
+
+                piecewise_linear_minimizer(&mut items[..])
+            },
+        }
+    }
+}
+
+
+/// The LambdaRank objective for learning to rank.
+/// Combined with [`GBM`](crate::booster::GBM)
+/// and [`RegressionTree`](crate::weak_learner::RegressionTree),
+/// this loss yields **LambdaMART**.
+/// 
+/// This struct is based on the paper:
+/// [From RankNet to LambdaRank to LambdaMART: An Overview](https://www.microsoft.com/en-us/research/publication/from-ranknet-to-lambdarank-to-lambdamart-an-overview/)
+/// by Christopher J.C. Burges.
+/// 
+/// The target values are the relevance labels (non-negative numbers),
+/// and the examples are grouped by [`Sample::set_group`](crate::Sample::set_group).
+/// For each pair `(i, j)` in the same query
+/// such that `i` is more relevant than `j`,
+/// `LambdaRank` pushes the score of `i` up and the one of `j` down
+/// by the RankNet gradient scaled by `|ΔNDCG|`,
+/// the change of NDCG by swapping `i` and `j`.
+/// `LambdaRank` requires the queries
+/// since the cost of a query grows quadratically in its size.
+/// The methods of `LambdaRank` panic if the queries are not specified.
+/// 
+/// The loss value is `1 - NDCG`, averaged over the queries.
+/// Note that the loss of a single example is always `0`
+/// since a list of one item has NDCG `1`.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("relevance")
+///     .group_feature("query")
+///     .read()
+///     .unwrap();
+/// 
+/// let loss = LambdaRank::new().truncation(10);
+/// let mut booster = GBM::init_with_loss(&sample, loss);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(3)
+///     .loss(loss)
+///     .build();
+/// 
+/// let f = booster.run(&weak_learner);
+/// let scores = f.predict_all(&sample);
+/// let ndcg = 1.0 - loss.eval_grouped(&scores, sample.target(), sample.group());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LambdaRank {
+    sigma: f64,
+    truncation: Option<usize>,
+}
+
+
+impl LambdaRank {
+    /// Construct a new instance of `LambdaRank`.
+    /// By default, the shape parameter of the sigmoid is `1.0`
+    /// and NDCG is not truncated.
+    pub fn new() -> Self {
+        Self { sigma: 1.0, truncation: None, }
+    }
+
+
+    /// Set the shape parameter `sigma` of the sigmoid
+    /// in the RankNet gradient.
+    /// Default value is `1.0`.
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        assert!(sigma > 0.0, "`sigma` must be positive");
+        self.sigma = sigma;
+        self
+    }
+
+
+    /// Optimize `NDCG@k` instead of NDCG.
+    /// The examples ranked below `k` do not contribute to the DCG.
+    pub fn truncation(mut self, k: usize) -> Self {
+        assert!(k > 0, "The truncation level must be positive");
+        self.truncation = Some(k);
+        self
+    }
+
+
+    /// Returns the discount of the `rank`th position (0-indexed).
+    #[inline(always)]
+    fn discount(&self, rank: usize) -> f64 {
+        match self.truncation {
+            Some(k) if rank >= k => 0.0,
+            _ => 1.0 / (rank as f64 + 2.0).log2(),
+        }
+    }
+
+
+    /// Returns the ideal DCG of the query with relevance labels `target`.
+    fn ideal_dcg(&self, ix: &[usize], target: &[f64]) -> f64 {
+        let mut gains = ix.iter()
+            .map(|&i| gain(target[i]))
+            .collect::<Vec<_>>();
+        gains.sort_by(|a, b| b.total_cmp(a));
+        gains.into_iter()
+            .enumerate()
+            .map(|(r, g)| g * self.discount(r))
+            .sum::<f64>()
+    }
+
+
+    /// Returns the indices of `ix` sorted by descending order of the scores.
+    fn ranking(ix: &[usize], predictions: &[f64]) -> Vec<usize> {
+        let mut ranked = ix.to_vec();
+        ranked.sort_by(|&i, &j| predictions[j].total_cmp(&predictions[i]));
+        ranked
+    }
+
+
+    /// Returns the pair `(gradient, hessian)` of the LambdaRank objective.
+    fn lambdas(&self, predictions: &[f64], target: &[f64], group: &[usize])
+        -> (Vec<f64>, Vec<f64>)
+    {
+        let n_sample = predictions.len();
+        assert_eq!(n_sample, target.len());
+        assert_queries(group);
+
+        let mut gradient = vec![0f64; n_sample];
+        let mut hessian = vec![0f64; n_sample];
+        for ix in queries(group, n_sample) {
+            let ideal = self.ideal_dcg(&ix[..], target);
+            if ideal <= 0.0 { continue; }
+
+            let ranked = Self::ranking(&ix[..], predictions);
+            for (ri, &i) in ranked.iter().enumerate() {
+                for (rj, &j) in ranked.iter().enumerate() {
+                    if target[i] <= target[j] { continue; }
+
+                    // `i` should be ranked above `j`.
+                    let delta = (gain(target[i]) - gain(target[j]))
+                        * (self.discount(ri) - self.discount(rj))
+                        / ideal;
+                    let delta = delta.abs();
+                    if delta == 0.0 { continue; }
+
+                    let rho = sigmoid(
+                        -self.sigma * (predictions[i] - predictions[j])
+                    );
+                    let lambda = self.sigma * rho * delta;
+                    let h = self.sigma.powi(2) * rho * (1.0 - rho) * delta;
+                    gradient[i] -= lambda;
+                    gradient[j] += lambda;
+                    hessian[i] += h;
+                    hessian[j] += h;
+                }
+            }
+        }
+        (gradient, hessian)
+    }
+}
+
+
+impl Default for LambdaRank {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl LossFunction for LambdaRank {
+    fn name(&self) -> &str {
+        "LambdaRank"
+    }
+
+
+    fn eval_at_point(&self, _prediction: f64, _true_value: f64) -> f64 {
+        0.0
+    }
+
+
+    fn eval(&self, predictions: &[f64], target: &[f64]) -> f64 {
+        self.eval_grouped(predictions, target, &[])
+    }
+
+
+    fn gradient(&self, predictions: &[f64], target: &[f64]) -> Vec<f64> {
+        self.gradient_grouped(predictions, target, &[])
+    }
+
+
+    fn hessian(&self, predictions: &[f64], target: &[f64]) -> Vec<f64> {
+        self.hessian_grouped(predictions, target, &[])
+    }
+
+
+    fn best_coefficient(
+        &self, 
+        _targets: &[f64],
+        _predictions: &[f64],
+    ) -> f64
+    {
+        // The leaves of the regression tree
+        // already take a Newton step on the LambdaRank objective.
+        1.0
+    }
+
+
+    fn eval_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> f64
+    {
+        let n_sample = predictions.len();
+        assert_eq!(n_sample, target.len());
+        assert_queries(group);
+
+        let ndcgs = queries(group, n_sample)
+            .into_iter()
+            .filter_map(|ix| {
+                let ideal = self.ideal_dcg(&ix[..], target);
+                if ideal <= 0.0 { return None; }
+
+                let dcg = Self::ranking(&ix[..], predictions)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(r, i)| gain(target[i]) * self.discount(r))
+                    .sum::<f64>();
+                Some(dcg / ideal)
+            })
+            .collect::<Vec<_>>();
+
+        if ndcgs.is_empty() { return 0.0; }
+        1.0 - ndcgs.iter().sum::<f64>() / ndcgs.len() as f64
+    }
+
+
+    fn gradient_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> Vec<f64>
+    {
+        self.lambdas(predictions, target, group).0
+    }
+
+
+    fn hessian_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> Vec<f64>
+    {
+        self.lambdas(predictions, target, group).1
+    }
+
+
+    fn gradient_and_hessian_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> (Vec<f64>, Vec<f64>)
+    {
+        self.lambdas(predictions, target, group)
+    }
+}
+
+
+/// Panics if the queries are not specified.
+#[inline(always)]
+fn assert_queries(group: &[usize]) {
+    assert!(
+        !group.is_empty(),
+        "LambdaRank requires the queries. \
+         Specify them by `Sample::set_group`."
+    );
+}
+
+
+/// The negative log partial likelihood
+/// of the Cox proportional hazards model for the survival analysis.
+/// 
+/// The Cox model assumes that the hazard of the `i`th example is
+/// `h0(t) * exp( F(x_i) )`,
+/// where `h0` is the (unknown) baseline hazard
+/// and `F` is the ensemble trained by [`GBM`](crate::booster::GBM).
+/// Thus, the predictions of the ensemble are the log hazard ratios;
+/// a larger score means an earlier event.
+/// Wrap the ensemble with
+/// [`LogLinkRegressor`](crate::hypothesis::LogLinkRegressor)
+/// to predict the hazard ratios `exp( F(x) )`.
+/// 
+/// The target values encode the pairs `(time, event)`
+/// as in XGBoost's `survival:cox`:
+/// `target[i] > 0` is the time of the observed event,
+/// and `target[i] < 0` is the negated censoring time.
+/// [`Sample::set_event`](crate::Sample::set_event)
+/// (or [`SampleReader::event_feature`](crate::SampleReader::event_feature))
+/// encodes the target values in this way.
+/// 
+/// The loss value is
+/// ```txt
+/// 1/n * sum_{i: event} [ ln( sum_{j: t_j >= t_i} exp( F(x_j) ) ) - F(x_i) ],
+/// ```
+/// where the ties of the times are handled by the Breslow approximation.
+/// If the examples are grouped by
+/// [`Sample::set_group`](crate::Sample::set_group),
+/// the groups are regarded as the strata,
+/// i.e., the risk sets are taken within each group.
+/// Note that [`LossFunction::line_search`] ignores the strata.
+/// 
+/// Since the partial likelihood is invariant
+/// under adding a constant to all the scores,
+/// the base score is `0`.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("time")
+///     .event_feature("event")
+///     .read()
+///     .unwrap();
+/// 
+/// let loss = CoxPH::new();
+/// let mut booster = GBM::init_with_loss(&sample, loss);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(loss)
+///     .build();
+/// 
+/// let f = booster.run(&weak_learner);
+/// let scores = f.predict_all(&sample);
+/// let c_index = loss.concordance_index(&scores, sample.target());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CoxPH;
+
+
+impl CoxPH {
+    /// Construct a new instance of `CoxPH`.
+    pub fn new() -> Self {
+        Self
+    }
+
+
+    /// Returns Harrell's concordance index of the risk scores `predictions`
+    /// for the survival times `target` encoded as in [`CoxPH`].
+    /// 
+    /// A pair `(i, j)` is comparable
+    /// if the event of `i` is observed and `t_i < t_j`.
+    /// A comparable pair is concordant if `predictions[i] > predictions[j]`,
+    /// and a tie of the predictions counts as a half.
+    /// The concordance index is the ratio of the concordant pairs
+    /// to the comparable pairs;
+    /// `1.0` is the perfect ranking and `0.5` is the random one.
+    /// Returns `NaN` if there is no comparable pair.
+    pub fn concordance_index(&self, predictions: &[f64], target: &[f64])
+        -> f64
+    {
+        let n_sample = predictions.len();
+        assert_eq!(n_sample, target.len());
+
+        let mut concordant = 0.0;
+        let mut comparable = 0_usize;
+        for i in 0..n_sample {
+            if target[i] <= 0.0 { continue; }
+            let time = target[i];
+            for j in 0..n_sample {
+                if target[j].abs() <= time { continue; }
+
+                comparable += 1;
+                if predictions[i] > predictions[j] {
+                    concordant += 1.0;
+                } else if predictions[i] == predictions[j] {
+                    concordant += 0.5;
+                }
+            }
+        }
+
+        concordant / comparable as f64
+    }
+
+
+    /// Returns the triple `(loss, gradient, hessian)`
+    /// of the negative log partial likelihood,
+    /// where `loss` is not divided by the number of examples.
+    fn partial_likelihood(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> (f64, Vec<f64>, Vec<f64>)
+    {
+        let n_sample = predictions.len();
+        assert_eq!(n_sample, target.len());
+
+        let mut loss = 0.0;
+        let mut gradient = vec![0f64; n_sample];
+        let mut hessian = vec![0f64; n_sample];
+        for mut ix in queries(group, n_sample) {
+            // Shift the scores by the maximum to avoid the overflow.
+            // The ratios `risk[j] / risk_sum[i]` do not change.
+            let max = ix.iter()
+                .map(|&i| predictions[i])
+                .fold(f64::MIN, f64::max);
+            let risk = |i: usize| (predictions[i] - max).exp();
+
+            // Sort the examples by descending order of the times.
+            ix.sort_by(|&i, &j| target[j].abs().total_cmp(&target[i].abs()));
+            let blocks = tied_blocks(&ix[..], target);
+
+            // `risk_sum[k]` is the sum of the risks
+            // over the risk set of the `k`th block.
+            let mut sum = 0.0;
+            let risk_sum = blocks.iter()
+                .map(|block| {
+                    sum += block.iter().map(|&i| risk(i)).sum::<f64>();
+                    sum
+                })
+                .collect::<Vec<_>>();
+
+            // Visit the blocks in the ascending order of the times,
+            // accumulating the terms of the events so far.
+            let (mut a, mut b) = (0.0, 0.0);
+            for (block, s) in blocks.iter().zip(risk_sum).rev() {
+                for &i in block.iter() {
+                    if target[i] <= 0.0 { continue; }
+                    loss += s.ln() + max - predictions[i];
+                    a += 1.0 / s;
+                    b += 1.0 / (s * s);
+                }
+                for &i in block.iter() {
+                    let r = risk(i);
+                    let event = if target[i] > 0.0 { 1.0 } else { 0.0 };
+                    gradient[i] = r * a - event;
+                    hessian[i] = r * a - r * r * b;
+                }
+            }
+        }
+        (loss, gradient, hessian)
+    }
+}
+
+
+impl LossFunction for CoxPH {
+    fn name(&self) -> &str {
+        "Cox Proportional Hazards"
+    }
+
+
+    fn eval_at_point(&self, _prediction: f64, _true_value: f64) -> f64 {
+        0.0
+    }
+
+
+    fn eval(&self, predictions: &[f64], target: &[f64]) -> f64 {
+        self.eval_grouped(predictions, target, &[])
+    }
+
+
+    fn gradient(&self, predictions: &[f64], target: &[f64]) -> Vec<f64> {
+        self.gradient_grouped(predictions, target, &[])
+    }
+
+
+    fn hessian(&self, predictions: &[f64], target: &[f64]) -> Vec<f64> {
+        self.hessian_grouped(predictions, target, &[])
+    }
+
+
+    fn best_coefficient(
+        &self, 
+        targets: &[f64],
+        predictions: &[f64],
+    ) -> f64
+    {
+        let zeros = vec![0.0; targets.len()];
+        newton_line_search(self, &zeros[..], predictions, targets, &[])
+    }
+
+
+    fn line_search(
+        &self,
+        current: &[f64],
+        direction: &[f64],
+        target: &[f64],
+    ) -> f64
+    {
+        self.line_search_grouped(current, direction, target, &[])
+    }
+
+
+    fn line_search_grouped(
+        &self,
+        current: &[f64],
+        direction: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> f64
+    {
+        newton_line_search(self, current, direction, target, group)
+    }
+
+
+    fn eval_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> f64
+    {
+        let n_sample = predictions.len();
+        self.partial_likelihood(predictions, target, group).0
+            / n_sample as f64
+    }
+
+
+    fn gradient_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> Vec<f64>
+    {
+        self.partial_likelihood(predictions, target, group).1
+    }
+
+
+    fn hessian_grouped(
+        &self,
+        predictions: &[f64],
+        target: &[f64],
+        group: &[usize],
+    ) -> Vec<f64>
+    {
+        self.partial_likelihood(predictions, target, group).2
+    }
+}
+
+
+/// Splits `ix`, sorted by the survival times,
+/// into the blocks of the same time.
+fn tied_blocks(ix: &[usize], target: &[f64]) -> Vec<Vec<usize>> {
+    let mut blocks: Vec<Vec<usize>> = Vec::new();
+    for &i in ix {
+        match blocks.last_mut() {
+            Some(block) if target[block[0]].abs() == target[i].abs() => {
+                block.push(i);
+            },
+            _ => { blocks.push(vec![i]); },
         }
     }
+    blocks
+}
+
+
+/// This trait defines the loss functions on the vector-valued predictions
+/// for the multi-class classification.
+/// The target values are the class labels `0`, `1`, ..., `K-1`,
+/// and `predictions[k][i]` is the score of the class `k`
+/// for the `i`th example.
+/// This is the vector-valued counterpart of [`LossFunction`].
+pub trait MultiClassLossFunction {
+    /// The name of the loss function.
+    fn name(&self) -> &str;
+
+
+    /// Loss value for the scores `predictions`,
+    /// averaged over the examples.
+    fn eval(&self, predictions: &[Vec<f64>], target: &[f64]) -> f64;
+
+
+    /// Gradient at the current point.
+    /// `gradient[k][i]` is the partial derivative of the loss
+    /// w.r.t. `predictions[k][i]`.
+    fn gradient(&self, predictions: &[Vec<f64>], target: &[f64])
+        -> Vec<Vec<f64>>;
+
+
+    /// Hessian at the current point.
+    /// Here, this method assumes that the Hessian is diagonal,
+    /// so that `hessian[k][i]` is the second derivative
+    /// w.r.t. `predictions[k][i]`.
+    fn hessian(&self, predictions: &[Vec<f64>], target: &[f64])
+        -> Vec<Vec<f64>>;
+
+
+    /// The initial scores of the `n_class` classes,
+    /// the constant predictions that minimize the loss for `target`.
+    /// By default, this method returns zeros.
+    fn base_score(&self, _target: &[f64], n_class: usize) -> Vec<f64> {
+        vec![0.0; n_class]
+    }
+}
+
+
+/// The softmax cross-entropy loss for the multi-class classification.
+/// Given the scores `F_1(x), ..., F_K(x)`,
+/// the probability of the class `k` is
+/// ```txt
+/// p_k(x) = exp( F_k(x) ) / sum_j exp( F_j(x) ),
+/// ```
+/// and the loss of the example `(x, y)` is `-ln( p_y(x) )`.
+/// 
+/// The gradient w.r.t. `F_k(x)` is `p_k(x) - 1[y = k]`.
+/// Since the scores are invariant under adding a constant to all the classes,
+/// one of the `K` coordinates is redundant.
+/// Following Friedman (2001), the diagonal of the Hessian is
+/// `K / (K - 1) * p_k(x) (1 - p_k(x))`,
+/// so that the Newton steps are scaled by `(K - 1) / K`.
+/// 
+/// The base score of the class `k` is the logarithm of
+/// the fraction of the examples labeled `k`.
+/// 
+/// See [`MultiClassGBM`](crate::booster::MultiClassGBM) for the usage.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SoftmaxLoss;
+
+
+impl SoftmaxLoss {
+    /// Construct a new instance of `SoftmaxLoss`.
+    pub fn new() -> Self {
+        Self
+    }
+
+
+    /// Returns the class probabilities `p[k][i]`
+    /// for the scores `predictions[k][i]`.
+    pub fn probabilities(&self, predictions: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        let n_class = predictions.len();
+        let n_sample = predictions.first().map_or(0, |p| p.len());
+
+        let mut probabilities = vec![vec![0f64; n_sample]; n_class];
+        for i in 0..n_sample {
+            let scores = predictions.iter()
+                .map(|p| p[i])
+                .collect::<Vec<_>>();
+            softmax(&scores[..])
+                .into_iter()
+                .zip(probabilities.iter_mut())
+                .for_each(|(p, prob)| { prob[i] = p; });
+        }
+        probabilities
+    }
+}
+
+
+impl MultiClassLossFunction for SoftmaxLoss {
+    fn name(&self) -> &str {
+        "Softmax cross-entropy"
+    }
+
+
+    fn eval(&self, predictions: &[Vec<f64>], target: &[f64]) -> f64 {
+        let n_sample = target.len();
+        let probabilities = self.probabilities(predictions);
+
+        target.iter()
+            .enumerate()
+            .map(|(i, &y)| {
+                let p = probabilities[y as usize][i];
+                - p.max(f64::MIN_POSITIVE).ln()
+            })
+            .sum::<f64>()
+            / n_sample as f64
+    }
+
+
+    fn gradient(&self, predictions: &[Vec<f64>], target: &[f64])
+        -> Vec<Vec<f64>>
+    {
+        let mut gradient = self.probabilities(predictions);
+        target.iter()
+            .enumerate()
+            .for_each(|(i, &y)| { gradient[y as usize][i] -= 1.0; });
+        gradient
+    }
+
+
+    fn hessian(&self, predictions: &[Vec<f64>], _target: &[f64])
+        -> Vec<Vec<f64>>
+    {
+        let n_class = predictions.len() as f64;
+        let scale = n_class / (n_class - 1.0);
+        let mut hessian = self.probabilities(predictions);
+        hessian.iter_mut()
+            .flatten()
+            .for_each(|p| { *p = scale * *p * (1.0 - *p); });
+        hessian
+    }
+
+
+    fn base_score(&self, target: &[f64], n_class: usize) -> Vec<f64> {
+        let n_sample = target.len() as f64;
+        let mut counts = vec![0f64; n_class];
+        target.iter()
+            .for_each(|&y| { counts[y as usize] += 1.0; });
+
+        // Avoid `ln(0)` for the classes without examples.
+        counts.into_iter()
+            .map(|c| (c.max(1e-12) / n_sample).ln())
+            .collect()
+    }
+}
+
+
+/// Returns the softmax of `scores` without overflow.
+#[inline(always)]
+pub(crate) fn softmax(scores: &[f64]) -> Vec<f64> {
+    let max = scores.iter()
+        .copied()
+        .fold(f64::MIN, f64::max);
+    let exps = scores.iter()
+        .map(|s| (s - max).exp())
+        .collect::<Vec<_>>();
+    let total = exps.iter().sum::<f64>();
+    exps.into_iter()
+        .map(|e| e / total)
+        .collect()
+}
+
+
+/// Returns the gain `2^rel - 1` of the relevance label `rel`.
+#[inline(always)]
+fn gain(relevance: f64) -> f64 {
+    relevance.exp2() - 1.0
+}
+
+
+/// Returns the indices of the examples for each query.
+/// If `group` is empty, all the examples form a single query.
+fn queries(group: &[usize], n_sample: usize) -> Vec<Vec<usize>> {
+    if group.is_empty() {
+        return vec![(0..n_sample).collect()];
+    }
+    assert_eq!(
+        group.len(), n_sample,
+        "The number of query groups does not match the number of examples"
+    );
+
+    let mut queries = BTreeMap::<usize, Vec<usize>>::new();
+    group.iter()
+        .enumerate()
+        .for_each(|(i, &g)| { queries.entry(g).or_default().push(i); });
+    queries.into_values().collect()
+}
+
+
+/// Returns `1 / (1 + exp(-x))` without overflow.
+#[inline(always)]
+pub(crate) fn sigmoid(x: f64) -> f64 {
+    if x >= 0.0 {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (1.0 + e)
+    }
+}
+
+
+/// Returns `ln( 1 + exp(x) )` without overflow.
+#[inline(always)]
+fn softplus(x: f64) -> f64 {
+    if x > 0.0 {
+        x + (-x).exp().ln_1p()
+    } else {
+        x.exp().ln_1p()
+    }
+}
+
+
+/// Minimizes the convex function `c -> loss.eval(current + c * direction)`
+/// by the damped Newton method starting from `c = 0`.
+/// Each step is halved until the loss does not increase.
+/// The loss, the gradient, and the Hessian are the grouped ones,
+/// where `group` is empty if the queries are not specified.
+fn newton_line_search<L>(
+    loss: &L,
+    current: &[f64],
+    direction: &[f64],
+    target: &[f64],
+    group: &[usize],
+) -> f64
+    where L: LossFunction + ?Sized,
+{
+    const MAX_ITER: usize = 20;
+    const MAX_HALVING: usize = 60;
+    const TOLERANCE: f64 = 1e-9;
+
+    let point = |c: f64| -> Vec<f64> {
+        current.iter()
+            .zip(direction)
+            .map(|(f, d)| f + c * d)
+            .collect()
+    };
+
+    let mut coef = 0.0;
+    let mut value = loss.eval_grouped(current, target, group);
+    for _ in 0..MAX_ITER {
+        let p = point(coef);
+        let grad = loss.gradient_grouped(&p[..], target, group)
+            .into_iter()
+            .zip(direction)
+            .map(|(g, d)| g * d)
+            .sum::<f64>();
+        let hess = loss.hessian_grouped(&p[..], target, group)
+            .into_iter()
+            .zip(direction)
+            .map(|(h, d)| h * d * d)
+            .sum::<f64>();
+
+        // The loss may overflow for the extreme scores.
+        if !grad.is_finite() || !hess.is_finite() || hess <= 0.0 { break; }
+
+        let mut step = grad / hess;
+        if !step.is_finite() { break; }
+
+        let mut accepted = false;
+        for _ in 0..MAX_HALVING {
+            let next = loss.eval_grouped(
+                &point(coef - step)[..], target, group
+            );
+            if next.is_finite() && (next <= value || step.abs() <= TOLERANCE) {
+                value = next;
+                accepted = true;
+                break;
+            }
+            step *= 0.5;
+        }
+        if !accepted { break; }
+        coef -= step;
+
+        if step.abs() <= TOLERANCE * (1.0 + coef.abs()) { break; }
+    }
+    coef
+}
+
+
+/// Minimizes the Huber loss of `current + c * direction`
+/// by the damped Newton method starting from `c = 0`.
+/// If the second derivative vanishes,
+/// i.e., all the examples are in the linear zone,
+/// the step is the gradient step of size `1 / sum( d^2 )`.
+fn huber_line_search(
+    delta: f64,
+    current: &[f64],
+    direction: &[f64],
+    target: &[f64],
+) -> f64
+{
+    const MAX_ITER: usize = 50;
+    const TOLERANCE: f64 = 1e-9;
+
+    let loss = GBMLoss::Huber(delta);
+    let point = |c: f64| -> Vec<f64> {
+        current.iter()
+            .zip(direction)
+            .map(|(f, d)| f + c * d)
+            .collect()
+    };
+
+    let dd_sum = direction.iter().map(|d| d * d).sum::<f64>();
+    if dd_sum == 0.0 { return 0.0; }
+
+    let mut coef = 0.0;
+    let mut value = loss.eval(current, target);
+    for _ in 0..MAX_ITER {
+        let p = point(coef);
+        let (grad, hess) = target.iter()
+            .zip(&p[..])
+            .zip(direction)
+            .fold((0.0, 0.0), |(g, h), ((y, p), d)| {
+                let diff = y - p;
+                if diff.abs() <= delta {
+                    (g - diff * d, h + d * d)
+                } else {
+                    (g - delta * diff.signum() * d, h)
+                }
+            });
+
+        let hess = if hess > 0.0 { hess } else { dd_sum };
+        let mut step = grad / hess;
+        loop {
+            let next = loss.eval(&point(coef - step)[..], target);
+            if next <= value || step.abs() <= TOLERANCE {
+                value = next;
+                break;
+            }
+            step *= 0.5;
+        }
+        coef -= step;
+
+        if step.abs() <= TOLERANCE * (1.0 + coef.abs()) { break; }
+    }
+    coef
+}
+
+
+/// Returns a minimizer of the convex piecewise linear function
+/// `c -> sum( phi_i(c) )`,
+/// where each `phi_i` is a function with a single kink at `items[i].0`,
+/// whose slope is `-items[i].1` on the left
+/// and `items[i].2` on the right.
+/// Both `items[i].1` and `items[i].2` must be non-negative.
+/// The weighted median and the weighted quantiles are special cases.
+fn piecewise_linear_minimizer(items: &mut [(f64, f64, f64)]) -> f64 {
+    assert!(!items.is_empty());
+
+    items.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    // The slope on the left of all the kinks.
+    let mut slope = - items.iter()
+        .map(|(_, before, _)| *before)
+        .sum::<f64>();
+
+    // Tolerance for the rounding errors of the slope.
+    let tolerance = 1e-12 * items.iter()
+        .map(|(_, before, after)| before + after)
+        .sum::<f64>();
+
+    for (kink, before, after) in items.iter() {
+        slope += before + after;
+        if slope >= -tolerance {
+            return *kink;
+        }
+    }
+
+    items[items.len() - 1].0
 }
 
 
@@ -0,0 +1,114 @@
+//! This file provides the privacy accountant
+//! and the noise mechanisms for differential privacy.
+use rand::Rng;
+
+
+/// A privacy accountant that records the privacy loss `(ε, δ)`
+/// spent in each round.
+///
+/// The total privacy loss is the smaller one of
+/// the basic composition theorem
+/// ```txt
+/// ( sum( ε[t] ), sum( δ[t] ) )
+/// ```
+/// and the advanced composition theorem
+/// by Dwork, Rothblum, and Vadhan
+/// ```txt
+/// ( sqrt( 2 ln(1/δ') sum( ε[t]^2 ) ) + sum( ε[t] (exp(ε[t]) - 1) ),
+///   sum( δ[t] ) + δ' ),
+/// ```
+/// where `δ'` is the slack parameter given to
+/// [`PrivacyAccountant::new`].
+#[derive(Debug, Clone, Default)]
+pub struct PrivacyAccountant {
+    // Slack parameter for the advanced composition.
+    slack: f64,
+
+    // Privacy loss `(ε, δ)` spent in each round.
+    rounds: Vec<(f64, f64)>,
+}
+
+
+impl PrivacyAccountant {
+    /// Construct a new instance of `PrivacyAccountant`
+    /// with the slack parameter `δ'` for the advanced composition.
+    /// Setting `slack = 0.0` disables the advanced composition.
+    pub fn new(slack: f64) -> Self {
+        assert!(
+            (0.0..1.0).contains(&slack),
+            "The slack parameter must be in [0.0, 1.0)"
+        );
+        Self { slack, rounds: Vec::new(), }
+    }
+
+
+    /// Record the privacy loss `(ε, δ)` spent in a new round.
+    pub(crate) fn spend(&mut self, epsilon: f64, delta: f64) {
+        self.rounds.push((epsilon, delta));
+    }
+
+
+    /// Forget the privacy losses recorded so far.
+    pub(crate) fn reset(&mut self) {
+        self.rounds.clear();
+    }
+
+
+    /// Returns the privacy loss `(ε, δ)` spent in each round.
+    pub fn rounds(&self) -> &[(f64, f64)] {
+        &self.rounds[..]
+    }
+
+
+    /// Returns the total privacy loss `(ε, δ)`
+    /// by the basic composition theorem.
+    pub fn basic(&self) -> (f64, f64) {
+        self.rounds.iter()
+            .fold((0.0, 0.0), |(e, d), (et, dt)| (e + et, d + dt))
+    }
+
+
+    /// Returns the total privacy loss `(ε, δ)`
+    /// by the advanced composition theorem.
+    /// If the slack parameter is `0.0`, the loss `ε` is infinite.
+    pub fn advanced(&self) -> (f64, f64) {
+        let (_, delta) = self.basic();
+        if self.slack <= 0.0 {
+            return (f64::INFINITY, delta);
+        }
+        let sq_sum = self.rounds.iter()
+            .map(|(e, _)| e.powi(2))
+            .sum::<f64>();
+        let drift = self.rounds.iter()
+            .map(|(e, _)| e * e.exp_m1())
+            .sum::<f64>();
+        let epsilon = (2.0 * (1.0 / self.slack).ln() * sq_sum).sqrt()
+            + drift;
+
+        (epsilon, delta + self.slack)
+    }
+
+
+    /// Returns the total privacy loss `(ε, δ)` spent so far.
+    /// This is the one with smaller `ε`
+    /// among [`PrivacyAccountant::basic`]
+    /// and [`PrivacyAccountant::advanced`].
+    pub fn spent(&self) -> (f64, f64) {
+        let basic = self.basic();
+        let advanced = self.advanced();
+
+        if advanced.0 < basic.0 { advanced } else { basic }
+    }
+}
+
+
+/// Returns a sample from the Laplace distribution
+/// with mean `0` and scale `scale`.
+#[inline(always)]
+pub(crate) fn laplace_noise<R>(scale: f64, rng: &mut R) -> f64
+    where R: Rng,
+{
+    let u = rng.gen_range(-0.5..0.5_f64);
+    let tail = (1.0 - 2.0 * u.abs()).max(f64::MIN_POSITIVE);
+    - scale * u.signum() * tail.ln()
+}
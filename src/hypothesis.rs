@@ -3,14 +3,27 @@
 pub(crate) mod hypothesis_traits;
 pub(crate) mod weighted_majority;
 pub(crate) mod naive_aggregation;
+pub(crate) mod logistic_classifier;
+pub(crate) mod log_link_regressor;
+pub(crate) mod base_score_regressor;
+pub(crate) mod softmax_classifier;
 
 
 pub use hypothesis_traits::{
     Classifier,
     Regressor,
+    MultiClassifier,
+    MultiLabelClassifier,
 };
 
-pub use weighted_majority::WeightedMajority;
+pub use weighted_majority::{
+    WeightedMajority,
+    CoverageAccuracy,
+};
 pub use naive_aggregation::NaiveAggregation;
+pub use logistic_classifier::LogisticClassifier;
+pub use log_link_regressor::LogLinkRegressor;
+pub use base_score_regressor::BaseScoreRegressor;
+pub use softmax_classifier::SoftmaxClassifier;
 
 
@@ -0,0 +1,86 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Regressor,
+    Sample,
+};
+
+
+/// A regressor that adds a constant, the base score,
+/// to the output of another regressor:
+/// ```txt
+/// F(x) = base_score + H(x).
+/// ```
+/// [`GBM`](crate::booster::GBM) starts from the base score
+/// (see [`GBM::base_score`](crate::booster::GBM::base_score))
+/// and returns this struct,
+/// so that the predictions include the base score.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// let sample = SampleReader::default()
+///     .file("/path/to/file.csv")
+///     .has_header(true)
+///     .target_feature("target")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::L2)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let base_score = f.base_score();
+/// // The ensemble of the trees without the base score.
+/// let trees = f.regressor();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BaseScoreRegressor<H> {
+    /// The constant added to the output of `regressor`.
+    base_score: f64,
+    /// The regressor that outputs the rest of the prediction.
+    regressor: H,
+}
+
+
+impl<H> BaseScoreRegressor<H> {
+    /// Construct a new `BaseScoreRegressor`
+    /// from the given base score and regressor.
+    #[inline]
+    pub fn new(base_score: f64, regressor: H) -> Self {
+        Self { base_score, regressor }
+    }
+
+
+    /// Returns the base score.
+    #[inline]
+    pub fn base_score(&self) -> f64 {
+        self.base_score
+    }
+
+
+    /// Returns the reference to the inner regressor.
+    #[inline]
+    pub fn regressor(&self) -> &H {
+        &self.regressor
+    }
+
+
+    /// Decompose `self` into the base score and the inner regressor.
+    #[inline]
+    pub fn decompose(self) -> (f64, H) {
+        (self.base_score, self.regressor)
+    }
+}
+
+
+impl<H> Regressor for BaseScoreRegressor<H>
+    where H: Regressor,
+{
+    fn predict(&self, sample: &Sample, row: usize) -> f64 {
+        self.base_score + self.regressor.predict(sample, row)
+    }
+}
@@ -33,6 +33,34 @@ pub trait Classifier {
         (0..n_sample).map(|row| self.predict(sample, row))
             .collect::<Vec<_>>()
     }
+
+
+    /// Predicts the label of the i'th row of the `df`,
+    /// or abstains from the prediction.
+    /// This method returns `0` (abstain)
+    /// if the absolute value of the confidence is at most `threshold`,
+    /// and the sign of the confidence otherwise.
+    /// With `threshold = 0.0`,
+    /// this method abstains only if `Classifier::confidence` returns `0.0`.
+    fn predict_or_abstain(&self, sample: &Sample, row: usize, threshold: f64)
+        -> i64
+    {
+        let conf = self.confidence(sample, row);
+        if conf.abs() <= threshold { 0 }
+        else if conf > 0.0 { 1 }
+        else { -1 }
+    }
+
+
+    /// Predicts the labels of `df`, or abstains from the predictions.
+    /// See [`Classifier::predict_or_abstain`].
+    fn predict_or_abstain_all(&self, sample: &Sample, threshold: f64)
+        -> Vec<i64>
+    {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_or_abstain(sample, row, threshold))
+            .collect::<Vec<_>>()
+    }
 }
 
 
@@ -54,3 +82,61 @@ pub trait Regressor {
 
 
 
+
+
+/// A trait that defines the behavor of multi-class classifier.
+/// The labels are assumed to be `0`, `1`, ..., `K-1`.
+/// You only need to implement `predict_class` method.
+pub trait MultiClassifier {
+    /// Predicts the class label of the i'th row of the `df`.
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize;
+
+
+    /// Predicts the class labels of `df`.
+    fn predict_class_all(&self, sample: &Sample) -> Vec<usize>
+    {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_class(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
+
+
+/// A trait that defines the behavor of multi-label classifier.
+/// A multi-label classifier outputs a confidence for each label.
+/// You only need to implement `confidences` method.
+pub trait MultiLabelClassifier {
+    /// Computes the confidences of the i'th row of the `df`.
+    /// The `k`th element corresponds to the `k`th label.
+    /// This code assumes that
+    /// each confidence takes a value in `[-1.0, 1.0]`.
+    fn confidences(&self, sample: &Sample, row: usize) -> Vec<f64>;
+
+
+    /// Predicts the labels of the i'th row of the `df`.
+    /// The `k`th element is `1` if the row has the `k`th label,
+    /// `-1` otherwise.
+    fn predict_labels(&self, sample: &Sample, row: usize) -> Vec<i64> {
+        self.confidences(sample, row)
+            .into_iter()
+            .map(|conf| if conf >= 0.0 { 1 } else { -1 })
+            .collect()
+    }
+
+
+    /// Computes the confidences of `df`.
+    fn confidences_all(&self, sample: &Sample) -> Vec<Vec<f64>> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.confidences(sample, row))
+            .collect::<Vec<_>>()
+    }
+
+
+    /// Predicts the labels of `df`.
+    fn predict_labels_all(&self, sample: &Sample) -> Vec<Vec<i64>>
+    {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_labels(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
@@ -0,0 +1,73 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Regressor,
+    Sample,
+};
+
+
+/// A regressor that regards the output of another regressor
+/// as the logarithm of the mean.
+/// Boosting with the log-link losses, such as
+/// [`GBMLoss::Poisson`](crate::GBMLoss::Poisson),
+/// [`GBMLoss::Gamma`](crate::GBMLoss::Gamma), and
+/// [`GBMLoss::Tweedie`](crate::GBMLoss::Tweedie),
+/// yields the log-mean `F(x)`,
+/// and this struct applies the inverse link at the prediction time:
+/// ```txt
+/// E[ y | x ] = exp( F(x) ).
+/// ```
+/// Thus, the predictions are always positive.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let mut booster = GBM::init_with_loss(&sample, GBMLoss::Poisson);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::Poisson)
+///     .build();
+/// 
+/// let f = LogLinkRegressor::new(booster.run(&weak_learner));
+/// // The expected counts.
+/// let predictions = f.predict_all(&sample);
+/// // The log-mean.
+/// let log_mean = f.regressor().predict_all(&sample);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LogLinkRegressor<H> {
+    /// The regressor that outputs the log-mean.
+    regressor: H,
+}
+
+
+impl<H> LogLinkRegressor<H> {
+    /// Construct a new `LogLinkRegressor` from the given regressor.
+    #[inline]
+    pub fn new(regressor: H) -> Self {
+        Self { regressor }
+    }
+
+
+    /// Returns the reference to the inner regressor.
+    #[inline]
+    pub fn regressor(&self) -> &H {
+        &self.regressor
+    }
+
+
+    /// Decompose `self` into the inner regressor.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.regressor
+    }
+}
+
+
+impl<H> Regressor for LogLinkRegressor<H>
+    where H: Regressor,
+{
+    fn predict(&self, sample: &Sample, row: usize) -> f64 {
+        self.regressor.predict(sample, row).exp()
+    }
+}
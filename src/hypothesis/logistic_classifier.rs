@@ -0,0 +1,81 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    common::loss_functions::sigmoid,
+    Classifier,
+    Regressor,
+    Sample,
+};
+
+
+/// A classifier that regards the output of a regressor
+/// as the log-odds of the label `+1`.
+/// Boosting algorithms that minimize the logistic loss,
+/// such as [`LogitBoost`](crate::booster::LogitBoost),
+/// return this struct.
+/// Wrapping the output of [`GBM`](crate::booster::GBM)
+/// with [`GBMLoss::Logistic`](crate::GBMLoss::Logistic)
+/// yields a probabilistic classifier as well.
+///
+/// Given the regressor output `F(x)`,
+/// the probability of the label `+1` is
+/// ```txt
+/// P(y = +1 | x) = 1 / (1 + exp(-F(x))),
+/// ```
+/// and the confidence is `2 * P(y = +1 | x) - 1`, which lies in `(-1, 1)`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LogisticClassifier<H> {
+    /// The regressor that outputs the log-odds.
+    regressor: H,
+}
+
+
+impl<H> LogisticClassifier<H> {
+    /// Construct a new `LogisticClassifier` from the given regressor.
+    #[inline]
+    pub fn new(regressor: H) -> Self {
+        Self { regressor }
+    }
+
+
+    /// Returns the reference to the inner regressor.
+    #[inline]
+    pub fn regressor(&self) -> &H {
+        &self.regressor
+    }
+
+
+    /// Decompose `self` into the inner regressor.
+    #[inline]
+    pub fn into_inner(self) -> H {
+        self.regressor
+    }
+}
+
+
+impl<H> LogisticClassifier<H>
+    where H: Regressor,
+{
+    /// Returns the probability that the i'th row of `sample` is `+1`.
+    #[inline]
+    pub fn probability(&self, sample: &Sample, row: usize) -> f64 {
+        sigmoid(self.regressor.predict(sample, row))
+    }
+
+
+    /// Returns the probabilities that each row of `sample` is `+1`.
+    #[inline]
+    pub fn probability_all(&self, sample: &Sample) -> Vec<f64> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.probability(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
+
+
+impl<H> Classifier for LogisticClassifier<H>
+    where H: Regressor,
+{
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        2.0 * self.probability(sample, row) - 1.0
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    common::loss_functions::softmax,
+    MultiClassifier,
+    Regressor,
+    Sample,
+};
+
+
+/// A multi-class classifier that regards the outputs of `K` regressors
+/// as the scores of the classes `0`, `1`, ..., `K-1`.
+/// [`MultiClassGBM`](crate::booster::MultiClassGBM)
+/// returns this struct.
+///
+/// Given the regressor outputs `F_1(x), ..., F_K(x)`,
+/// the probability of the class `k` is
+/// ```txt
+/// P(y = k | x) = exp( F_k(x) ) / sum_j exp( F_j(x) ),
+/// ```
+/// and the predicted class is the one with the highest score.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SoftmaxClassifier<H> {
+    /// The regressors that output the scores of the classes.
+    regressors: Vec<H>,
+}
+
+
+impl<H> SoftmaxClassifier<H> {
+    /// Construct a new `SoftmaxClassifier` from the given regressors.
+    /// `regressors[k]` outputs the score of the class `k`.
+    #[inline]
+    pub fn new(regressors: Vec<H>) -> Self {
+        assert!(
+            regressors.len() >= 2,
+            "`SoftmaxClassifier` needs at least 2 classes"
+        );
+        Self { regressors }
+    }
+
+
+    /// Returns the number of classes `K`.
+    #[inline]
+    pub fn n_class(&self) -> usize {
+        self.regressors.len()
+    }
+
+
+    /// Returns the slice of the inner regressors.
+    #[inline]
+    pub fn regressors(&self) -> &[H] {
+        &self.regressors[..]
+    }
+
+
+    /// Decompose `self` into the inner regressors.
+    #[inline]
+    pub fn into_inner(self) -> Vec<H> {
+        self.regressors
+    }
+}
+
+
+impl<H> SoftmaxClassifier<H>
+    where H: Regressor,
+{
+    /// Returns the scores of the classes for the i'th row of `sample`.
+    #[inline]
+    pub fn scores(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        self.regressors.iter()
+            .map(|h| h.predict(sample, row))
+            .collect()
+    }
+
+
+    /// Returns the probabilities of the classes
+    /// for the i'th row of `sample`.
+    #[inline]
+    pub fn probability(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        softmax(&self.scores(sample, row)[..])
+    }
+
+
+    /// Returns the probabilities of the classes
+    /// for each row of `sample`.
+    #[inline]
+    pub fn probability_all(&self, sample: &Sample) -> Vec<Vec<f64>> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.probability(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
+
+
+impl<H> MultiClassifier for SoftmaxClassifier<H>
+    where H: Regressor,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize {
+        self.scores(sample, row)
+            .into_iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap()
+            .0
+    }
+}
@@ -3,6 +3,8 @@ use crate::{
     common::utils,
     Classifier,
     Regressor,
+    MultiClassifier,
+    MultiLabelClassifier,
     Sample,
 };
 
@@ -66,6 +68,71 @@ impl<H> WeightedMajority<H> {
 }
 
 
+/// The coverage and the accuracy of a classifier
+/// that abstains if the normalized margin is at most `threshold`.
+/// See [`WeightedMajority::coverage_accuracy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoverageAccuracy {
+    /// The threshold for the normalized margin.
+    pub threshold: f64,
+    /// The fraction of the examples that are not abstained.
+    pub coverage: f64,
+    /// The accuracy on the examples that are not abstained.
+    /// This value is `NaN` if the classifier abstains on all the examples.
+    pub accuracy: f64,
+}
+
+
+impl<F> WeightedMajority<F>
+    where F: Classifier,
+{
+    /// Computes the normalized margin `sum( w h(x) ) / sum( |w| )`
+    /// of the i'th row of the `df`,
+    /// which lies in `[-1.0, 1.0]`.
+    #[inline]
+    pub fn margin(&self, sample: &Sample, row: usize) -> f64 {
+        let total = self.weights.iter()
+            .map(|w| w.abs())
+            .sum::<f64>();
+        if total == 0.0 { return 0.0; }
+
+        self.confidence(sample, row) / total
+    }
+
+
+    /// Reports the coverage and the accuracy on `sample`
+    /// for each threshold in `thresholds`.
+    /// For each threshold,
+    /// `self` abstains on the examples
+    /// whose normalized margin is at most the threshold.
+    /// The target values of `sample` are assumed to be `+1` or `-1`.
+    pub fn coverage_accuracy(&self, sample: &Sample, thresholds: &[f64])
+        -> Vec<CoverageAccuracy>
+    {
+        let n_sample = sample.shape().0;
+        let margins = (0..n_sample)
+            .map(|row| self.margin(sample, row))
+            .collect::<Vec<_>>();
+        let target = sample.target();
+
+        thresholds.iter()
+            .map(|&threshold| {
+                let (n_covered, n_correct) = margins.iter()
+                    .zip(target)
+                    .filter(|(m, _)| m.abs() > threshold)
+                    .fold((0usize, 0usize), |(n, c), (m, y)| {
+                        let correct = (*m > 0.0) == (*y > 0.0);
+                        (n + 1, c + correct as usize)
+                    });
+                let coverage = n_covered as f64 / n_sample as f64;
+                let accuracy = n_correct as f64 / n_covered as f64;
+                CoverageAccuracy { threshold, coverage, accuracy, }
+            })
+            .collect()
+    }
+}
+
+
 impl<F> Classifier for WeightedMajority<F>
     where F: Classifier,
 {
@@ -75,6 +142,21 @@ impl<F> Classifier for WeightedMajority<F>
             .map(|(w, h)| *w * h.confidence(sample, row))
             .sum::<f64>()
     }
+
+
+    /// Predicts the label of the i'th row of the `df`,
+    /// or abstains from the prediction.
+    /// Unlike the default implementation,
+    /// this method abstains if the **normalized margin**
+    /// (see [`WeightedMajority::margin`]) is at most `threshold`.
+    fn predict_or_abstain(&self, sample: &Sample, row: usize, threshold: f64)
+        -> i64
+    {
+        let margin = self.margin(sample, row);
+        if margin.abs() <= threshold { 0 }
+        else if margin > 0.0 { 1 }
+        else { -1 }
+    }
 }
 
 
@@ -89,3 +171,44 @@ impl<F> Regressor for WeightedMajority<F>
     }
 }
 
+
+
+impl<F> MultiClassifier for WeightedMajority<F>
+    where F: MultiClassifier,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize {
+        let mut votes: Vec<f64> = Vec::new();
+        self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .for_each(|(w, h)| {
+                let k = h.predict_class(sample, row);
+                if votes.len() <= k { votes.resize(k + 1, 0f64); }
+                votes[k] += *w;
+            });
+
+        votes.into_iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(k, _)| k)
+            .expect("The combined hypothesis has no hypotheses")
+    }
+}
+
+
+impl<F> MultiLabelClassifier for WeightedMajority<F>
+    where F: MultiLabelClassifier,
+{
+    fn confidences(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        let mut confs: Vec<f64> = Vec::new();
+        self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .for_each(|(w, h)| {
+                let conf = h.confidences(sample, row);
+                if confs.len() < conf.len() { confs.resize(conf.len(), 0f64); }
+                confs.iter_mut()
+                    .zip(conf)
+                    .for_each(|(c, hc)| { *c += *w * hc; });
+            });
+        confs
+    }
+}
@@ -10,9 +10,49 @@
 //! 
 //! * Empirical risk minimizing (ERM) boosting
 //!     - [`AdaBoost`],
+//!     - [`LogitBoost`],
+//!     - [`GentleBoost`],
 //!     - [`GraphSepBoost`].
 //! 
 //! 
+//! * Noise tolerant boosting
+//!     - [`BrownBoost`].
+//! 
+//! 
+//! * Boosting for imbalanced data
+//!     - [`RUSBoost`],
+//!     - [`AdaC2`] (Cost-sensitive AdaBoost).
+//! 
+//! 
+//! * Online boosting
+//!     - [`OzaBoost`].
+//! 
+//! 
+//! * Boosting by filtering
+//!     - [`FilterBoost`].
+//! 
+//! 
+//! * Multi-class boosting
+//!     - [`SAMME`],
+//!     - [`MultiClassGBM`].
+//! 
+//! 
+//! * Multi-label boosting
+//!     - [`AdaBoostMH`].
+//! 
+//! 
+//! * Bipartite ranking
+//!     - [`RankBoost`].
+//! 
+//! 
+//! * Semi-supervised boosting
+//!     - [`ASSEMBLE`].
+//! 
+//! 
+//! * Differentially private boosting
+//!     - [`DPBoost`].
+//! 
+//! 
 //! * Hard margin maximizing boosting
 //!     - [`AdaBoostV`],
 //!     - [`TotalBoost`](crate::booster::TotalBoost).
@@ -33,6 +73,11 @@
 //!     - [`NeuralNetwork`],
 //!     - [`GaussianNB`],
 //!     - [`BadBaseLearner`] (The bad base learner for LPBoost).
+//! * Multi-label classification
+//!     - [`MultiLabelStump`].
+//! * Online classification
+//!     - [`OnlineGaussianNB`],
+//!     - [`HoeffdingStump`].
 //! * Regression
 //!     - [`RegressionTree`]. Note that the current implement is not efficient.
 //! 
@@ -128,7 +173,14 @@ pub use sample::{
 pub use hypothesis::{
     Classifier,
     Regressor,
+    MultiClassifier,
+    MultiLabelClassifier,
     WeightedMajority,
+    CoverageAccuracy,
+    LogisticClassifier,
+    LogLinkRegressor,
+    BaseScoreRegressor,
+    SoftmaxClassifier,
     NaiveAggregation,
 };
 
@@ -142,10 +194,69 @@ pub use booster::Booster;
 pub use booster::{
     AdaBoost,
     MadaBoost,
+    LogitBoost,
+    GentleBoost,
     // AdaBoostL,
 };
 
 
+// Export the noise tolerant boosting algorithms.
+pub use booster::{
+    BrownBoost,
+};
+
+
+// Export the boosting algorithms for imbalanced data.
+pub use booster::{
+    RUSBoost,
+    AdaC2,
+};
+
+
+// Export the online boosting algorithms.
+pub use booster::{
+    OzaBoost,
+};
+
+
+// Export the boosting algorithms by filtering.
+pub use booster::{
+    FilterBoost,
+    ExampleOracle,
+    SampleOracle,
+};
+
+
+// Export the boosting algorithms for multi-class classification.
+pub use booster::{
+    SAMME,
+};
+
+
+// Export the boosting algorithms for multi-label classification.
+pub use booster::{
+    AdaBoostMH,
+};
+
+
+// Export the boosting algorithms for bipartite ranking.
+pub use booster::{
+    RankBoost,
+};
+
+
+// Export the boosting algorithms for semi-supervised classification.
+pub use booster::{
+    ASSEMBLE,
+};
+
+
+// Export the differentially private boosting algorithms.
+pub use booster::{
+    DPBoost,
+};
+
+
 // Export the boosting algorithms that maximizes the hard margin.
 pub use booster::{
     AdaBoostV,
@@ -170,6 +281,7 @@ pub use booster::{
 // Export the boosting algorithms for regression
 pub use booster::{
     GBM,
+    MultiClassGBM,
 };
 
 
@@ -177,8 +289,11 @@ pub use booster::{
 pub use booster::GraphSepBoost;
 
 
-// Export the `WeakLearner` trait.
-pub use weak_learner::WeakLearner;
+// Export the `WeakLearner` traits.
+pub use weak_learner::{
+    WeakLearner,
+    OnlineWeakLearner,
+};
 
 
 // Export the instances of the `WeakLearner` trait.
@@ -196,6 +311,15 @@ pub use weak_learner::{
 
     BadBaseLearner,
     BadBaseLearnerBuilder,
+
+    MultiLabelStump,
+};
+
+
+// Export the instances of the `OnlineWeakLearner` trait.
+pub use weak_learner::{
+    OnlineGaussianNB,
+    HoeffdingStump,
 };
 
 
@@ -210,6 +334,8 @@ pub use weak_learner::{
 
     BadClassifier,
     NBayesClassifier,
+
+    MultiLabelStumpClassifier,
 };
 
 pub use weak_learner::{
@@ -223,9 +349,14 @@ pub use common::{
     frank_wolfe::{
         FWType,
     },
+    privacy::PrivacyAccountant,
     loss_functions::{
         GBMLoss,
         LossFunction,
+        LambdaRank,
+        CoxPH,
+        MultiClassLossFunction,
+        SoftmaxLoss,
     },
 };
 
@@ -9,6 +9,47 @@ pub use crate::booster::{
     // ERM boostings
     AdaBoost,
     MadaBoost,
+    LogitBoost,
+    GentleBoost,
+
+
+    // Noise tolerant boostings
+    BrownBoost,
+
+
+    // Boostings for imbalanced data
+    RUSBoost,
+    AdaC2,
+
+
+    // Online boostings
+    OzaBoost,
+
+
+    // Boostings by filtering
+    FilterBoost,
+    ExampleOracle,
+    SampleOracle,
+
+
+    // Multi-class boostings
+    SAMME,
+
+
+    // Multi-label boostings
+    AdaBoostMH,
+
+
+    // Bipartite ranking
+    RankBoost,
+
+
+    // Semi-supervised boostings
+    ASSEMBLE,
+
+
+    // Differentially private boostings
+    DPBoost,
 
 
     // Hard margin maximizing boostings
@@ -26,6 +67,7 @@ pub use crate::booster::{
 
     // Regression
     GBM,
+    MultiClassGBM,
 
 
     // Others
@@ -36,6 +78,7 @@ pub use crate::booster::{
 pub use crate::weak_learner::{
     // Base Learner trait
     WeakLearner,
+    OnlineWeakLearner,
 
 
     // Classification ---------------------------
@@ -60,6 +103,16 @@ pub use crate::weak_learner::{
     BadBaseLearnerBuilder,
 
 
+    // Multi-label classification ---------------
+    MultiLabelStump,
+    MultiLabelStumpClassifier,
+
+
+    // Online classification --------------------
+    OnlineGaussianNB,
+    HoeffdingStump,
+
+
     // Regression -------------------------------
     RegressionTree,
     RegressionTreeBuilder,
@@ -70,7 +123,14 @@ pub use crate::weak_learner::{
 pub use crate::hypothesis::{
     Classifier,
     Regressor,
+    MultiClassifier,
+    MultiLabelClassifier,
     WeightedMajority,
+    CoverageAccuracy,
+    LogisticClassifier,
+    LogLinkRegressor,
+    BaseScoreRegressor,
+    SoftmaxClassifier,
 };
 
 pub use crate::{
@@ -81,6 +141,11 @@ pub use crate::{
 pub use crate::common::{
     loss_functions::GBMLoss,
     loss_functions::LossFunction,
+    loss_functions::LambdaRank,
+    loss_functions::CoxPH,
+    loss_functions::MultiClassLossFunction,
+    loss_functions::SoftmaxLoss,
     frank_wolfe::FWType,
+    privacy::PrivacyAccountant,
 };
 
@@ -22,6 +22,10 @@ pub struct SampleReader<P, S> {
     file: Option<P>,
     has_header: bool,
     target: Option<S>,
+    multi_target: Vec<S>,
+    group: Option<S>,
+    event: Option<S>,
+    allow_missing_target: bool,
 }
 
 
@@ -32,6 +36,21 @@ impl<P, S> SampleReader<P, S> {
         self.has_header = flag;
         self
     }
+
+
+    /// Set the flag whether the target column of a CSV file
+    /// may have empty fields or not.
+    /// If `true`, an empty field in the target column becomes `NaN`,
+    /// which marks an unlabeled example
+    /// for the semi-supervised boosting such as
+    /// [`ASSEMBLE`](crate::booster::ASSEMBLE).
+    /// Otherwise, [`SampleReader::read`] returns `Err`
+    /// for an empty field in any column.
+    /// Default is `false.`
+    pub fn allow_missing_target(mut self, flag: bool) -> Self {
+        self.allow_missing_target = flag;
+        self
+    }
 }
 
 
@@ -55,6 +74,34 @@ impl<P, S> SampleReader<P, S>
         self.target = Some(column);
         self
     }
+
+
+    /// Set the column names that are used for multi-label targets.
+    /// An example has the `k`th label
+    /// if the value of the `k`th column is positive.
+    /// For a CSV file with multi-label targets,
+    /// [`SampleReader::target_feature`] is optional.
+    pub fn multi_target_features(mut self, columns: Vec<S>) -> Self {
+        self.multi_target = columns;
+        self
+    }
+
+
+    /// Set the column name that is used for query groups.
+    /// See [`Sample::set_group`] for details.
+    pub fn group_feature(mut self, column: S) -> Self {
+        self.group = Some(column);
+        self
+    }
+
+
+    /// Set the column name that is used for the event indicators
+    /// of the survival analysis.
+    /// See [`Sample::set_event`] for details.
+    pub fn event_feature(mut self, column: S) -> Self {
+        self.event = Some(column);
+        self
+    }
 }
 
 
@@ -75,15 +122,33 @@ impl<P, S> SampleReader<P, S>
         let file = file.as_ref();
 
         let sample = if file.extension().is_some_and(|ext| ext == "csv") {
-            if self.target.is_none() {
+            if self.target.is_none() && self.multi_target.is_empty() {
                 panic!(
                     "Target (class) column is not specified. \
                     Use `SampleReader::target`."
                 );
             }
-            let target = self.target.unwrap();
-            Sample::from_csv(file, self.has_header)?
-                .set_target(target.as_ref())
+            let mut sample = match self.target.as_ref() {
+                Some(target) if self.allow_missing_target => {
+                    Sample::from_csv_with_target(
+                        file, self.has_header, Some(target.as_ref())
+                    )?
+                },
+                _ => Sample::from_csv(file, self.has_header)?,
+            };
+            if !self.multi_target.is_empty() {
+                sample = sample.set_multi_target(&self.multi_target[..]);
+            }
+            if let Some(group) = self.group {
+                sample = sample.set_group(group.as_ref());
+            }
+            if let Some(target) = self.target {
+                sample = sample.set_target(target.as_ref());
+            }
+            if let Some(event) = self.event {
+                sample = sample.set_event(event.as_ref());
+            }
+            sample
         } else {
             Sample::from_svmlight(file)?
         };
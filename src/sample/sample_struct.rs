@@ -26,6 +26,16 @@ pub struct Sample {
     pub(super) name_to_index: HashMap<String, usize>,
     pub(super) features: Vec<Feature>,
     pub(super) target: Vec<f64>,
+    // Multi-label targets.
+    // `multi_target[k][i]` is `+1` if the `i`th example has
+    // the `k`th label, `-1` otherwise.
+    pub(super) multi_target: Vec<Vec<f64>>,
+    // Query group of each example.
+    // This vector is empty if the groups are not specified.
+    pub(super) group: Vec<usize>,
+    // Weight of each example used by the weak learners.
+    // This vector is empty if the weights are not specified.
+    pub(super) weight: Vec<f64>,
     pub(super) n_sample: usize,
     pub(super) n_feature: usize,
 }
@@ -44,6 +54,9 @@ impl Sample {
             name_to_index: HashMap::from([("dummy".to_string(), 0)]),
             features,
             target,
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
             n_sample,
             n_feature: 1usize,
         }
@@ -59,9 +72,27 @@ impl Sample {
     /// 
     /// **Do not forget** to call [`Sample::set_target`] to
     /// assign the class label.
-    pub(super) fn from_csv<P>(file: P, mut has_header: bool)
+    /// 
+    /// This method returns `Err` if the file has an empty field.
+    pub(super) fn from_csv<P>(file: P, has_header: bool)
         -> io::Result<Self>
         where P: AsRef<Path>,
+    {
+        Self::from_csv_with_target(file, has_header, None)
+    }
+
+
+    /// Read a CSV format file to [`Sample`] type
+    /// in the same way as [`Sample::from_csv`],
+    /// except that an empty field in the column of name `target`
+    /// is a missing value, which becomes `NaN`.
+    /// This method returns `Err` if the other columns have an empty field.
+    pub(super) fn from_csv_with_target<P>(
+        file: P,
+        mut has_header: bool,
+        target: Option<&str>,
+    ) -> io::Result<Self>
+        where P: AsRef<Path>,
     {
         // Open the given `file`.
         let file = File::open(file)?;
@@ -75,6 +106,7 @@ impl Sample {
                 .collect::<Vec<_>>();
         }
         let mut n_sample = 0_usize;
+        let mut target_index = None;
 
         // For each line of the file
         for (i, line) in lines.enumerate() {
@@ -84,40 +116,47 @@ impl Sample {
             // if the headeer does not exists,
             // construct a dummy header.
             if !has_header {
-                let xs = line.split(',')
-                    .map(|x| {
-                        x.trim().parse::<f64>()
-                            .unwrap_or_else(|_| {
-                                panic!(
-                                    "The file contains non-numerical value. \
-                                    Got {x} in Line {i}"
-                                )
-                            })
-                    })
-                    .collect::<Vec<_>>();
-
-                let n_feature = xs.len();
+                let n_feature = line.split(',').count();
                 features = (1..=n_feature).map(|i| {
                         let name = format!("Feat. [{i}]");
                         DenseFeature::new(name)
                     })
                     .collect::<Vec<_>>();
 
-                for (feat, x) in features.iter_mut().zip(xs) {
-                    feat.append(x);
-                }
-
                 has_header = true;
-                n_sample += 1;
-                continue;
             }
-
-            line.split(',')
-                .map(|x| x.trim().parse::<f64>().unwrap())
-                .enumerate()
-                .for_each(|(i, x)| {
-                    features[i].append(x);
+            if i == 0 {
+                target_index = target.and_then(|target| {
+                    features.iter().position(|f| f.name == target)
                 });
+            }
+
+            for (j, x) in line.split(',').enumerate() {
+                let x = x.trim();
+                // An empty field in the target column is a missing value.
+                let x = if x.is_empty() {
+                    if target_index != Some(j) {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!(
+                                "The column `{}` has an empty field \
+                                 in Line {i}",
+                                features[j].name
+                            ),
+                        ));
+                    }
+                    f64::NAN
+                } else {
+                    x.parse::<f64>()
+                        .unwrap_or_else(|_| {
+                            panic!(
+                                "The file contains non-numerical value. \
+                                Got {x} in Line {i}"
+                            )
+                        })
+                };
+                features[j].append(x);
+            }
 
             n_sample += 1;
         }
@@ -136,6 +175,9 @@ impl Sample {
 
         let sample = Self {
             name_to_index, features, target, n_sample, n_feature,
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
         };
 
         Ok(sample)
@@ -184,6 +226,9 @@ impl Sample {
             name_to_index,
             features,
             target,
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
             n_sample,
             n_feature,
         };
@@ -198,6 +243,15 @@ impl Sample {
     }
 
 
+    /// Replaces the target values with `target`.
+    /// This method is used for the boosting algorithms
+    /// that pass pseudo-labels to the weak learner.
+    pub(crate) fn replace_target(&mut self, target: Vec<f64>) {
+        assert_eq!(self.n_sample, target.len());
+        self.target = target;
+    }
+
+
     /// Returns the unique target values.
     pub fn unique_target(&self) -> Vec<f64> {
         let mut target = self.target().to_vec();
@@ -237,6 +291,187 @@ impl Sample {
     }
 
 
+    /// Set the features of names `targets` to the multi-label targets.
+    /// The `k`th name in `targets` corresponds to the `k`th label.
+    /// An example has the `k`th label
+    /// if the value of the `k`th feature is positive.
+    /// The old multi-label targets will be dropped.
+    pub fn set_multi_target<S: AsRef<str>>(mut self, targets: &[S]) -> Self {
+        let mut multi_target = Vec::with_capacity(targets.len());
+        for target in targets {
+            let target = target.as_ref();
+            let pos = self.features.iter()
+                .position(|feat| feat.name() == target)
+                .expect("The target class does not exist");
+
+            let labels = self.features.remove(pos)
+                .into_target()
+                .into_iter()
+                .map(|y| if y > 0.0 { 1.0 } else { -1.0 })
+                .collect::<Vec<_>>();
+            multi_target.push(labels);
+            self.n_feature -= 1;
+        }
+        self.multi_target = multi_target;
+
+
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        self
+    }
+
+
+    /// Returns the multi-label targets.
+    /// The `i`th element of the `k`th vector is `+1`
+    /// if the `i`th example has the `k`th label, `-1` otherwise.
+    pub fn multi_target(&self) -> &[Vec<f64>] {
+        &self.multi_target[..]
+    }
+
+
+    /// Returns the multi-label targets of the examples `ix`.
+    fn multi_target_of<I>(&self, ix: I) -> Vec<Vec<f64>>
+        where I: Iterator<Item = usize> + Clone
+    {
+        self.multi_target.iter()
+            .map(|labels| ix.clone().map(|i| labels[i]).collect())
+            .collect()
+    }
+
+
+    /// Set the feature of name `group` to the query groups.
+    /// The examples with the same value belong to the same query,
+    /// and the ranking losses such as
+    /// [`LambdaRank`](crate::LambdaRank)
+    /// compare the examples only within each query.
+    /// The values of the feature must be non-negative integers.
+    /// The old query groups will be dropped.
+    pub fn set_group<S: AsRef<str>>(mut self, group: S) -> Self {
+        let group = group.as_ref();
+        let pos = self.features.iter()
+            .position(|feat| feat.name() == group)
+            .expect("The group column does not exist");
+
+
+        self.group = self.features.remove(pos)
+            .into_target()
+            .into_iter()
+            .map(|g| {
+                if g < 0.0 || g.trunc() != g {
+                    panic!("Query groups must be non-negative integers. Got {g}.");
+                }
+                g as usize
+            })
+            .collect();
+        self.n_feature -= 1;
+
+
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        self
+    }
+
+
+    /// Set the feature of name `event` to the event indicators
+    /// for the survival analysis.
+    /// The value `1` means that the event (e.g., a failure) is observed
+    /// at the target time,
+    /// and `0` means that the example is censored at the target time.
+    /// The target times must be positive
+    /// and must be set by [`Sample::set_target`] beforehand.
+    /// 
+    /// Following XGBoost, this method encodes the censored examples
+    /// by negating their target values.
+    /// That is, after calling this method,
+    /// `target[i] > 0` if the event is observed for the `i`th example,
+    /// and `-target[i]` is the censoring time otherwise.
+    /// See also [`CoxPH`](crate::CoxPH).
+    pub fn set_event<S: AsRef<str>>(mut self, event: S) -> Self {
+        self.target_is_specified();
+        let event = event.as_ref();
+        let pos = self.features.iter()
+            .position(|feat| feat.name() == event)
+            .expect("The event column does not exist");
+
+
+        let event = self.features.remove(pos).into_target();
+        self.target.iter_mut()
+            .zip(event)
+            .for_each(|(y, e)| {
+                if *y <= 0.0 {
+                    panic!("The survival times must be positive. Got {y}.");
+                }
+                if e == 0.0 {
+                    *y = -*y;
+                } else if e != 1.0 {
+                    panic!("The event indicators must be 0 or 1. Got {e}.");
+                }
+            });
+        self.n_feature -= 1;
+
+
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        self
+    }
+
+
+    /// Returns the query group of each example.
+    /// If the groups are not specified by [`Sample::set_group`],
+    /// this method returns an empty slice.
+    pub fn group(&self) -> &[usize] {
+        &self.group[..]
+    }
+
+
+    /// Returns the query groups of the examples `ix`.
+    fn group_of<I>(&self, ix: I) -> Vec<usize>
+        where I: Iterator<Item = usize>
+    {
+        if self.group.is_empty() { return Vec::new(); }
+        ix.map(|i| self.group[i]).collect()
+    }
+
+
+    /// Returns the weight of each example.
+    /// If the weights are not specified,
+    /// this method returns an empty slice.
+    pub(crate) fn weight(&self) -> &[f64] {
+        &self.weight[..]
+    }
+
+
+    /// Set the weight of each example.
+    /// The weak learners that support the weights,
+    /// such as [`RegressionTree`](crate::weak_learner::RegressionTree),
+    /// scale the contribution of each example by its weight.
+    pub(crate) fn set_weight(&mut self, weight: Vec<f64>) {
+        assert_eq!(
+            weight.len(), self.n_sample,
+            "The number of weights is not equal to the one of examples"
+        );
+        self.weight = weight;
+    }
+
+
+    /// Returns the weights of the examples `ix`.
+    fn weight_of<I>(&self, ix: I) -> Vec<f64>
+        where I: Iterator<Item = usize>
+    {
+        if self.weight.is_empty() { return Vec::new(); }
+        ix.map(|i| self.weight[i]).collect()
+    }
+
+
     /// Read a SVMLight format file to `Sample`.
     /// 
     /// Each line of SVMLight format file has the following form:
@@ -306,6 +541,9 @@ impl Sample {
 
         let mut sample = Self {
             name_to_index, features, target, n_sample, n_feature,
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
         };
 
         sample.remove_allzero_features();
@@ -461,6 +699,121 @@ impl Sample {
     }
 
 
+    /// Check whether `self` is
+    /// a training set for semi-supervised binary classification or not.
+    /// The target value of an unlabeled example must be `NaN`,
+    /// which is the value of an empty field in a CSV file.
+    /// The labeled examples must satisfy the conditions of
+    /// [`Sample::is_valid_binary_instance`].
+    /// This method returns the number of unlabeled examples.
+    pub fn is_valid_semi_supervised_instance(&self) -> usize {
+        // Check whether the target column is specified.
+        self.target_is_specified();
+
+
+        let n_unlabeled = self.target.iter()
+            .filter(|yi| yi.is_nan())
+            .count();
+
+
+        let set = self.target.iter()
+            .filter(|yi| !yi.is_nan())
+            .map(|&yi| {
+                if yi != 1.0 && yi != -1.0 {
+                    panic!(
+                        "The target values of labeled examples \
+                         must be +1 or -1. Got {yi}."
+                    );
+                }
+                yi as i32
+            })
+            .collect::<HashSet<_>>();
+        if set.len() < 2 {
+            panic!(
+                "The labeled examples take less than 2 kinds. \
+                 Expected 2 kinds, got {} kinds.",
+                set.len()
+            );
+        }
+
+        n_unlabeled
+    }
+
+
+    /// Check whether `self` is
+    /// a training set for multi-class classification or not.
+    /// The target values must take `0`, `1`, ..., `K-1`.
+    /// This method returns the number of classes `K`.
+    pub fn is_valid_multiclass_instance(&self) -> usize {
+        // Check whether the target column is specified.
+        self.target_is_specified();
+
+
+        // Check whether the target values are non-negative integers.
+        let invalids = self.target.iter()
+            .filter(|&yi| !yi.trunc().eq(yi) || *yi < 0.0)
+            .collect::<Vec<_>>();
+        if !invalids.is_empty() {
+            let line = invalids.iter().take(5)
+                .map(|yi| yi.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            panic!(
+                "Target values must be non-negative integers.\n\
+                 Ex. [{line}, ...]."
+            );
+        }
+
+
+        let set = self.target.iter()
+            .copied()
+            .map(|yi| yi as usize)
+            .collect::<HashSet<_>>();
+        let n_class = set.iter().max().unwrap() + 1;
+        if n_class < 2 {
+            panic!(
+                "The target values take less than 2 kinds. \
+                 Expected at least 2 kinds, got {n_class} kinds."
+            );
+        }
+        if set.len() != n_class {
+            println!(
+                "Warning: the target values do not cover [0, {n_class}).\n\
+                 Currently, the labels take {} kinds.",
+                set.len()
+            );
+        }
+
+        n_class
+    }
+
+
+    /// Check whether `self` is
+    /// a training set for multi-label classification or not.
+    /// The multi-label targets must be set by [`Sample::set_multi_target`].
+    /// This method returns the number of labels `K`.
+    pub fn is_valid_multilabel_instance(&self) -> usize {
+        let n_label = self.multi_target.len();
+        if n_label == 0 {
+            panic!(
+                "The multi-label targets are not specified. \
+                 Call `Sample::set_multi_target` to specify them."
+            );
+        }
+
+        let is_valid = self.multi_target.iter()
+            .all(|labels| labels.len() == self.n_sample);
+        if !is_valid {
+            panic!(
+                "The number of multi-label targets \
+                 does not match the number of examples."
+            );
+        }
+
+        n_label
+    }
+
+
     /// Computes the weighted mean and variance
     /// for each feature.
     ///
@@ -538,13 +891,18 @@ impl Sample {
     }
 
 
-    fn append(&mut self, row: usize, feat: Vec<f64>, y: f64) {
+    /// Append the `idx`-th instance of `sample` as the `row`-th one.
+    /// The target is skipped if `sample` has no target,
+    /// e.g., a multi-label sample.
+    fn append_from(&mut self, row: usize, sample: &Sample, idx: usize) {
         self.features.par_iter_mut()
-            .zip(feat)
-            .for_each(|(col, f)| {
-                col.append(row, f);
+            .zip(&sample.features[..])
+            .for_each(|(col, feat)| {
+                col.append(row, feat[idx]);
             });
-        self.target.push(y);
+        if let Some(&y) = sample.target.get(idx) {
+            self.target.push(y);
+        }
     }
 
 
@@ -566,6 +924,9 @@ impl Sample {
             name_to_index: name_to_ix.clone(),
             features: vec![Feature::new_sparse("dummy"); n_feature],
             target: Vec::with_capacity(train_size),
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
         };
 
         let mut test = Self {
@@ -574,6 +935,9 @@ impl Sample {
             name_to_index: name_to_ix,
             features: vec![Feature::new_sparse("dummy"); n_feature],
             target: Vec::with_capacity(test_size),
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
         };
 
         for (name, &i) in self.name_to_index.iter() {
@@ -589,22 +953,90 @@ impl Sample {
         }
 
         for (i, &ii) in ix.iter().enumerate().take(start) {
-            let (x, y) = self.at(ii);
-            train.append(i, x, y);
+            train.append_from(i, self, ii);
         }
 
         for (i, &ii) in ix.iter().enumerate().take(end).skip(start) {
-            let (x, y) = self.at(ii);
-            test.append(i, x, y);
+            test.append_from(i, self, ii);
         }
 
         for (i, &ii) in ix.iter().enumerate().take(self.n_sample).skip(end) {
-            let (x, y) = self.at(ii);
-            train.append(i, x, y);
+            train.append_from(i, self, ii);
         }
 
+
+        let train_ix = ix[..start].iter()
+            .chain(&ix[end..self.n_sample])
+            .copied();
+        train.multi_target = self.multi_target_of(train_ix.clone());
+        test.multi_target = self.multi_target_of(ix[start..end].iter().copied());
+        train.group = self.group_of(train_ix.clone());
+        test.group = self.group_of(ix[start..end].iter().copied());
+        train.weight = self.weight_of(train_ix);
+        test.weight = self.weight_of(ix[start..end].iter().copied());
+
         (train, test)
     }
+
+
+    /// Returns the sub-sample consisting of the rows `ix`.
+    /// The `i`th row of the returned sample is the `ix[i]`th row of `self`.
+    pub(crate) fn subsample<T>(&self, ix: T) -> Sample
+        where T: AsRef<[usize]>
+    {
+        let n_feature = self.features.len();
+        let ix = ix.as_ref();
+        let n_sample = ix.len();
+
+        let mut sub = Self {
+            n_sample,
+            n_feature,
+            name_to_index: self.name_to_index.clone(),
+            features: vec![Feature::new_sparse("dummy"); n_feature],
+            target: Vec::with_capacity(n_sample),
+            multi_target: Vec::new(),
+            group: Vec::new(),
+            weight: Vec::new(),
+        };
+
+        for (name, &i) in self.name_to_index.iter() {
+            if self.features[i].is_sparse() {
+                sub.features[i] = Feature::new_sparse(name.to_string());
+                sub.features[i].set_n_sample(n_sample);
+            } else {
+                sub.features[i] = Feature::new_dense(name.to_string());
+            }
+        }
+
+        for (i, &ii) in ix.iter().enumerate() {
+            sub.append_from(i, self, ii);
+        }
+        sub.multi_target = self.multi_target_of(ix.iter().copied());
+        sub.group = self.group_of(ix.iter().copied());
+        sub.weight = self.weight_of(ix.iter().copied());
+
+        sub
+    }
+
+
+    /// Keep the features `ix` and drop the others.
+    /// The order of the kept features does not change.
+    pub(crate) fn retain_features<T>(&mut self, ix: T)
+        where T: AsRef<[usize]>
+    {
+        let ix = ix.as_ref();
+        let features = mem::take(&mut self.features);
+        self.features = features.into_iter()
+            .enumerate()
+            .filter_map(|(i, feat)| ix.contains(&i).then_some(feat))
+            .collect();
+        self.n_feature = self.features.len();
+
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+    }
 }
 
 
@@ -27,7 +27,18 @@ mod bad_learner;
 // Defines Naive Bayes.
 mod naive_bayes;
 
-pub use self::core::WeakLearner;
+
+// Defines Hoeffding tree stump.
+mod hoeffding_tree;
+
+
+// Defines multi-label decision stump.
+mod multilabel_stump;
+
+pub use self::core::{
+    WeakLearner,
+    OnlineWeakLearner,
+};
 
 pub use self::decision_tree::{
     Criterion,
@@ -39,6 +50,16 @@ pub use self::decision_tree::{
 pub use self::naive_bayes::{
     GaussianNB,
     NBayesClassifier,
+    OnlineGaussianNB,
+};
+
+
+pub use self::hoeffding_tree::HoeffdingStump;
+
+
+pub use self::multilabel_stump::{
+    MultiLabelStump,
+    MultiLabelStumpClassifier,
 };
 
 
@@ -1,2 +1,3 @@
 pub(crate) mod type_and_struct;
 pub(crate) mod split_rule;
+pub(crate) mod monotone;
@@ -0,0 +1,95 @@
+//! Defines the monotone constraints on the tree weak learners.
+use std::collections::HashMap;
+
+
+/// Monotone constraints on the features.
+/// A feature with `1` (resp. `-1`) must have
+/// non-decreasing (resp. non-increasing) effect on the output,
+/// and the features not in the map are unconstrained.
+pub(crate) type MonotoneConstraints<'a> = HashMap<&'a str, i32>;
+
+
+/// The bounds `[lower, upper]` on the output values of a node.
+/// A split on a constrained feature halves the bounds
+/// at the midpoint of the outputs of the children,
+/// so that all the leaves in the left sub-tree are
+/// on the correct side of the leaves in the right sub-tree.
+/// This is the way of XGBoost and LightGBM.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct Bounds {
+    pub(crate) lower: f64,
+    pub(crate) upper: f64,
+}
+
+
+impl Bounds {
+    /// Construct the bounds `[-∞, +∞]` for the root node.
+    #[inline]
+    pub(crate) fn unbounded() -> Self {
+        Self { lower: f64::NEG_INFINITY, upper: f64::INFINITY, }
+    }
+
+
+    /// Clamp the output value `value` into the bounds.
+    #[inline]
+    pub(crate) fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.lower, self.upper)
+    }
+
+
+    /// Returns the bounds of the left and right children
+    /// of a split on a feature with the monotone constraint `sign`,
+    /// where the children output `left` and `right`.
+    #[inline]
+    pub(crate) fn split(&self, sign: i32, left: f64, right: f64)
+        -> (Self, Self)
+    {
+        let mid = 0.5 * (left + right);
+        let lower = Self { lower: self.lower, upper: mid, };
+        let upper = Self { lower: mid, upper: self.upper, };
+        match sign.signum() {
+            1 => (lower, upper),
+            -1 => (upper, lower),
+            _ => (*self, *self),
+        }
+    }
+}
+
+
+/// Returns `true` if the outputs `left` and `right` of the children
+/// respect the monotone constraint `sign`.
+#[inline(always)]
+pub(crate) fn is_monotone(sign: i32, left: f64, right: f64) -> bool {
+    match sign.signum() {
+        1 => left <= right,
+        -1 => left >= right,
+        _ => true,
+    }
+}
+
+
+/// Set the monotone constraint `sign` on the feature named `name`
+/// for the tree builders.
+/// `names` is the map from the feature names to any value,
+/// which is used to borrow the name for the lifetime `'a`.
+pub(crate) fn set_monotone_constraint<'a, V>(
+    constraints: &mut MonotoneConstraints<'a>,
+    names: &HashMap<&'a str, V>,
+    name: &str,
+    sign: i32,
+)
+{
+    assert!(
+        (-1..=1).contains(&sign),
+        "The monotone constraint must be `1`, `-1`, or `0`"
+    );
+    let Some((&name, _)) = names.get_key_value(name) else {
+        panic!("The feature named `{name}` does not exist");
+    };
+
+    if sign == 0 {
+        constraints.remove(name);
+    } else {
+        constraints.insert(name, sign);
+    }
+}
@@ -115,6 +115,13 @@ impl From<usize> for Depth {
 }
 
 
+impl From<Depth> for usize {
+    fn from(depth: Depth) -> Self {
+        depth.0
+    }
+}
+
+
 impl ops::Sub<usize> for Depth {
     type Output = Self;
     /// Define the subtraction of the `Depth` struct.
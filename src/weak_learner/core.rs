@@ -21,6 +21,16 @@ pub trait WeakLearner {
     }
 
 
+    /// Returns the privacy budget `ε` and the smoothness `κ`
+    /// if each call of [`WeakLearner::produce`] is
+    /// `ε`-differentially private for `κ`-smooth distributions.
+    /// This method is used for [`DPBoost`](crate::booster::DPBoost).
+    /// By default, this method returns `None`.
+    fn privacy(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+
     /// For classification, `WeakLearner::produce`
     /// outputs an instance of `Classifier` trait
     /// that achieves high accuracy
@@ -29,3 +39,40 @@ pub trait WeakLearner {
         -> Self::Hypothesis;
 }
 
+
+
+/// An interface for the weak learners in the online boosting protocol.
+/// Unlike [`WeakLearner`],
+/// an `OnlineWeakLearner` is itself a hypothesis
+/// that is updated incrementally by a stream of examples.
+/// 
+/// The examples are given as the slices of feature values,
+/// ordered as the features of a [`Sample`].
+/// The labels are assumed to be `+1` or `-1`.
+pub trait OnlineWeakLearner {
+    /// Defines the name of weak-learning algorithm.
+    fn name(&self) -> &str {
+        "Not specified"
+    }
+
+
+    /// Updates `self` by the example `(x, y)`
+    /// that has importance `weight`.
+    /// [`OzaBoost`](crate::booster::OzaBoost) calls this method
+    /// once per example, possibly with `weight == 0.0`.
+    /// A weak learner with forgetting should forget in every call
+    /// and ignore the example of zero weight.
+    fn update(&mut self, x: &[f64], y: f64, weight: f64);
+
+
+    /// Computes the confidence for the instance `x`.
+    /// This method returns a value in `[-1.0, 1.0]`.
+    fn confidence_on(&self, x: &[f64]) -> f64;
+
+
+    /// Predicts the label of the instance `x`.
+    fn predict_on(&self, x: &[f64]) -> i64 {
+        let conf = self.confidence_on(x);
+        if conf >= 0.0 { 1 } else { -1 }
+    }
+}
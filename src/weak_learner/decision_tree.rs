@@ -13,6 +13,9 @@ mod node;
 mod criterion;
 mod train_node;
 
+// Defines the differentially private tree growing.
+mod privacy;
+
 
 pub use decision_tree_classifier::DecisionTreeClassifier;
 pub use decision_tree_algorithm::DecisionTree;
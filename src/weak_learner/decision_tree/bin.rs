@@ -57,17 +57,38 @@ impl Bins {
     #[inline(always)]
     pub fn cut(feature: &Feature, n_bin: usize) -> Self
     {
-        let mut bins = match feature {
+        let bins = match feature {
             Feature::Dense(feat) => Self::cut_dense(feat, n_bin),
             Feature::Sparse(feat) => Self::cut_sparse(feat, n_bin),
         };
 
+        bins.unbounded()
+    }
+
+
+    /// Cut the range `[min, max)` into `n_bins` bins with same width.
+    /// Unlike [`Bins::cut`], the bins do not depend on the feature values.
+    /// The values out of the range fall into the left-most
+    /// or the right-most bin.
+    pub fn cut_range(min: f64, max: f64, n_bin: usize) -> Self
+    {
+        assert!(
+            min.is_finite() && max.is_finite() && min < max,
+            "The range must satisfy `min < max`"
+        );
+        Self(equal_width_bins(min, max, n_bin)).unbounded()
+    }
+
+
+    /// Extends the left-most and the right-most bins to the infinity.
+    #[inline(always)]
+    fn unbounded(mut self) -> Self {
         // The `start` of the left-most bin should be `f64::MIN`.
-        bins.0.first_mut().unwrap().0.start = f64::MIN;
+        self.0.first_mut().unwrap().0.start = f64::MIN;
         // The `end` of the right-most bin should be `f64::MAX`.
-        bins.0.last_mut().unwrap().0.end = f64::MAX;
+        self.0.last_mut().unwrap().0.end = f64::MAX;
 
-        bins
+        self
     }
 
 
@@ -117,26 +138,7 @@ impl Bins {
         }
 
 
-        let intercept = (max - min) / n_bin as f64;
-
-        let mut bins = Vec::with_capacity(n_bin);
-
-        let mut left = min;
-        while left < max {
-            let right = left + intercept;
-            bins.push(Bin::new(left..right));
-
-            // Numerical error leads an unexpected split.
-            // So, we ignore the bin with width smaller than 1e-9.
-            if (right - max).abs() < NUM_TOLERANCE { break; }
-
-            left = right;
-        }
-
-
-        assert_eq!(bins.len(), n_bin);
-
-        Self(bins)
+        Self(equal_width_bins(min, max, n_bin))
     }
 
 
@@ -171,26 +173,7 @@ impl Bins {
         }
 
 
-        let intercept = (max - min) / n_bin as f64;
-
-        let mut bins = Vec::with_capacity(n_bin);
-
-        let mut left = min;
-        while left < max {
-            let right = left + intercept;
-            bins.push(Bin::new(left..right));
-
-            // Numerical error leads an unexpected split.
-            // So, we ignore the bin with width smaller than 1e-9.
-            if (right - max).abs() < NUM_TOLERANCE { break; }
-
-            left = right;
-        }
-
-
-        assert_eq!(bins.len(), n_bin);
-
-        Self(bins)
+        Self(equal_width_bins(min, max, n_bin))
     }
 
 
@@ -201,6 +184,22 @@ impl Bins {
         y: &[f64],
         dist: &[f64]
     ) -> Vec<(Bin, LabelToWeight)>
+    {
+        let packed = self.weights_per_bin(indices, feat, y, dist);
+        self.remove_zero_weight_pack_and_normalize(packed)
+    }
+
+
+    /// Returns the weight on each label for each bin.
+    /// Unlike [`Bins::pack`], this method keeps the bins with zero weights
+    /// so that the bin bounds do not depend on `dist`.
+    pub(crate) fn weights_per_bin(
+        &self,
+        indices: &[usize],
+        feat: &Feature,
+        y: &[f64],
+        dist: &[f64]
+    ) -> Vec<LabelToWeight>
     {
         let n_bins = self.0.len();
         let mut packed = vec![LabelToWeight::new(); n_bins];
@@ -221,7 +220,13 @@ impl Bins {
             let weight = packed[pos].entry(yi).or_insert(0.0);
             *weight += di;
         }
-        self.remove_zero_weight_pack_and_normalize(packed)
+        packed
+    }
+
+
+    /// Returns an iterator over the bins.
+    pub(crate) fn iter(&self) -> std::slice::Iter<'_, Bin> {
+        self.0.iter()
     }
 
 
@@ -334,6 +339,31 @@ impl Bins {
 }
 
 
+/// Cut the range `[min, max)` into `n_bin` bins with same width.
+fn equal_width_bins(min: f64, max: f64, n_bin: usize) -> Vec<Bin> {
+    let intercept = (max - min) / n_bin as f64;
+
+    let mut bins = Vec::with_capacity(n_bin);
+
+    let mut left = min;
+    while left < max {
+        let right = left + intercept;
+        bins.push(Bin::new(left..right));
+
+        // Numerical error leads an unexpected split.
+        // So, we ignore the bin with width smaller than 1e-9.
+        if (right - max).abs() < NUM_TOLERANCE { break; }
+
+        left = right;
+    }
+
+
+    assert_eq!(bins.len(), n_bin);
+
+    bins
+}
+
+
 // fn value_counts(feature: &Feature) -> Vec<(f64, usize)> {
 //     match feature {
 //         Feature::Dense(feat) => value_counts_dense(feat),
@@ -1,5 +1,6 @@
 use crate::{Sample, DecisionTree};
 use crate::weak_learner::common::type_and_struct::*;
+use crate::weak_learner::common::monotone::*;
 use super::bin::*;
 use super::criterion::*;
 use std::collections::HashMap;
@@ -29,9 +30,19 @@ pub struct DecisionTreeBuilder<'a> {
     sample: &'a Sample,
     /// Number of bins per feature.
     n_bins: HashMap<&'a str, usize>,
+    /// Public ranges of the features.
+    ranges: HashMap<&'a str, (f64, f64)>,
 
     max_depth: Depth,
     criterion: Criterion,
+
+    /// Privacy budget `ε` and smoothness `κ`
+    /// for the differentially private tree.
+    privacy: Option<(f64, f64)>,
+    seed: u64,
+
+    /// Monotone constraints on the features.
+    monotone: MonotoneConstraints<'a>,
 }
 
 
@@ -40,8 +51,12 @@ impl<'a> DecisionTreeBuilder<'a> {
     /// By default, [`DecisionTreeBuilder`] sets the parameters as follows;
     /// ```text
     /// n_bins: DEFAULT_NBIN == 255,
+    /// ranges: None,
     /// max_depth: DEFAULT_MAX_DEPTH == 2,
     /// criterion: Criterion::Entropy,
+    /// privacy: None,
+    /// seed: 1234,
+    /// monotone constraints: None,
     /// ```
     pub fn new(sample: &'a Sample) -> Self {
         let n_bins = sample.features()
@@ -55,7 +70,16 @@ impl<'a> DecisionTreeBuilder<'a> {
         let max_depth = Depth::from(DEFAULT_MAX_DEPTH);
         let criterion = Criterion::Entropy;
 
-        Self { sample, n_bins, max_depth, criterion, }
+        Self {
+            sample,
+            n_bins,
+            ranges: HashMap::new(),
+            max_depth,
+            criterion,
+            privacy: None,
+            seed: 1234,
+            monotone: MonotoneConstraints::new(),
+        }
     }
 
 
@@ -79,6 +103,86 @@ impl<'a> DecisionTreeBuilder<'a> {
     }
 
 
+    /// Set the public range `[min, max)` of the feature named `name`.
+    /// The bins of the feature cut this range
+    /// instead of the one of the feature values,
+    /// and the number of bins is reset to `DEFAULT_NBIN == 255`.
+    /// Call [`DecisionTreeBuilder::set_nbins`] after this method
+    /// to change the number of bins.
+    /// By default, the bins cut the range of the feature values.
+    pub fn feature_range<T>(mut self, name: T, min: f64, max: f64) -> Self
+        where T: AsRef<str>
+    {
+        let name = name.as_ref();
+        assert!(
+            min.is_finite() && max.is_finite() && min < max,
+            "The range must satisfy `min < max`"
+        );
+        let Some((&key, _)) = self.n_bins.get_key_value(name) else {
+            panic!("The feature named `{name}` does not exist");
+        };
+        self.n_bins.insert(key, DEFAULT_NBIN);
+        self.ranges.insert(key, (min, max));
+        self
+    }
+
+
+    /// Make the tree `epsilon`-differentially private
+    /// for `kappa`-smooth distributions,
+    /// i.e., the distributions that put weight at most `1 / (kappa * m)`
+    /// on each example.
+    /// See [`DecisionTree`] for details.
+    /// By default, the tree is not private.
+    /// 
+    /// Since the bins must not depend on the private data,
+    /// this method panics unless the public ranges of all the features
+    /// are set by [`DecisionTreeBuilder::feature_range`].
+    pub fn differential_privacy(mut self, epsilon: f64, kappa: f64) -> Self {
+        assert!(epsilon > 0.0, "The privacy budget must be positive");
+        assert!(
+            kappa > 0.0 && kappa <= 1.0,
+            "The smoothness parameter must be in (0.0, 1.0]"
+        );
+        let missing = self.sample.features()
+            .iter()
+            .map(|feature| feature.name())
+            .filter(|name| !self.ranges.contains_key(name))
+            .collect::<Vec<_>>();
+        assert!(
+            missing.is_empty(),
+            "The differentially private tree requires the public ranges \
+             of all the features. \
+             Set the ranges of {missing:?} by `feature_range`"
+        );
+        self.privacy = Some((epsilon, kappa));
+        self
+    }
+
+
+    /// Set the seed for the noise of the differentially private tree.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Set the monotone constraint on the feature named `name`.
+    /// `sign = 1` (resp. `-1`) makes the confidence of the tree
+    /// non-decreasing (resp. non-increasing) in the feature,
+    /// and `sign = 0` removes the constraint.
+    /// By default, no feature is constrained.
+    /// See [`DecisionTree`] for details.
+    pub fn monotone_constraint<T>(mut self, name: T, sign: i32) -> Self
+        where T: AsRef<str>
+    {
+        set_monotone_constraint(
+            &mut self.monotone, &self.n_bins, name.as_ref(), sign
+        );
+        self
+    }
+
+
     /// Set the number of bins to a feature named `name`.
     /// By default, each feature is binned in `255` bins.
     pub fn set_nbins<T>(&mut self, name: T, n_bins: usize)
@@ -103,13 +207,26 @@ impl<'a> DecisionTreeBuilder<'a> {
                 let name = feature.name();
                 let n_bins = *self.n_bins.get(name).unwrap();
 
-                (name, Bins::cut(feature, n_bins))
+                let bins = match self.ranges.get(name) {
+                    Some(&(min, max)) => Bins::cut_range(min, max, n_bins),
+                    None => Bins::cut(feature, n_bins),
+                };
+                (name, bins)
             })
             .collect::<HashMap<_, _>>();
 
-        let dtree = DecisionTree::from_components(
+        let mut dtree = DecisionTree::from_components(
             bins, self.criterion, self.max_depth
         );
+        if let Some((epsilon, kappa)) = self.privacy {
+            assert!(
+                self.monotone.is_empty(),
+                "The monotone constraints are not supported \
+                 for the differentially private tree"
+            );
+            dtree = dtree.with_privacy(epsilon, kappa, self.seed);
+        }
+        dtree = dtree.with_monotone_constraints(self.monotone);
 
 
         dtree
@@ -14,6 +14,7 @@ use crate::Sample;
 use super::bin::*;
 use crate::weak_learner::common::{
     type_and_struct::*,
+    monotone::*,
 };
 
 
@@ -101,12 +102,16 @@ impl fmt::Display for Criterion {
 
 impl Criterion {
     /// Returns the best splitting rule based on the criterion.
+    /// The splits whose children violate the monotone constraints
+    /// `monotone` under the bounds `bounds` are rejected.
     pub(super) fn best_split<'a>(
         &self,
         bins_map: &HashMap<&'a str, Bins>,
         sample: &'a Sample,
         dist: &[f64],
         idx: &[usize],
+        monotone: &MonotoneConstraints<'_>,
+        bounds: Bounds,
     ) -> (&'a str, f64)
     {
         let target = sample.target();
@@ -118,7 +123,10 @@ impl Criterion {
                         let name = feature.name();
                         let bin = bins_map.get(name).unwrap();
                         let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_entropy(pack);
+                        let sign = monotone.get(name).copied().unwrap_or(0);
+                        let (threshold, score) = split_by_entropy(
+                            pack, sign, bounds
+                        );
 
                         (score, name, threshold)
                     })
@@ -133,7 +141,10 @@ impl Criterion {
                         let name = feature.name();
                         let bin = bins_map.get(name).unwrap();
                         let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_edge(pack);
+                        let sign = monotone.get(name).copied().unwrap_or(0);
+                        let (threshold, score) = split_by_edge(
+                            pack, sign, bounds
+                        );
 
                         (score, name, threshold)
                     })
@@ -148,7 +159,10 @@ impl Criterion {
                         let name = feature.name();
                         let bin = bins_map.get(name).unwrap();
                         let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_gini(pack);
+                        let sign = monotone.get(name).copied().unwrap_or(0);
+                        let (threshold, score) = split_by_gini(
+                            pack, sign, bounds
+                        );
 
                         (score, name, threshold)
                     })
@@ -163,7 +177,10 @@ impl Criterion {
                         let name = feature.name();
                         let bin = bins_map.get(name).unwrap();
                         let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_twoing(pack);
+                        let sign = monotone.get(name).copied().unwrap_or(0);
+                        let (threshold, score) = split_by_twoing(
+                            pack, sign, bounds
+                        );
 
                         (score, name, threshold)
                     })
@@ -176,7 +193,7 @@ impl Criterion {
 }
 
 
-fn split_by_entropy(pack: Vec<(Bin, LabelToWeight)>)
+fn split_by_entropy(pack: Vec<(Bin, LabelToWeight)>, sign: i32, bounds: Bounds)
     -> (f64, Score)
 {
     let weight_sum = pack.iter()
@@ -209,6 +226,9 @@ fn split_by_entropy(pack: Vec<(Bin, LabelToWeight)>)
             *entry -= w;
             right_weight_sum -= w;
         }
+        if !is_admissible(&left_weight, &right_weight, sign, bounds) {
+            continue;
+        }
         let lp = left_weight_sum / weight_sum;
         let rp = (1f64 - lp).max(0f64);
 
@@ -227,7 +247,9 @@ fn split_by_entropy(pack: Vec<(Bin, LabelToWeight)>)
 }
 
 
-fn split_by_edge(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
+fn split_by_edge(pack: Vec<(Bin, LabelToWeight)>, sign: i32, bounds: Bounds)
+    -> (f64, Score)
+{
     // Compute the edge of the hypothesis that predicts `+1`
     // for all instances.
     let mut edge = pack.iter()
@@ -242,11 +264,34 @@ fn split_by_edge(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
     let mut best_threshold = f64::MIN;
 
 
+    // The weights on both sides are used
+    // only for the monotone constraints.
+    let mut left_weight = LabelToWeight::new();
+    let mut right_weight = LabelToWeight::new();
+    if sign != 0 {
+        for (_, mp) in pack.iter() {
+            for (y, d) in mp.iter() {
+                *right_weight.entry(*y).or_insert(0f64) += d;
+            }
+        }
+    }
+
+
     for (bin, map) in pack {
+        if sign != 0 {
+            for (y, d) in map.iter() {
+                *left_weight.entry(*y).or_insert(0f64) += d;
+                *right_weight.entry(*y).or_insert(0f64) -= d;
+            }
+        }
         edge -= 2f64 * map.into_iter()
             .map(|(y, d)| y as f64 * d)
             .sum::<f64>();
 
+        if !is_admissible(&left_weight, &right_weight, sign, bounds) {
+            continue;
+        }
+
 
         if best_edge < edge.abs() {
             best_edge = edge.abs();
@@ -258,7 +303,9 @@ fn split_by_edge(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
 }
 
 
-fn split_by_gini(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
+fn split_by_gini(pack: Vec<(Bin, LabelToWeight)>, sign: i32, bounds: Bounds)
+    -> (f64, Score)
+{
     const ZERO_WEIGHT_TOLERANCE: f64 = 1e-12;
 
     let weight_sum = pack.iter()
@@ -300,6 +347,9 @@ fn split_by_gini(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
             right_weight_sum -= w;
 
 
+        }
+        if !is_admissible(&left_weight, &right_weight, sign, bounds) {
+            continue;
         }
         let lp = left_weight_sum / weight_sum;
         let rp = (1f64 - lp).max(0f64);
@@ -319,7 +369,9 @@ fn split_by_gini(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
 }
 
 
-fn split_by_twoing(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
+fn split_by_twoing(pack: Vec<(Bin, LabelToWeight)>, sign: i32, bounds: Bounds)
+    -> (f64, Score)
+{
     let mut left_weight = LabelToWeight::new();
     let mut right_weight = LabelToWeight::new();
 
@@ -347,6 +399,9 @@ fn split_by_twoing(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
 
             if *entry <= 0f64 { right_weight.remove(&y); }
         }
+        if !is_admissible(&left_weight, &right_weight, sign, bounds) {
+            continue;
+        }
 
 
         let score = twoing_score(&labels, &left_weight, &right_weight);
@@ -362,6 +417,38 @@ fn split_by_twoing(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
 }
 
 
+/// Returns the confidence of a leaf with the given weights,
+/// i.e., the weighted mean of the labels `+1` and `-1`.
+/// This is the confidence computed by `DecisionTree`.
+#[inline(always)]
+pub(super) fn mean_label(map: &LabelToWeight) -> f64 {
+    let total = map.values().sum::<f64>();
+    if total <= 0f64 { return 0f64; }
+
+    let pos = map.get(&1).copied().unwrap_or(0f64);
+    let neg = map.get(&-1).copied().unwrap_or(0f64);
+    (pos - neg) / total
+}
+
+
+/// Returns `true` if the split into `left` and `right`
+/// respects the monotone constraint `sign`,
+/// where the confidences of the children are clamped by `bounds`.
+#[inline(always)]
+fn is_admissible(
+    left: &LabelToWeight,
+    right: &LabelToWeight,
+    sign: i32,
+    bounds: Bounds,
+) -> bool
+{
+    if sign == 0 { return true; }
+    let left = bounds.clamp(mean_label(left));
+    let right = bounds.clamp(mean_label(right));
+    is_monotone(sign, left, right)
+}
+
+
 /// Returns the entropic-impurity of the given map.
 #[inline(always)]
 fn entropic_impurity(map: &HashMap<i32, f64>) -> f64 {
@@ -8,11 +8,13 @@ use super::bin::*;
 use crate::weak_learner::common::{
     type_and_struct::*,
     split_rule::*,
+    monotone::*,
 };
 use super::{
     node::*,
     criterion::*,
     train_node::*,
+    privacy::Privacy,
     decision_tree_classifier::DecisionTreeClassifier,
 };
 
@@ -72,10 +74,53 @@ use std::collections::HashMap;
 ///     / n_sample as f64;
 /// println!("loss (train) is: {loss}");
 /// ```
+///
+/// # Differential privacy
+/// [`DecisionTreeBuilder::differential_privacy`](crate::weak_learner::DecisionTreeBuilder::differential_privacy)
+/// makes each call of `produce` `ε`-differentially private.
+/// In this case, [`DecisionTree`] grows a full binary tree
+/// of depth `max_depth`.
+/// Each split is chosen by the exponential mechanism
+/// over the upper bounds of the bins,
+/// and each leaf predicts the label chosen by the report noisy max
+/// with the Laplace noise.
+/// The splitting criterion is ignored.
+///
+/// The guarantee assumes that
+/// 1. the bins do not depend on the private data, and
+/// 2. the distribution given to `produce` is `κ`-smooth,
+///    i.e., each example has weight at most `1 / (κ m)`,
+///    where `m` is the number of training examples.
+///
+/// For the first assumption,
+/// `differential_privacy` requires the public range of every feature, set by
+/// [`DecisionTreeBuilder::feature_range`](crate::weak_learner::DecisionTreeBuilder::feature_range),
+/// and the bins cut the public ranges.
+/// The number of bins should not depend on the private data either.
+/// The boosting algorithm [`DPBoost`](crate::booster::DPBoost)
+/// satisfies the second assumption.
+///
+/// # Monotone constraints
+/// [`DecisionTreeBuilder::monotone_constraint`](crate::weak_learner::DecisionTreeBuilder::monotone_constraint)
+/// makes the confidence of the tree
+/// non-decreasing (`+1`) or non-increasing (`-1`) in a feature.
+/// The labels must be `+1` or `-1`.
+/// A split on a constrained feature is rejected
+/// if the confidences of its children violate the constraint,
+/// and the confidences of the sub-trees are bounded
+/// by the midpoint of the children,
+/// so that the whole tree is monotone.
+/// Thus, a boosting algorithm with non-negative weights on the trees,
+/// e.g., [`AdaBoost`](crate::booster::AdaBoost),
+/// outputs a monotone classifier.
+/// The monotone constraints are not supported
+/// for the differentially private tree.
 pub struct DecisionTree<'a> {
     bins: HashMap<&'a str, Bins>,
     criterion: Criterion,
     max_depth: Depth,
+    privacy: Option<Privacy>,
+    monotone: MonotoneConstraints<'a>,
 }
 
 
@@ -89,7 +134,37 @@ impl<'a> DecisionTree<'a> {
         max_depth: Depth,
     ) -> Self
     {
-        Self { bins, criterion, max_depth, }
+        let monotone = MonotoneConstraints::new();
+        Self { bins, criterion, max_depth, privacy: None, monotone, }
+    }
+
+
+    /// Make the tree growing `epsilon`-differentially private
+    /// for `kappa`-smooth distributions.
+    /// This method is called only via `DecisionTreeBuilder::build`.
+    #[inline]
+    pub(super) fn with_privacy(
+        mut self,
+        epsilon: f64,
+        kappa: f64,
+        seed: u64,
+    ) -> Self
+    {
+        self.privacy = Some(Privacy::new(epsilon, kappa, seed));
+        self
+    }
+
+
+    /// Set the monotone constraints on the features.
+    /// This method is called only via `DecisionTreeBuilder::build`.
+    #[inline]
+    pub(super) fn with_monotone_constraints(
+        mut self,
+        monotone: MonotoneConstraints<'a>,
+    ) -> Self
+    {
+        self.monotone = monotone;
+        self
     }
 
 
@@ -102,6 +177,7 @@ impl<'a> DecisionTree<'a> {
         indices: Vec<usize>,
         criterion: Criterion,
         depth: Depth,
+        bounds: Bounds,
     ) -> TrainNodePtr
     {
         let total_weight = indices.par_iter()
@@ -112,19 +188,22 @@ impl<'a> DecisionTree<'a> {
 
         // Compute the best confidence that minimizes the training error
         // on this node.
-        let (conf, loss) = confidence_and_loss(sample, dist, &indices[..]);
+        let (conf, label, loss) = confidence_and_loss(
+            sample, dist, &indices[..]
+        );
+        let (conf, label) = bounded_confidence(conf, label, bounds);
 
 
         // If sum of `dist` over `train` is zero, construct a leaf node.
         if loss == 0f64 || depth < 1 {
-            return TrainNode::leaf(conf, total_weight, loss);
+            return TrainNode::leaf(conf, label, total_weight, loss);
         }
 
 
         // Find the best pair of feature name and threshold
         // based on the `criterion`.
         let (feature, threshold) = criterion.best_split(
-            &self.bins, sample, dist, &indices[..]
+            &self.bins, sample, dist, &indices[..], &self.monotone, bounds,
         );
 
 
@@ -146,17 +225,38 @@ impl<'a> DecisionTree<'a> {
 
         // If the split has no meaning, construct a leaf node.
         if lindices.is_empty() || rindices.is_empty() {
-            return TrainNode::leaf(conf, total_weight, loss);
+            return TrainNode::leaf(conf, label, total_weight, loss);
         }
 
+        // Bound the confidences of the children
+        // if the feature is constrained.
+        let (lbounds, rbounds) = match self.monotone.get(feature) {
+            Some(&sign) => {
+                let (lconf, _, _) = confidence_and_loss(
+                    sample, dist, &lindices[..]
+                );
+                let (rconf, _, _) = confidence_and_loss(
+                    sample, dist, &rindices[..]
+                );
+                let lconf = bounds.clamp(lconf.0);
+                let rconf = bounds.clamp(rconf.0);
+                bounds.split(sign, lconf, rconf)
+            },
+            None => (bounds, bounds),
+        };
+
         // At this point, `depth > 0` is guaranteed so that
         // one can grow the tree.
         let depth = depth - 1;
-        let ltree = self.full_tree(sample, dist, lindices, criterion, depth);
-        let rtree = self.full_tree(sample, dist, rindices, criterion, depth);
+        let ltree = self.full_tree(
+            sample, dist, lindices, criterion, depth, lbounds
+        );
+        let rtree = self.full_tree(
+            sample, dist, rindices, criterion, depth, rbounds
+        );
 
 
-        TrainNode::branch(rule, ltree, rtree, conf, total_weight, loss)
+        TrainNode::branch(rule, ltree, rtree, conf, label, total_weight, loss)
     }
 }
 
@@ -175,15 +275,31 @@ impl WeakLearner for DecisionTree<'_> {
             .map(|bin| bin.len())
             .reduce(usize::max)
             .unwrap_or(0);
-        let info = Vec::from([
+        let mut info = Vec::from([
             ("# of bins (max)", format!("{n_bins}")),
             ("Max depth", format!("{}", self.max_depth)),
             ("Split criterion", format!("{}", self.criterion)),
         ]);
+        if let Some(privacy) = self.privacy.as_ref() {
+            info.push(("Privacy budget (ε)", format!("{}", privacy.epsilon())));
+            info.push(("Smoothness (κ)", format!("{}", privacy.kappa())));
+        }
+        if !self.monotone.is_empty() {
+            info.push((
+                "# of monotone constraints",
+                format!("{}", self.monotone.len())
+            ));
+        }
         Some(info)
     }
 
 
+    fn privacy(&self) -> Option<(f64, f64)> {
+        self.privacy.as_ref()
+            .map(|privacy| (privacy.epsilon(), privacy.kappa()))
+    }
+
+
     /// This method computes as follows;
     /// 1. construct a `TrainNode` which contains some information
     ///     to grow a tree (e.g., impurity, total distribution mass, etc.)
@@ -192,6 +308,12 @@ impl WeakLearner for DecisionTree<'_> {
     fn produce(&self, sample: &Sample, dist: &[f64])
         -> Self::Hypothesis
     {
+        if let Some(privacy) = self.privacy.as_ref() {
+            let root = privacy.grow(&self.bins, sample, dist, self.max_depth);
+            return DecisionTreeClassifier::from(root);
+        }
+
+
         let n_sample = sample.shape().0;
 
         let indices = (0..n_sample).filter(|&i| dist[i] > 0f64)
@@ -202,7 +324,8 @@ impl WeakLearner for DecisionTree<'_> {
 
         // Construct a large binary tree
         let tree = self.full_tree(
-            sample, dist, indices, criterion, self.max_depth
+            sample, dist, indices, criterion, self.max_depth,
+            Bounds::unbounded(),
         );
 
 
@@ -221,15 +344,18 @@ impl WeakLearner for DecisionTree<'_> {
 }
 
 
-/// This function returns a tuple `(c, l)` where
+/// This function returns a tuple `(c, y, l)` where
 /// `c` is the **confidence** for some label `y`
 /// that minimizes the training loss.
+/// - `y` is the label that have most weight.
 /// - `l` is the training loss when the confidence is `y`.
 /// 
-/// **Note that** this function assumes that the label is `+1` or `-1`.
+/// **Note that** the confidence `c` assumes
+/// that the label is `+1` or `-1`.
+/// For multi-class labels, use `y` instead.
 #[inline]
 fn confidence_and_loss(sample: &Sample, dist: &[f64], indices: &[usize])
-    -> (Confidence<f64>, LossValue)
+    -> (Confidence<f64>, i64, LossValue)
 {
 
     assert_ne!(indices.len(), 0);
@@ -264,7 +390,23 @@ fn confidence_and_loss(sample: &Sample, dist: &[f64], indices: &[usize])
 
     let confidence = Confidence::from(confidence);
     let loss = LossValue::from(loss);
-    (confidence, loss)
+    (confidence, label, loss)
+}
+
+
+/// Clamp the confidence `conf` by `bounds`.
+/// If the confidence is clamped,
+/// the label becomes the sign of the clamped confidence.
+#[inline]
+fn bounded_confidence(conf: Confidence<f64>, label: i64, bounds: Bounds)
+    -> (Confidence<f64>, i64)
+{
+    let clamped = bounds.clamp(conf.0);
+    if clamped == conf.0 {
+        return (conf, label);
+    }
+    let label = if clamped >= 0f64 { 1 } else { -1 };
+    (Confidence::from(clamped), label)
 }
 
 
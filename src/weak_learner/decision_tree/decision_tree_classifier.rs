@@ -1,5 +1,5 @@
 //! Defines the decision tree classifier.
-use crate::{Classifier, Sample};
+use crate::{Classifier, MultiClassifier, Sample};
 
 
 use super::node::*;
@@ -33,6 +33,13 @@ impl Classifier for DecisionTreeClassifier {
 }
 
 
+impl MultiClassifier for DecisionTreeClassifier {
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize {
+        self.root.predict_class(sample, row)
+    }
+}
+
+
 impl DecisionTreeClassifier {
     /// Write the current decision tree to dot file.
     #[inline]
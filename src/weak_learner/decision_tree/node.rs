@@ -1,6 +1,6 @@
 //! Defines the inner representation 
 //! of the Decision Tree class.
-use crate::{Classifier, Sample};
+use crate::{Classifier, MultiClassifier, Sample};
 
 
 use crate::weak_learner::common::{
@@ -56,6 +56,10 @@ impl BranchNode {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LeafNode {
     pub(super) confidence: Confidence<f64>,
+    /// The label that have most weight on this leaf.
+    /// Trees serialized before this field existed default to `0`.
+    #[serde(default)]
+    pub(super) label: i64,
 }
 
 
@@ -64,8 +68,8 @@ impl LeafNode {
     /// given to this function.
     /// Note that this function does not assign the impurity.
     #[inline]
-    pub(crate) fn from_raw(confidence: Confidence<f64>) -> Self {
-        Self { confidence }
+    pub(crate) fn from_raw(confidence: Confidence<f64>, label: i64) -> Self {
+        Self { confidence, label, }
     }
 }
 
@@ -95,7 +99,7 @@ impl From<TrainBranchNode> for BranchNode {
 impl From<TrainLeafNode> for LeafNode {
     #[inline]
     fn from(leaf: TrainLeafNode) -> Self {
-        Self::from_raw(leaf.confidence)
+        Self::from_raw(leaf.confidence, leaf.label)
     }
 }
 
@@ -145,6 +149,36 @@ impl Classifier for Node {
 }
 
 
+impl MultiClassifier for LeafNode {
+    #[inline]
+    fn predict_class(&self, _sample: &Sample, _row: usize) -> usize {
+        self.label.max(0) as usize
+    }
+}
+
+
+impl MultiClassifier for BranchNode {
+    #[inline]
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize {
+        match self.rule.split(sample, row) {
+            LR::Left => self.left.predict_class(sample, row),
+            LR::Right => self.right.predict_class(sample, row)
+        }
+    }
+}
+
+
+impl MultiClassifier for Node {
+    #[inline]
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize {
+        match self {
+            Node::Branch(ref node) => node.predict_class(sample, row),
+            Node::Leaf(ref node) => node.predict_class(sample, row)
+        }
+    }
+}
+
+
 impl Node {
     pub(super) fn to_dot_info(&self, id: usize) -> (Vec<String>, usize) {
         match self {
@@ -0,0 +1,254 @@
+//! Defines the differentially private tree growing
+//! for the decision tree weak learner.
+use rand::prelude::*;
+
+
+use crate::Sample;
+use crate::common::privacy::laplace_noise;
+use super::bin::*;
+
+
+use crate::weak_learner::common::{
+    type_and_struct::*,
+    split_rule::*,
+};
+use super::node::*;
+
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+
+/// Parameters for growing a decision tree
+/// with `epsilon`-differential privacy.
+pub(super) struct Privacy {
+    // Privacy budget `ε` for a single call of `produce`.
+    epsilon: f64,
+
+    // Smoothness of the distributions given to `produce`.
+    // Each example has weight at most `1 / (kappa * m)`.
+    kappa: f64,
+
+    // Seed for the noise.
+    seed: u64,
+
+    // The number of calls of `produce` so far.
+    calls: AtomicU64,
+}
+
+
+impl Privacy {
+    /// Construct a new instance of `Privacy`.
+    #[inline]
+    pub(super) fn new(epsilon: f64, kappa: f64, seed: u64) -> Self {
+        Self { epsilon, kappa, seed, calls: AtomicU64::new(0), }
+    }
+
+
+    /// Returns the privacy budget `ε` for a single tree.
+    #[inline]
+    pub(super) fn epsilon(&self) -> f64 {
+        self.epsilon
+    }
+
+
+    /// Returns the smoothness parameter `κ`.
+    #[inline]
+    pub(super) fn kappa(&self) -> f64 {
+        self.kappa
+    }
+
+
+    /// Returns a random number generator for a new tree.
+    #[inline]
+    fn rng(&self) -> StdRng {
+        let calls = self.calls.fetch_add(1, Ordering::Relaxed);
+        StdRng::seed_from_u64(self.seed.wrapping_add(calls))
+    }
+
+
+    /// Grows a full binary tree of depth `depth`
+    /// with `ε`-differential privacy.
+    ///
+    /// The budget `ε` is split equally into the `depth` split levels
+    /// and the leaf level.
+    /// Since the nodes of the same level partition the examples,
+    /// each level costs its share of `ε` by the parallel composition.
+    pub(super) fn grow(
+        &self,
+        bins: &HashMap<&str, Bins>,
+        sample: &Sample,
+        dist: &[f64],
+        depth: Depth,
+    ) -> Node
+    {
+        let n_sample = sample.shape().0;
+        let depth = usize::from(depth);
+        let mut rng = self.rng();
+
+        // Replacing an example changes each score by
+        // at most twice the maximal weight `1 / (κ m)`.
+        let sensitivity = 2.0 / (self.kappa * n_sample as f64);
+        let epsilon = self.epsilon / (depth + 1) as f64;
+
+        let indices = (0..n_sample).collect::<Vec<_>>();
+        let grower = Grower { bins, sample, dist, sensitivity, epsilon, };
+        grower.full_tree(indices, depth, &mut rng)
+    }
+}
+
+
+/// A helper that keeps the arguments of the recursion.
+struct Grower<'b, 'c> {
+    bins: &'b HashMap<&'b str, Bins>,
+    sample: &'c Sample,
+    dist: &'c [f64],
+    sensitivity: f64,
+    epsilon: f64,
+}
+
+
+impl Grower<'_, '_> {
+    /// Construct a full binary tree of depth `depth`.
+    /// Unlike `DecisionTree::full_tree`,
+    /// the shape of the tree does not depend on `dist`.
+    fn full_tree(
+        &self,
+        indices: Vec<usize>,
+        depth: usize,
+        rng: &mut StdRng,
+    ) -> Node
+    {
+        if depth < 1 {
+            return Node::Leaf(self.noisy_leaf(&indices[..], rng));
+        }
+
+        let Some((feature, threshold)) = self.exponential_mechanism(
+            &indices[..], rng
+        )
+        else {
+            return Node::Leaf(self.noisy_leaf(&indices[..], rng));
+        };
+
+        let rule = Splitter::new(feature, Threshold::from(threshold));
+
+        let mut lindices = Vec::new();
+        let mut rindices = Vec::new();
+        for i in indices {
+            match rule.split(self.sample, i) {
+                LR::Left  => { lindices.push(i); },
+                LR::Right => { rindices.push(i); },
+            }
+        }
+
+        let depth = depth - 1;
+        let left = self.full_tree(lindices, depth, rng);
+        let right = self.full_tree(rindices, depth, rng);
+
+        Node::Branch(
+            BranchNode::from_raw(rule, Box::new(left), Box::new(right))
+        )
+    }
+
+
+    /// Chooses a pair of feature name and threshold
+    /// by the exponential mechanism.
+    /// The candidates are the upper bounds of the bins,
+    /// and the score of a candidate is
+    /// the weighted accuracy of the majority labels on both sides.
+    /// Returns `None` if no feature has two or more bins.
+    fn exponential_mechanism(
+        &self,
+        indices: &[usize],
+        rng: &mut StdRng,
+    ) -> Option<(&str, f64)>
+    {
+        let target = self.sample.target();
+
+        let mut candidates = Vec::new();
+        for feature in self.sample.features() {
+            let name = feature.name();
+            let bins = self.bins.get(name).unwrap();
+            if bins.len() < 2 { continue; }
+
+            let weights = bins.weights_per_bin(
+                indices, feature, target, self.dist
+            );
+
+            let (mut rp, mut rn) = weights.iter()
+                .fold((0.0, 0.0), |(p, n), w| (p + pos(w), n + neg(w)));
+            let (mut lp, mut ln) = (0.0, 0.0);
+
+            // The last bin yields an empty right side,
+            // so that it is not a candidate.
+            let n_bins = bins.len();
+            for (bin, w) in bins.iter().zip(weights).take(n_bins - 1) {
+                lp += pos(&w);
+                ln += neg(&w);
+                rp -= pos(&w);
+                rn -= neg(&w);
+
+                let score = lp.max(ln) + rp.max(rn);
+                candidates.push((score, name, bin.0.end));
+            }
+        }
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        // Sample a candidate with probability
+        // `∝ exp( ε * score / (2 * sensitivity) )`.
+        let scale = self.epsilon / (2.0 * self.sensitivity);
+        let max_score = candidates.iter()
+            .map(|(s, _, _)| *s)
+            .fold(f64::MIN, f64::max);
+        let weights = candidates.iter()
+            .map(|(s, _, _)| (scale * (s - max_score)).exp())
+            .collect::<Vec<_>>();
+
+        let total = weights.iter().sum::<f64>();
+        let mut u = rng.gen::<f64>() * total;
+        for ((_, name, threshold), w) in candidates.iter().zip(weights) {
+            if u < w {
+                return Some((name, *threshold));
+            }
+            u -= w;
+        }
+        candidates.last().map(|(_, name, threshold)| (*name, *threshold))
+    }
+
+
+    /// Returns a leaf that predicts the label
+    /// chosen by the report noisy max.
+    fn noisy_leaf(&self, indices: &[usize], rng: &mut StdRng) -> LeafNode {
+        let target = self.sample.target();
+        let (p, n) = indices.iter()
+            .fold((0.0, 0.0), |(p, n), &i| {
+                if target[i] > 0.0 {
+                    (p + self.dist[i], n)
+                } else {
+                    (p, n + self.dist[i])
+                }
+            });
+
+        let scale = 2.0 * self.sensitivity / self.epsilon;
+        let p = p + laplace_noise(scale, rng);
+        let n = n + laplace_noise(scale, rng);
+
+        let label = if p >= n { 1 } else { -1 };
+        LeafNode::from_raw(Confidence::from(label as f64), label)
+    }
+}
+
+
+#[inline(always)]
+fn pos(weight: &LabelToWeight) -> f64 {
+    weight.get(&1).copied().unwrap_or(0.0)
+}
+
+
+#[inline(always)]
+fn neg(weight: &LabelToWeight) -> f64 {
+    weight.get(&-1).copied().unwrap_or(0.0)
+}
@@ -44,10 +44,14 @@ pub struct TrainBranchNode {
     pub(super) right: TrainNodePtr,
 
 
-    // A label that have most weight on this node.
+    // Confidence for the label that have most weight on this node.
     pub(super) confidence: Confidence<f64>,
 
 
+    // A label that have most weight on this node.
+    pub(super) label: i64,
+
+
     // Total mass on this node.
     pub(self) total_weight: f64,
 
@@ -75,6 +79,7 @@ impl TrainBranchNode {
 /// Represents the leaf nodes of decision tree.
 pub struct TrainLeafNode {
     pub(super) confidence: Confidence<f64>,
+    pub(super) label: i64,
     pub(self) total_weight: f64,
     pub(self) loss_as_leaf: LossValue,
 }
@@ -97,6 +102,7 @@ impl From<TrainBranchNode> for TrainLeafNode {
     fn from(branch: TrainBranchNode) -> Self {
         Self {
             confidence: branch.confidence,
+            label: branch.label,
             total_weight: branch.total_weight,
             loss_as_leaf: branch.loss_as_leaf,
         }
@@ -109,12 +115,14 @@ impl TrainNode {
     #[inline]
     pub(super) fn leaf(
         confidence: Confidence<f64>,
+        label: i64,
         total_weight: f64,
         loss_as_leaf: LossValue,
     ) -> Rc<RefCell<Self>>
     {
         let leaf = TrainLeafNode {
             confidence,
+            label,
             total_weight,
             loss_as_leaf,
         };
@@ -131,6 +139,7 @@ impl TrainNode {
         left: TrainNodePtr,
         right: TrainNodePtr,
         confidence: Confidence<f64>,
+        label: i64,
         total_weight: f64,
         loss_as_leaf: LossValue,
     ) -> Rc<RefCell<Self>>
@@ -142,6 +151,7 @@ impl TrainNode {
             right,
 
             confidence,
+            label,
             total_weight,
             loss_as_leaf,
 
@@ -216,6 +226,7 @@ impl TrainNode {
                     *self = TrainNode::Leaf(
                         TrainLeafNode {
                             confidence: branch.confidence,
+                            label: branch.label,
                             total_weight: branch.total_weight,
                             loss_as_leaf: branch.loss_as_leaf,
                         }
@@ -0,0 +1,5 @@
+// Defines the Hoeffding tree stump for online boosting.
+mod hoeffding_stump;
+
+
+pub use hoeffding_stump::HoeffdingStump;
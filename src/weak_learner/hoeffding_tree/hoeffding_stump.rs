@@ -0,0 +1,464 @@
+//! Defines the Hoeffding tree stump,
+//! an incremental decision stump for online boosting.
+use serde::{
+    Serialize,
+    Deserialize,
+};
+
+use crate::{Sample, Classifier};
+use crate::weak_learner::core::OnlineWeakLearner;
+
+
+/// Running statistics of a feature for the examples with the same label.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct GaussianStats {
+    weight: f64,
+    mean: f64,
+    // Weighted sum of squared deviations from the mean.
+    m2: f64,
+}
+
+
+impl GaussianStats {
+    /// Adds the value `x` with `weight` by West's algorithm.
+    #[inline]
+    fn update(&mut self, x: f64, weight: f64) {
+        self.weight += weight;
+        let delta = x - self.mean;
+        self.mean += delta * weight / self.weight;
+        self.m2 += weight * delta * (x - self.mean);
+    }
+
+
+    /// Multiplies the weights of the past values by `decay`.
+    /// The mean and the variance do not change.
+    #[inline]
+    fn forget(&mut self, decay: f64) {
+        self.weight *= decay;
+        self.m2 *= decay;
+    }
+
+
+    /// Returns the estimated weight of the values
+    /// that are smaller than `threshold`.
+    #[inline]
+    fn weight_below(&self, threshold: f64) -> f64 {
+        if self.weight <= 0.0 { return 0.0; }
+
+        let std = (self.m2 / self.weight).max(0.0).sqrt();
+        if std <= f64::EPSILON {
+            return if self.mean < threshold { self.weight } else { 0.0 };
+        }
+        let z = (threshold - self.mean) / std;
+        self.weight * normal_cdf(z)
+    }
+}
+
+
+/// Observes a feature for each label to estimate the splits.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct FeatureObserver {
+    min: f64,
+    max: f64,
+    // Statistics for the labels `-1` and `+1`, respectively.
+    stats: [GaussianStats; 2],
+}
+
+
+impl FeatureObserver {
+    fn new() -> Self {
+        Self {
+            min: f64::MAX,
+            max: f64::MIN,
+            stats: Default::default(),
+        }
+    }
+
+
+    #[inline]
+    fn update(&mut self, x: f64, y: f64, weight: f64) {
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+        self.stats[label_index(y)].update(x, weight);
+    }
+
+
+    #[inline]
+    fn forget(&mut self, decay: f64) {
+        self.stats.iter_mut()
+            .for_each(|stats| { stats.forget(decay); });
+    }
+
+
+    /// Returns the pair `(threshold, gain)`
+    /// that maximizes the information gain
+    /// among the `n_split_points` candidates.
+    fn best_split(&self, n_split_points: usize) -> Option<(f64, f64)> {
+        if self.min >= self.max { return None; }
+
+        let total = [self.stats[0].weight, self.stats[1].weight];
+        let parent = entropy(total);
+        let width = (self.max - self.min) / (n_split_points + 1) as f64;
+        (1..=n_split_points)
+            .map(|k| {
+                let threshold = self.min + width * k as f64;
+                let left = [
+                    self.stats[0].weight_below(threshold),
+                    self.stats[1].weight_below(threshold),
+                ];
+                let right = [total[0] - left[0], total[1] - left[1]];
+                (threshold, parent - split_entropy(left, right))
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+    }
+}
+
+
+/// A splitting rule of `HoeffdingStump` and the statistics of its leaves.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Split {
+    feature: usize,
+    threshold: f64,
+    // Weights of the labels `-1` and `+1` on the left/right leaves.
+    left: [f64; 2],
+    right: [f64; 2],
+}
+
+
+/// The Hoeffding tree stump,
+/// an incremental decision stump for binary classification.
+/// This struct is based on the paper:
+/// 
+/// [
+/// Mining high-speed data streams
+/// ](https://dl.acm.org/doi/10.1145/347090.347107)
+/// by Pedro Domingos and Geoff Hulten.
+/// 
+/// `HoeffdingStump` starts with a single leaf
+/// and observes the weighted mean and variance of each feature
+/// for each label.
+/// Every time the observed weight grows by the grace period,
+/// `HoeffdingStump` estimates the information gain of the splits
+/// and splits the leaf
+/// if the Hoeffding bound guarantees that the best split is better than
+/// the splits on the other features.
+/// After the split, each leaf accumulates the weights of the labels.
+/// 
+/// `HoeffdingStump` keeps observing the features after the split.
+/// Every time the observed weight grows by the grace period,
+/// `HoeffdingStump` re-checks the current split
+/// and replaces it by the best split
+/// if the Hoeffding bound guarantees that the best one is better.
+/// 
+/// # Drift
+/// By default, `HoeffdingStump` weighs all the past examples equally.
+/// For a drifting stream, [`HoeffdingStump::decay`]
+/// sets a forgetting factor on the statistics,
+/// so that the split and the leaves follow the recent examples.
+/// 
+/// The confidence of `HoeffdingStump` on `x` is
+/// `(w+ - w-) / (w+ + w-)`, where `w+` and `w-` are
+/// the weights of the positive and negative examples on the leaf of `x`.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let mut stump = HoeffdingStump::init()
+///     .delta(1e-7)
+///     .grace_period(200.0)
+///     .decay(0.999);
+/// stump.update(&[0.1, 1.2], 1.0, 1.0);
+/// stump.update(&[2.3, -0.4], -1.0, 1.0);
+/// 
+/// let y = stump.predict_on(&[0.0, 1.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoeffdingStump {
+    // Confidence parameter of the Hoeffding bound.
+    delta: f64,
+
+    // Split anyway if the Hoeffding bound is smaller than this value.
+    tie_threshold: f64,
+
+    // The stump attempts to split
+    // every time the observed weight grows by this value.
+    grace_period: f64,
+
+    // The number of candidate thresholds for each feature.
+    n_split_points: usize,
+
+    // Forgetting factor for the statistics.
+    decay: f64,
+
+    // Weights of the labels `-1` and `+1` on the root.
+    root: [f64; 2],
+
+    // Observed weight since the last split attempt.
+    weight_since_check: f64,
+
+    observers: Vec<FeatureObserver>,
+
+    split: Option<Split>,
+}
+
+
+impl HoeffdingStump {
+    /// Initializes the HoeffdingStump instance.
+    /// By default, `delta = 1e-7`, `tie_threshold = 0.05`,
+    /// `grace_period = 200.0`, `n_split_points = 10`, and `decay = 1.0`.
+    pub fn init() -> Self {
+        Self {
+            delta: 1e-7,
+            tie_threshold: 0.05,
+            grace_period: 200.0,
+            n_split_points: 10,
+            decay: 1.0,
+
+            root: [0.0; 2],
+            weight_since_check: 0.0,
+            observers: Vec::new(),
+            split: None,
+        }
+    }
+
+
+    /// Set the confidence parameter `delta` of the Hoeffding bound.
+    /// Default value is `1e-7`.
+    pub fn delta(mut self, delta: f64) -> Self {
+        assert!(
+            0.0 < delta && delta < 1.0,
+            "The confidence parameter must be in `(0, 1)`."
+        );
+        self.delta = delta;
+        self
+    }
+
+
+    /// Set the tie threshold.
+    /// `HoeffdingStump` splits the leaf
+    /// if the Hoeffding bound becomes smaller than this value.
+    /// Default value is `0.05`.
+    pub fn tie_threshold(mut self, tie_threshold: f64) -> Self {
+        self.tie_threshold = tie_threshold;
+        self
+    }
+
+
+    /// Set the grace period.
+    /// Default value is `200.0`.
+    pub fn grace_period(mut self, grace_period: f64) -> Self {
+        self.grace_period = grace_period;
+        self
+    }
+
+
+    /// Set the number of candidate thresholds for each feature.
+    /// Default value is `10`.
+    pub fn n_split_points(mut self, n_split_points: usize) -> Self {
+        assert!(n_split_points > 0);
+        self.n_split_points = n_split_points;
+        self
+    }
+
+
+    /// Set the forgetting factor in `(0, 1]`.
+    /// Before each update, `HoeffdingStump` multiplies
+    /// the weights of the past examples by `decay`.
+    /// Default value is `1.0`, *i.e.,* no forgetting.
+    pub fn decay(mut self, decay: f64) -> Self {
+        assert!(
+            0.0 < decay && decay <= 1.0,
+            "The forgetting factor must be in `(0, 1]`."
+        );
+        self.decay = decay;
+        self
+    }
+
+
+    /// Returns `true` if `self` has split the root.
+    pub fn is_split(&self) -> bool {
+        self.split.is_some()
+    }
+
+
+    /// Splits the root if the Hoeffding bound allows.
+    /// If `self` has split the root already,
+    /// this method replaces the current split by the best one
+    /// if the Hoeffding bound guarantees that the best one is better.
+    fn attempt_to_split(&mut self) {
+        let n = self.root[0] + self.root[1];
+
+        // Find the best and the second best splits
+        // on the different features.
+        // The second best is `0.0`, the gain of no split, by default.
+        let mut best: Option<(usize, f64, f64)> = None;
+        let mut second = 0.0;
+        for (j, observer) in self.observers.iter().enumerate() {
+            let Some((threshold, gain)) = observer.best_split(
+                self.n_split_points
+            ) else { continue; };
+
+            match best {
+                Some((_, _, best_gain)) if gain <= best_gain => {
+                    second = f64::max(second, gain);
+                },
+                _ => {
+                    if let Some((_, _, best_gain)) = best {
+                        second = f64::max(second, best_gain);
+                    }
+                    best = Some((j, threshold, gain));
+                },
+            }
+        }
+        let Some((feature, threshold, gain)) = best else { return; };
+        if gain <= 0.0 { return; }
+
+
+        // Since the labels are binary, the range of the gain is `1.0`.
+        let bound = ((1.0 / self.delta).ln() / (2.0 * n)).sqrt();
+        let accept = match self.split.as_ref() {
+            None => gain - second > bound || bound < self.tie_threshold,
+            Some(split) => {
+                let current = entropy(self.root)
+                    - split_entropy(split.left, split.right);
+                gain - current > bound
+            },
+        };
+        if accept {
+            let stats = &self.observers[feature].stats;
+            let left = [
+                stats[0].weight_below(threshold),
+                stats[1].weight_below(threshold),
+            ];
+            let right = [self.root[0] - left[0], self.root[1] - left[1]];
+            self.split = Some(Split { feature, threshold, left, right });
+        }
+    }
+}
+
+
+impl OnlineWeakLearner for HoeffdingStump {
+    fn name(&self) -> &str {
+        "Hoeffding Tree Stump"
+    }
+
+
+    fn update(&mut self, x: &[f64], y: f64, weight: f64) {
+        if self.decay < 1.0 {
+            let decay = self.decay;
+            self.root.iter_mut()
+                .for_each(|w| { *w *= decay; });
+            self.observers.iter_mut()
+                .for_each(|observer| { observer.forget(decay); });
+            if let Some(split) = self.split.as_mut() {
+                split.left.iter_mut()
+                    .chain(split.right.iter_mut())
+                    .for_each(|w| { *w *= decay; });
+            }
+        }
+
+        if weight <= 0.0 { return; }
+
+        let k = label_index(y);
+        if let Some(split) = self.split.as_mut() {
+            if x[split.feature] < split.threshold {
+                split.left[k] += weight;
+            } else {
+                split.right[k] += weight;
+            }
+        }
+
+
+        if self.observers.is_empty() {
+            self.observers = vec![FeatureObserver::new(); x.len()];
+        }
+        self.root[k] += weight;
+        self.observers.iter_mut()
+            .zip(x)
+            .for_each(|(observer, &xj)| { observer.update(xj, y, weight); });
+
+
+        self.weight_since_check += weight;
+        if self.weight_since_check >= self.grace_period {
+            self.weight_since_check = 0.0;
+            self.attempt_to_split();
+        }
+    }
+
+
+    fn confidence_on(&self, x: &[f64]) -> f64 {
+        let weights = match self.split.as_ref() {
+            Some(split) if x[split.feature] < split.threshold => split.left,
+            Some(split) => split.right,
+            None => self.root,
+        };
+        let total = weights[0] + weights[1];
+        if total <= 0.0 { return 0.0; }
+
+        (weights[1] - weights[0]) / total
+    }
+}
+
+
+impl Classifier for HoeffdingStump {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let (x, _) = sample.at(row);
+        self.confidence_on(&x[..])
+    }
+}
+
+
+/// Returns the index for the label `y`,
+/// `0` for the negative label and `1` for the positive one.
+#[inline(always)]
+fn label_index(y: f64) -> usize {
+    if y > 0.0 { 1 } else { 0 }
+}
+
+
+/// Returns the binary entropy (in bits) of the given weights.
+#[inline(always)]
+fn entropy(weights: [f64; 2]) -> f64 {
+    let total = weights[0] + weights[1];
+    if total <= 0.0 { return 0.0; }
+
+    weights.iter()
+        .map(|w| {
+            let p = w / total;
+            if p <= 0.0 { 0.0 } else { -p * p.log2() }
+        })
+        .sum::<f64>()
+}
+
+
+/// Returns the weighted entropy of the split.
+#[inline(always)]
+fn split_entropy(left: [f64; 2], right: [f64; 2]) -> f64 {
+    let lw = left[0] + left[1];
+    let rw = right[0] + right[1];
+    let total = lw + rw;
+    if total <= 0.0 { return 0.0; }
+
+    (lw * entropy(left) + rw * entropy(right)) / total
+}
+
+
+/// Returns the cumulative distribution function
+/// of the standard normal distribution.
+/// This function uses the approximation of `erf`
+/// by Abramowitz and Stegun (7.1.26),
+/// whose absolute error is at most `1.5e-7`.
+#[inline(always)]
+fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t * (0.254829592
+        + t * (-0.284496736
+        + t * (1.421413741
+        + t * (-1.453152027
+        + t * 1.061405429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+
+    if z >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) }
+}
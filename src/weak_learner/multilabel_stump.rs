@@ -0,0 +1,8 @@
+// Defines the multi-label decision stump weak learner.
+mod multilabel_stump_algorithm;
+// Defines the classifier produced by `MultiLabelStump`.
+mod multilabel_stump_classifier;
+
+
+pub use multilabel_stump_algorithm::MultiLabelStump;
+pub use multilabel_stump_classifier::MultiLabelStumpClassifier;
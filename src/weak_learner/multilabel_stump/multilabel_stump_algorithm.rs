@@ -0,0 +1,172 @@
+//! Defines the multi-label decision stump weak learner.
+use rayon::prelude::*;
+
+use crate::{Sample, WeakLearner};
+use crate::weak_learner::common::{
+    split_rule::Splitter,
+    type_and_struct::Threshold,
+};
+
+use super::multilabel_stump_classifier::MultiLabelStumpClassifier;
+
+
+/// A factory that produces a [`MultiLabelStumpClassifier`]
+/// for a given distribution over the pairs of example and label.
+/// 
+/// `MultiLabelStump` is the weak learner of
+/// [`AdaBoostMH`](crate::booster::AdaBoostMH).
+/// It expects that the multi-label targets are set by
+/// [`Sample::set_multi_target`].
+/// The distribution `dist` passed to `MultiLabelStump::produce`
+/// has length `m * K`, where `m` is the number of examples
+/// and `K` is the number of labels.
+/// The weight on the pair `(i, k)` is `dist[i * K + k]`.
+/// 
+/// `MultiLabelStump` chooses the split that minimizes
+/// the normalization factor
+/// `Z = sum( 2 sqrt( W+ * W- ) )`,
+/// where the sum is taken over each side of the split and each label,
+/// and `W+` (resp. `W-`) is the total weight of the pairs
+/// with label `+1` (resp. `-1`).
+/// The confidence for the label `k` on each side is
+/// `(W+ - W-) / (W+ + W-)`, which lies in `[-1.0, 1.0]`.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let sample = SampleReader::default()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .multi_target_features(vec!["label1", "label2", "label3"])
+///     .read()
+///     .unwrap();
+/// 
+/// let weak_learner = MultiLabelStump::init();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultiLabelStump {}
+
+
+impl MultiLabelStump {
+    /// Initializes the `MultiLabelStump` instance.
+    pub fn init() -> Self {
+        Self {}
+    }
+}
+
+
+impl WeakLearner for MultiLabelStump {
+    type Hypothesis = MultiLabelStumpClassifier;
+
+
+    fn name(&self) -> &str {
+        "Multi-label Decision Stump"
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_label = sample.is_valid_multilabel_instance();
+        let n_sample = sample.shape().0;
+        assert_eq!(
+            dist.len(), n_sample * n_label,
+            "The length of `dist` must be (# of examples) * (# of labels)"
+        );
+
+
+        let targets = sample.multi_target();
+        // `totals[k] = (W+, W-)` for the label `k`.
+        let totals = label_weights(targets, dist, 0..n_sample);
+
+
+        let (_, name, threshold, left) = sample.features()
+            .par_iter()
+            .map(|feature| {
+                let mut ix = (0..n_sample).collect::<Vec<_>>();
+                ix.sort_by(|&i, &j| feature[i].total_cmp(&feature[j]));
+
+                // All the examples go to the right at first.
+                let mut left = vec![(0.0, 0.0); n_label];
+                let mut best_z = z_value(&left, &totals);
+                let mut best_threshold = f64::MIN;
+                let mut best_left = left.clone();
+
+                let mut iter = ix.into_iter().peekable();
+                while let Some(i) = iter.next() {
+                    for (k, lw) in left.iter_mut().enumerate() {
+                        let d = dist[i * n_label + k];
+                        if targets[k][i] > 0.0 { lw.0 += d; } else { lw.1 += d; }
+                    }
+
+                    let Some(&j) = iter.peek() else { break; };
+                    // Skip the examples that have the same value.
+                    if feature[i] == feature[j] { continue; }
+
+                    let z = z_value(&left, &totals);
+                    if z < best_z {
+                        best_z = z;
+                        best_threshold = (feature[i] + feature[j]) / 2.0;
+                        best_left.clone_from(&left);
+                    }
+                }
+                (best_z, feature.name(), best_threshold, best_left)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .expect("The sample has no features");
+
+
+        let right = totals.iter()
+            .zip(&left)
+            .map(|(t, l)| (t.0 - l.0, t.1 - l.1))
+            .collect::<Vec<_>>();
+        let left = left.into_iter().map(confidence).collect();
+        let right = right.into_iter().map(confidence).collect();
+
+        let splitter = Splitter::new(name, Threshold::from(threshold));
+        MultiLabelStumpClassifier::new(splitter, left, right)
+    }
+}
+
+
+/// Returns the pairs `(W+, W-)` for each label
+/// over the examples `ix`.
+#[inline]
+fn label_weights<I>(targets: &[Vec<f64>], dist: &[f64], ix: I)
+    -> Vec<(f64, f64)>
+    where I: Iterator<Item = usize> + Clone,
+{
+    let n_label = targets.len();
+    targets.iter()
+        .enumerate()
+        .map(|(k, labels)| {
+            ix.clone().fold((0.0, 0.0), |(pos, neg), i| {
+                let d = dist[i * n_label + k];
+                if labels[i] > 0.0 { (pos + d, neg) } else { (pos, neg + d) }
+            })
+        })
+        .collect()
+}
+
+
+/// Computes the normalization factor `Z`
+/// for the split whose left side has weights `left`.
+#[inline]
+fn z_value(left: &[(f64, f64)], totals: &[(f64, f64)]) -> f64 {
+    left.iter()
+        .zip(totals)
+        .map(|(l, t)| {
+            let (rp, rn) = ((t.0 - l.0).max(0.0), (t.1 - l.1).max(0.0));
+            2.0 * ((l.0 * l.1).sqrt() + (rp * rn).sqrt())
+        })
+        .sum::<f64>()
+}
+
+
+/// Computes the confidence `(W+ - W-) / (W+ + W-)`.
+#[inline]
+fn confidence((pos, neg): (f64, f64)) -> f64 {
+    let total = pos + neg;
+    if total <= 0.0 { 0.0 } else { ((pos - neg) / total).clamp(-1.0, 1.0) }
+}
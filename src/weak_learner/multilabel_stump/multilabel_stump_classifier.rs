@@ -0,0 +1,56 @@
+//! Defines the multi-label decision stump classifier.
+use serde::{Serialize, Deserialize};
+
+use crate::{Sample, MultiLabelClassifier};
+use crate::weak_learner::common::split_rule::{Splitter, LR};
+
+
+/// Multi-label decision stump classifier.
+/// This struct outputs a confidence vector
+/// on each side of the split.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MultiLabelStumpClassifier {
+    splitter: Splitter,
+    // Confidences for the examples that go to the left.
+    left: Vec<f64>,
+    // Confidences for the examples that go to the right.
+    right: Vec<f64>,
+}
+
+
+impl MultiLabelStumpClassifier {
+    #[inline]
+    pub(super) fn new(
+        splitter: Splitter,
+        left: Vec<f64>,
+        right: Vec<f64>,
+    ) -> Self
+    {
+        Self { splitter, left, right, }
+    }
+
+
+    /// Returns the name of the feature used for the split.
+    #[inline]
+    pub fn feature(&self) -> &str {
+        &self.splitter.feature
+    }
+
+
+    /// Returns the threshold of the split.
+    /// An example goes to the left if its value is less than this.
+    #[inline]
+    pub fn threshold(&self) -> f64 {
+        self.splitter.threshold.0
+    }
+}
+
+
+impl MultiLabelClassifier for MultiLabelStumpClassifier {
+    fn confidences(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        match self.splitter.split(sample, row) {
+            LR::Left  => self.left.clone(),
+            LR::Right => self.right.clone(),
+        }
+    }
+}
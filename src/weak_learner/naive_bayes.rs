@@ -3,8 +3,12 @@ mod nbayes;
 /// Defines Naive Bayes Classifiers returned by `NBayes`.
 mod nbayes_classifier;
 
+/// Defines an incremental Naive Bayes for online boosting.
+mod online_nbayes;
+
 /// Defines probability density/mass functions.
 mod probability;
 
 pub use nbayes::GaussianNB;
 pub use nbayes_classifier::NBayesClassifier;
+pub use online_nbayes::OnlineGaussianNB;
@@ -3,7 +3,7 @@ use serde::{
     Deserialize,
 };
 
-use crate::{Sample, Classifier};
+use crate::{Sample, Classifier, MultiClassifier};
 
 use super::probability::Probability;
 
@@ -73,3 +73,11 @@ impl<P: Probability> Classifier for NBayesClassifier<P>
 }
 
 
+
+
+impl<P: Probability> MultiClassifier for NBayesClassifier<P>
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> usize {
+        self.confidence(sample, row) as usize
+    }
+}
@@ -0,0 +1,185 @@
+use serde::{
+    Serialize,
+    Deserialize,
+};
+
+use core::f64::consts::PI;
+
+use crate::{Sample, Classifier};
+use crate::weak_learner::core::OnlineWeakLearner;
+
+
+/// A small value added to the variances for numerical stability.
+const VAR_SMOOTHING: f64 = 1e-9;
+
+
+/// Weighted running statistics of the examples with the same label.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ClassStats {
+    label: f64,
+    weight: f64,
+    means: Vec<f64>,
+    // Weighted sum of squared deviations from the means.
+    m2s: Vec<f64>,
+}
+
+
+impl ClassStats {
+    fn new(label: f64, n_feature: usize) -> Self {
+        Self {
+            label,
+            weight: 0.0,
+            means: vec![0.0; n_feature],
+            m2s: vec![0.0; n_feature],
+        }
+    }
+
+
+    /// Adds the instance `x` with `weight` by West's algorithm.
+    fn update(&mut self, x: &[f64], weight: f64) {
+        self.weight += weight;
+        let ratio = weight / self.weight;
+        self.means.iter_mut()
+            .zip(&mut self.m2s[..])
+            .zip(x)
+            .for_each(|((mean, m2), &xj)| {
+                let delta = xj - *mean;
+                *mean += ratio * delta;
+                *m2 += weight * delta * (xj - *mean);
+            });
+    }
+
+
+    /// Multiplies the weights of the past instances by `decay`.
+    /// The means and the variances do not change.
+    fn forget(&mut self, decay: f64) {
+        self.weight *= decay;
+        self.m2s.iter_mut()
+            .for_each(|m2| { *m2 *= decay; });
+    }
+
+
+    /// Returns the logarithm of the density of `x`
+    /// conditioned by the label.
+    fn log_density(&self, x: &[f64]) -> f64 {
+        self.means.iter()
+            .zip(&self.m2s[..])
+            .zip(x)
+            .map(|((mean, m2), xj)| {
+                let var = m2 / self.weight + VAR_SMOOTHING;
+                -0.5 * ((2.0 * PI * var).ln() + (xj - mean).powi(2) / var)
+            })
+            .sum::<f64>()
+    }
+}
+
+
+/// An incremental version of [`GaussianNB`](crate::weak_learner::GaussianNB).
+/// `OnlineGaussianNB` maintains the weighted means and variances
+/// of the features for each label
+/// and updates them by each example in `O(n)` time,
+/// where `n` is the number of features.
+/// 
+/// `OnlineGaussianNB` predicts the label
+/// that maximizes the posterior probability.
+/// 
+/// # Drift
+/// By default, `OnlineGaussianNB` weighs all the past examples equally.
+/// For a drifting stream, [`OnlineGaussianNB::decay`]
+/// sets a forgetting factor on the statistics,
+/// so that the means, the variances, and the priors
+/// follow the recent examples.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let mut nb = OnlineGaussianNB::init()
+///     .decay(0.999);
+/// nb.update(&[0.1, 1.2], 1.0, 1.0);
+/// nb.update(&[2.3, -0.4], -1.0, 1.0);
+/// 
+/// let y = nb.predict_on(&[0.0, 1.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OnlineGaussianNB {
+    stats: Vec<ClassStats>,
+
+    // Forgetting factor for the statistics.
+    decay: f64,
+}
+
+
+impl OnlineGaussianNB {
+    /// Initializes the OnlineGaussianNB instance.
+    pub fn init() -> Self {
+        Self { stats: Vec::new(), decay: 1.0, }
+    }
+
+
+    /// Set the forgetting factor in `(0, 1]`.
+    /// Before each update, `OnlineGaussianNB` multiplies
+    /// the weights of the past examples by `decay`.
+    /// Default value is `1.0`, *i.e.,* no forgetting.
+    pub fn decay(mut self, decay: f64) -> Self {
+        assert!(
+            0.0 < decay && decay <= 1.0,
+            "The forgetting factor must be in `(0, 1]`."
+        );
+        self.decay = decay;
+        self
+    }
+}
+
+
+impl OnlineWeakLearner for OnlineGaussianNB {
+    fn name(&self) -> &str {
+        "Online Gaussian Naive Bayes"
+    }
+
+
+    fn update(&mut self, x: &[f64], y: f64, weight: f64) {
+        if self.decay < 1.0 {
+            let decay = self.decay;
+            self.stats.iter_mut()
+                .for_each(|stats| { stats.forget(decay); });
+        }
+
+        if weight <= 0.0 { return; }
+
+        let pos = self.stats.iter()
+            .position(|stats| stats.label == y);
+        let stats = match pos {
+            Some(pos) => &mut self.stats[pos],
+            None => {
+                self.stats.push(ClassStats::new(y, x.len()));
+                self.stats.last_mut().unwrap()
+            },
+        };
+        stats.update(x, weight);
+    }
+
+
+    fn confidence_on(&self, x: &[f64]) -> f64 {
+        let total = self.stats.iter()
+            .map(|stats| stats.weight)
+            .sum::<f64>();
+
+        self.stats.iter()
+            .map(|stats| {
+                let log_prior = (stats.weight / total).ln();
+                (stats.label, log_prior + stats.log_density(x))
+            })
+            .reduce(|a, b| if a.1 > b.1 { a } else { b })
+            .map(|(y, _)| y)
+            .unwrap_or(0.0)
+    }
+}
+
+
+impl Classifier for OnlineGaussianNB {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let (x, _) = sample.at(row);
+        self.confidence_on(&x[..])
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{Sample, RegressionTree};
+use crate::weak_learner::common::monotone::*;
 use super::bin::*;
 
 use crate::common::loss_functions::LossFunction;
@@ -12,6 +13,8 @@ pub const DEFAULT_NBIN: usize = 255;
 pub const DEFAULT_MAX_DEPTH: usize = 2;
 /// Default L2-regularization parameter
 pub const DEFAULT_LAMBDA_L2: f64 = 0.01;
+/// Default minimal gain to split a node
+pub const DEFAULT_MIN_SPLIT_GAIN: f64 = 0.0;
 
 
 /// A struct that builds `RegressionTree`.
@@ -41,6 +44,14 @@ pub struct RegressionTreeBuilder<'a, L> {
     /// L2 regularization for the leaf values.
     lambda_l2: f64,
 
+
+    /// The minimal gain to split a node.
+    min_split_gain: f64,
+
+
+    /// Monotone constraints on the features.
+    monotone: MonotoneConstraints<'a>,
+
     /// Loss function
     loss: Option<L>,
 }
@@ -53,6 +64,9 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
     /// ```text
     /// n_bins: DEFAULT_NBIN == 255,
     /// max_depth: DEFAULT_MAX_DEPTH == 2,
+    /// lambda_l2: DEFAULT_LAMBDA_L2 == 0.01,
+    /// min_split_gain: DEFAULT_MIN_SPLIT_GAIN == 0.0,
+    /// monotone constraints: None,
     /// ```
     pub fn new(sample: &'a Sample) -> Self {
         let n_bins = sample.features()
@@ -66,10 +80,20 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
         let max_depth = DEFAULT_MAX_DEPTH;
 
         let lambda_l2 = DEFAULT_LAMBDA_L2;
+        let min_split_gain = DEFAULT_MIN_SPLIT_GAIN;
 
         let loss = None;
-
-        Self { sample, n_bins, max_depth, loss, lambda_l2, }
+        let monotone = MonotoneConstraints::new();
+
+        Self {
+            sample,
+            n_bins,
+            max_depth,
+            loss,
+            lambda_l2,
+            min_split_gain,
+            monotone,
+        }
     }
 
 
@@ -80,13 +104,49 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
     }
 
 
-    /// Set the L2-regularization parameter.
+    /// Set the L2-regularization parameter `λ`.
+    /// Each leaf predicts `-G / (H + λ)`,
+    /// where `G` and `H` are the sums of the gradients and hessians
+    /// on the leaf.
+    /// Default value is `0.01`.
     pub fn lambda_l2(mut self, lambda_l2: f64) -> Self {
+        assert!(lambda_l2 >= 0.0, "`lambda_l2` must be non-negative");
         self.lambda_l2 = lambda_l2;
         self
     }
 
 
+    /// Set the minimal gain to split a node,
+    /// which is `gamma` in XGBoost.
+    /// A node becomes a leaf if no split has the gain
+    /// greater than `min_split_gain`.
+    /// Default value is `0.0`.
+    pub fn min_split_gain(mut self, min_split_gain: f64) -> Self {
+        assert!(
+            min_split_gain >= 0.0,
+            "`min_split_gain` must be non-negative"
+        );
+        self.min_split_gain = min_split_gain;
+        self
+    }
+
+
+    /// Set the monotone constraint on the feature named `name`.
+    /// `sign = 1` (resp. `-1`) makes the output of the tree
+    /// non-decreasing (resp. non-increasing) in the feature,
+    /// and `sign = 0` removes the constraint.
+    /// By default, no feature is constrained.
+    /// See [`RegressionTree`] for details.
+    pub fn monotone_constraint<T>(mut self, name: T, sign: i32) -> Self
+        where T: AsRef<str>
+    {
+        set_monotone_constraint(
+            &mut self.monotone, &self.n_bins, name.as_ref(), sign
+        );
+        self
+    }
+
+
     /// Specify the maximal depth of the tree.
     /// Default maximal depth is `2`.
     pub fn max_depth(mut self, depth: usize) -> Self {
@@ -131,10 +191,9 @@ impl<'a, L> RegressionTreeBuilder<'a, L>
         let loss = self.loss
             .expect("failed to get loss function. you need to specify a function that implements `LossFunction` trait");
 
-        let n_sample = self.sample.shape().0;
         let regression_tree = RegressionTree::from_components(
-            bins, n_sample, self.max_depth, self.lambda_l2, loss,
-        );
+            bins, self.max_depth, self.lambda_l2, self.min_split_gain, loss,
+        ).with_monotone_constraints(self.monotone);
 
 
         regression_tree
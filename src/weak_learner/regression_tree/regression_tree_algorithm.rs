@@ -7,6 +7,7 @@ use crate::common::loss_functions::LossFunction;
 use crate::weak_learner::common::{
     split_rule::*,
     type_and_struct::*,
+    monotone::*,
 };
 
 use rayon::prelude::*;
@@ -67,17 +68,35 @@ type Hessian  = f64;
 ///     / n_sample as f64;
 /// println!("loss (train) is: {loss}");
 /// ```
+/// 
+/// # Monotone constraints
+/// [`RegressionTreeBuilder::monotone_constraint`](crate::weak_learner::RegressionTreeBuilder::monotone_constraint)
+/// makes the output of the tree
+/// non-decreasing (`+1`) or non-increasing (`-1`) in a feature.
+/// As in XGBoost, a split on a constrained feature is rejected
+/// if the leaf values of its children violate the constraint,
+/// and the leaf values of the sub-trees are bounded
+/// by the midpoint of the children,
+/// so that the whole tree is monotone.
+/// Since a non-negative combination of monotone trees is monotone,
+/// so is the output of a boosting algorithm
+/// with non-negative weights on the trees,
+/// e.g., [`GBM`](crate::booster::GBM) with
+/// [`GBM::newton`](crate::booster::GBM::newton).
 pub struct RegressionTree<'a, L> {
     bins: HashMap<&'a str, Bins>,
     // The maximal depth of the output trees
     max_depth: usize,
 
-    // The number of training instances
-    n_sample: usize,
-
     // Regularization parameter
     lambda_l2: f64,
 
+    // The minimal gain to split a node
+    min_split_gain: f64,
+
+    // Monotone constraints on the features
+    monotone: MonotoneConstraints<'a>,
+
     // Loss function
     loss_func: L,
 }
@@ -87,13 +106,27 @@ impl<'a, L> RegressionTree<'a, L> {
     #[inline]
     pub(super) fn from_components(
         bins: HashMap<&'a str, Bins>,
-        n_sample: usize,
         max_depth: usize,
         lambda_l2: f64,
+        min_split_gain: f64,
         loss_func: L,
     ) -> Self
     {
-        Self { bins, n_sample, max_depth, lambda_l2, loss_func, }
+        let monotone = MonotoneConstraints::new();
+        Self { bins, max_depth, lambda_l2, min_split_gain, monotone, loss_func, }
+    }
+
+
+    /// Set the monotone constraints on the features.
+    /// This method is called only via `RegressionTreeBuilder::build`.
+    #[inline]
+    pub(super) fn with_monotone_constraints(
+        mut self,
+        monotone: MonotoneConstraints<'a>,
+    ) -> Self
+    {
+        self.monotone = monotone;
+        self
     }
 
 
@@ -105,26 +138,38 @@ impl<'a, L> RegressionTree<'a, L> {
         hessian: &[Hessian],
         indices: Vec<usize>,
         max_depth: usize,
+        bounds: Bounds,
     ) -> Rc<RefCell<TrainNode>>
     {
         // Compute the best prediction that minimizes the training error
         // on this node.
         let (pred, loss) = prediction_and_loss(
-            &indices[..], gradient, hessian, self.lambda_l2,
+            &indices[..], gradient, hessian, self.lambda_l2, bounds,
         );
 
 
-        // If sum of `dist` over `train` is zero, construct a leaf node.
-        if loss == 0.0 || max_depth <= 1 {
+        // If all the gradients on this node are zero,
+        // no split decreases the loss, so construct a leaf node.
+        // Note that `loss == 0.0` does not imply this condition
+        // since the gradients may cancel out each other.
+        let no_gradient = indices.iter().all(|&i| gradient[i] == 0.0);
+        if no_gradient || max_depth <= 1 {
             return TrainNode::leaf(pred, loss);
         }
 
 
         // Find the best splitting rule.
-        let (feature, threshold) = best_split(
-            &self.bins, sample, gradient, hessian, &indices[..], self.lambda_l2,
+        let (gain, feature, threshold) = self.best_split(
+            sample, gradient, hessian, &indices[..], bounds,
         );
 
+
+        // If the best split does not decrease the regularized loss enough,
+        // construct a leaf node.
+        if gain <= self.min_split_gain {
+            return TrainNode::leaf(pred, loss);
+        }
+
         let rule = Splitter::new(feature, threshold);
 
 
@@ -144,15 +189,67 @@ impl<'a, L> RegressionTree<'a, L> {
             return TrainNode::leaf(pred, loss);
         }
 
+        // Bound the leaf values of the children
+        // if the feature is constrained.
+        let (lbounds, rbounds) = match self.monotone.get(feature) {
+            Some(&sign) => {
+                let (lpred, _) = prediction_and_loss(
+                    &lindices[..], gradient, hessian, self.lambda_l2, bounds,
+                );
+                let (rpred, _) = prediction_and_loss(
+                    &rindices[..], gradient, hessian, self.lambda_l2, bounds,
+                );
+                bounds.split(sign, lpred.0, rpred.0)
+            },
+            None => (bounds, bounds),
+        };
+
         // -----
         // At this point, `max_depth > 1` is guaranteed
         // so that one can grow the tree.
-        let ltree = self.full_tree(sample, gradient, hessian, lindices, max_depth-1);
-        let rtree = self.full_tree(sample, gradient, hessian, rindices, max_depth-1);
+        let ltree = self.full_tree(
+            sample, gradient, hessian, lindices, max_depth-1, lbounds,
+        );
+        let rtree = self.full_tree(
+            sample, gradient, hessian, rindices, max_depth-1, rbounds,
+        );
 
 
         TrainNode::branch(rule, ltree, rtree, pred, loss)
     }
+
+    /// Returns the best splitting rule based on the loss function
+    /// and its gain.
+    /// The splits that violate the monotone constraints are rejected.
+    fn best_split<'b>(
+        &self,
+        sample: &'b Sample,
+        gradient: &[Gradient],
+        hessian: &[Hessian],
+        idx: &[usize],
+        bounds: Bounds,
+    ) -> (f64, &'b str, Threshold)
+    {
+        // `L` may not be `Sync`, so borrow the fields outside the closure.
+        let bins = &self.bins;
+        let monotone = &self.monotone;
+        let lambda_l2 = self.lambda_l2;
+        sample.features()
+            .par_iter()
+            .map(|feature| {
+                let name = feature.name();
+                let bin = bins.get(name).unwrap();
+                let pack = bin.pack(idx, feature, gradient, hessian);
+                let sign = monotone.get(name).copied().unwrap_or(0);
+                let (gain, threshold) = best_split_at(
+                    pack, lambda_l2, sign, bounds
+                );
+
+                (gain, name, threshold)
+            })
+            .max_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
+            .expect("No feature that maximizes the score.")
+    }
 }
 
 
@@ -177,6 +274,8 @@ impl<L> WeakLearner for RegressionTree<'_, L>
             ("Max depth", format!("{}", self.max_depth)),
             ("Split criterion", self.loss_func.name().to_string()),
             ("Regularization param.", format!("{}", self.lambda_l2)),
+            ("Min. split gain", format!("{}", self.min_split_gain)),
+            ("# of monotone constraints", format!("{}", self.monotone.len())),
         ]);
         Some(info)
     }
@@ -184,11 +283,25 @@ impl<L> WeakLearner for RegressionTree<'_, L>
     fn produce(&self, sample: &Sample, predictions: &[f64])
         -> Self::Hypothesis
     {
-        let gradient = self.loss_func.gradient(predictions, sample.target());
-        let hessian = self.loss_func.hessian(predictions, sample.target());
+        let target = sample.target();
+        let group = sample.group();
+        let (mut gradient, mut hessian) = self.loss_func
+            .gradient_and_hessian_grouped(predictions, target, group);
+
+
+        // Scale the gradients and hessians by the weights on examples,
+        // which are specified by the booster, e.g., GOSS in `GBM`.
+        let weight = sample.weight();
+        if !weight.is_empty() {
+            gradient.iter_mut()
+                .zip(hessian.iter_mut())
+                .zip(weight)
+                .for_each(|((g, h), w)| { *g *= w; *h *= w; });
+        }
 
 
-        let indices = (0..self.n_sample).collect::<Vec<_>>();
+        let n_sample = sample.shape().0;
+        let indices = (0..n_sample).collect::<Vec<_>>();
 
 
         let tree = self.full_tree(
@@ -197,6 +310,7 @@ impl<L> WeakLearner for RegressionTree<'_, L>
             &hessian[..],
             indices,
             self.max_depth,
+            Bounds::unbounded(),
         );
 
         let root = Node::from(
@@ -253,39 +367,26 @@ impl<L> fmt::Display for RegressionTree<'_, L>
 }
 
 
-/// Returns the best splitting rule based on the loss function.
-fn best_split<'a>(
-    bins_map: &HashMap<&'_ str, Bins>,
-    sample: &'a Sample,
-    gradient: &[Gradient],
-    hessian: &[Hessian],
-    idx: &[usize],
-    lambda_l2: f64,
-) -> (&'a str, Threshold)
-{
-
-    sample.features()
-        .par_iter()
-        .map(|feature| {
-            let name = feature.name();
-            let bin = bins_map.get(name).unwrap();
-            let pack = bin.pack(idx, feature, gradient, hessian);
-            let (score, threshold) = best_split_at(pack, lambda_l2);
-
-            (score, name, threshold)
-        })
-        .max_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
-        .map(|(_, name, threshold)| (name, threshold))
-        .expect("No feature that maximizes the score.")
-}
-
 /// this code is implemented based on Algorithm 3 of the following paper:
 /// Tianqi Chen and Carlos Guestrin.
 /// XGBoost: A scalable tree boosting system [KDD '16]
+/// 
+/// The gain of a split is Eq. (7) of the paper without `gamma`:
+/// ```txt
+/// 0.5 * [ G_L^2 / (H_L + λ) + G_R^2 / (H_R + λ) - G^2 / (H + λ) ]
+/// ```
+/// If the leaf values are clamped by `bounds`,
+/// each term is replaced by the one for the clamped value
+/// (see `leaf_score`).
+/// If `sign` is not zero,
+/// the splits whose leaf values violate the monotone constraint
+/// are rejected.
 fn best_split_at(
     pack: Vec<(Bin, Gradient, Hessian)>,
     lambda_l2: f64,
-) -> (LossValue, Threshold)
+    sign: i32,
+    bounds: Bounds,
+) -> (f64, Threshold)
 {
     let mut right_grad_sum = pack.par_iter()
         .map(|(_, grad, _)| grad)
@@ -295,6 +396,11 @@ fn best_split_at(
         .sum::<f64>();
 
 
+    let parent_score = leaf_score(
+        right_grad_sum, right_hess_sum, lambda_l2, bounds
+    );
+
+
     let mut left_grad_sum = 0.0;
     let mut left_hess_sum = 0.0;
 
@@ -310,27 +416,59 @@ fn best_split_at(
         right_hess_sum -= hess;
 
 
+        if sign != 0 {
+            let lpred = bounds.clamp(
+                - left_grad_sum / (left_hess_sum + lambda_l2)
+            );
+            let rpred = bounds.clamp(
+                - right_grad_sum / (right_hess_sum + lambda_l2)
+            );
+            if !is_monotone(sign, lpred, rpred) { continue; }
+        }
+
         let score = 
-            left_grad_sum.powi(2) / (left_hess_sum + lambda_l2)
-            + right_grad_sum.powi(2) / (right_hess_sum + lambda_l2);
+            leaf_score(left_grad_sum, left_hess_sum, lambda_l2, bounds)
+            + leaf_score(right_grad_sum, right_hess_sum, lambda_l2, bounds);
         if best_score < score {
             best_score = score;
             best_threshold = bin.0.end;
         }
     }
 
-    (best_score.into(), best_threshold.into())
+    let gain = 0.5 * (best_score - parent_score);
+    (gain, best_threshold.into())
+}
+
+/// Returns `-2` times the regularized loss `G w + 0.5 (H + λ) w^2`
+/// of the leaf value `w = -G / (H + λ)` clamped by `bounds`.
+/// If `w` is not clamped, this value is `G^2 / (H + λ)`.
+#[inline(always)]
+fn leaf_score(grad_sum: f64, hess_sum: f64, lambda_l2: f64, bounds: Bounds)
+    -> f64
+{
+    let pred = - grad_sum / (hess_sum + lambda_l2);
+    let clamped = bounds.clamp(pred);
+    if clamped == pred {
+        grad_sum.powi(2) / (hess_sum + lambda_l2)
+    } else {
+        - (2.0 * grad_sum * clamped + (hess_sum + lambda_l2) * clamped.powi(2))
+    }
 }
 
+
 /// returns the prediction value and the loss value of a leaf.
 /// this function is implemented based on Eqs. (5), (6) of the following paper:
 /// Tianqi Chen and Carlos Guestrin.
 /// XGBoost: A scalable tree boosting system [KDD '16]
+/// 
+/// The prediction value is clamped by `bounds`
+/// to respect the monotone constraints.
 fn prediction_and_loss(
     indices: &[usize],
     gradient: &[Gradient],
     hessian: &[Hessian],
     lambda_l2: f64,
+    bounds: Bounds,
 ) -> (Prediction<f64>, LossValue)
 {
     let grad_sum = indices.par_iter()
@@ -341,8 +479,8 @@ fn prediction_and_loss(
         .map(|&i| hessian[i])
         .sum::<f64>();
 
-    let prediction = - grad_sum / (hess_sum + lambda_l2);
-    let loss_value = -0.5 * grad_sum.powi(2) / (hess_sum + lambda_l2);
+    let prediction = bounds.clamp(- grad_sum / (hess_sum + lambda_l2));
+    let loss_value = -0.5 * leaf_score(grad_sum, hess_sum, lambda_l2, bounds);
 
     (prediction.into(), loss_value.into())
 }
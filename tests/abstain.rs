@@ -0,0 +1,117 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for the abstaining hypotheses.
+#[cfg(test)]
+pub mod abstain_tests {
+    use super::*;
+
+    // A hypothesis that predicts `label` on `x < threshold`
+    // (or `x >= threshold` if `left` is `false`) and abstains otherwise.
+    #[derive(Clone, Debug)]
+    struct Specialist {
+        threshold: f64,
+        left: bool,
+        label: f64,
+    }
+
+
+    impl Classifier for Specialist {
+        fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+            let x = sample["x"][row];
+            if (x < self.threshold) == self.left { self.label } else { 0.0 }
+        }
+    }
+
+
+    // A weak learner that minimizes `W0 + 2 sqrt(W+ W-)`
+    // over the specialists.
+    struct SpecialistLearner {
+        thresholds: Vec<f64>,
+    }
+
+
+    impl WeakLearner for SpecialistLearner {
+        type Hypothesis = Specialist;
+
+        fn produce(&self, sample: &Sample, dist: &[f64]) -> Specialist {
+            let mut best = (f64::MAX, None);
+            for &threshold in &self.thresholds {
+                for left in [true, false] {
+                    for label in [-1.0, 1.0] {
+                        let h = Specialist { threshold, left, label, };
+                        let (mut wp, mut wn, mut w0) = (0.0, 0.0, 0.0);
+                        for (i, (y, d)) in sample.target().iter().zip(dist).enumerate() {
+                            let p = y * h.confidence(sample, i);
+                            if p > 0.0 { wp += d; }
+                            else if p < 0.0 { wn += d; }
+                            else { w0 += d; }
+                        }
+                        if wp < wn { continue; }
+                        let z = w0 + 2.0 * (wp * wn).sqrt();
+                        if z < best.0 { best = (z, Some(h)); }
+                    }
+                }
+            }
+            best.1.unwrap()
+        }
+    }
+
+
+    // Toy example on the line.
+    // The labels around `x = 10` are noisy.
+    fn toy_sample() -> Sample {
+        let x = (0..20).map(|i| i as f64).collect::<Vec<_>>();
+        let target = (0..20)
+            .map(|i| match i {
+                9 | 11 => 1.0,
+                10 => -1.0,
+                _ if i < 10 => -1.0,
+                _ => 1.0,
+            })
+            .collect::<Vec<_>>();
+        Sample::from_dense_columns(vec![("x", x)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn abstaining_hypothesis() {
+        let sample = toy_sample();
+        let h = Specialist { threshold: 5.0, left: true, label: -1.0 };
+
+        assert_eq!(h.predict(&sample, 10), 1);
+        assert_eq!(h.predict_or_abstain(&sample, 10, 0.0), 0);
+        assert_eq!(h.predict_or_abstain(&sample, 0, 0.0), -1);
+    }
+
+
+    #[test]
+    fn coverage_and_accuracy() {
+        let sample = toy_sample();
+
+        let mut booster = AdaBoost::init(&sample)
+            .force_quit_at(20);
+        let wl = SpecialistLearner {
+            thresholds: vec![4.5, 8.5, 11.5, 15.5],
+        };
+
+        let f = booster.run(&wl);
+
+
+        let report = f.coverage_accuracy(&sample, &[0.0, 0.1, 0.4]);
+        println!("{report:?}");
+        for pair in report.windows(2) {
+            assert!(pair[0].coverage >= pair[1].coverage);
+        }
+        assert!(report[0].accuracy >= 0.9);
+        assert!(report[2].coverage < 1.0 && report[2].coverage >= 0.5);
+        assert_eq!(report[2].accuracy, 1.0);
+
+
+        let predictions = f.predict_or_abstain_all(&sample, 0.4);
+        let n_abstained = predictions.iter().filter(|&&p| p == 0).count();
+        let expected = (1.0 - report[2].coverage) * 20.0;
+        assert_eq!(n_abstained, expected.round() as usize);
+    }
+}
@@ -0,0 +1,107 @@
+use miniboosts::prelude::*;
+use miniboosts::CrossValidation;
+
+
+/// Tests for `AdaBoostMH`.
+#[cfg(test)]
+pub mod adaboost_mh_tests {
+    use super::*;
+
+    // Toy example with three labels on the grid `[0, 1]^2`.
+    // The labels are `x > 0.5`, `y > 0.5`, and `x > 0.3 && y > 0.3`.
+    fn toy_sample() -> Sample {
+        let grid = (0..10)
+            .flat_map(|i| (0..10).map(move |j| (i as f64 / 9.0, j as f64 / 9.0)))
+            .collect::<Vec<_>>();
+        let (x, y): (Vec<f64>, Vec<f64>) = grid.iter().copied().unzip();
+
+        let sign = |b: bool| if b { 1.0 } else { -1.0 };
+        let l1 = x.iter().map(|&xi| sign(xi > 0.5)).collect::<Vec<_>>();
+        let l2 = y.iter().map(|&yi| sign(yi > 0.5)).collect::<Vec<_>>();
+        let l3 = grid.iter()
+            .map(|&(xi, yi)| sign(xi > 0.3 && yi > 0.3))
+            .collect::<Vec<_>>();
+        let target = vec![0.0; grid.len()];
+
+        let columns = vec![
+            ("x", x), ("y", y), ("l1", l1), ("l2", l2), ("l3", l3),
+        ];
+        Sample::from_dense_columns(columns, target)
+            .unwrap()
+            .set_multi_target(&["l1", "l2", "l3"])
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+        assert_eq!(sample.shape().1, 2);
+
+        let mut booster = AdaBoostMH::init(&sample)
+            .force_quit_at(50);
+
+        let wl = MultiLabelStump::init();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let targets = sample.multi_target();
+        let k = targets.len();
+        let predictions = f.predict_labels_all(&sample);
+
+        let loss = predictions.into_iter()
+            .enumerate()
+            .map(|(i, labels)| {
+                labels.into_iter()
+                    .zip(targets)
+                    .filter(|(p, y)| *p != y[i] as i64)
+                    .count()
+            })
+            .sum::<usize>() as f64 / (m * k) as f64;
+
+        println!("Hamming Loss: {loss}");
+        assert_eq!(loss, 0.0);
+    }
+
+
+    // A CSV file with the label columns only has no single target,
+    // so that the folds must not touch the target.
+    #[test]
+    fn cross_validation() {
+        let mut csv = String::from("x,y,l1,l2\n");
+        for i in 0..20 {
+            let x = i as f64 / 19.0;
+            let l1 = if x > 0.5 { 1 } else { -1 };
+            let l2 = if i % 2 == 0 { 1 } else { -1 };
+            csv.push_str(&format!("{x},{},{l1},{l2}\n", 1.0 - x));
+        }
+        let path = std::env::temp_dir()
+            .join("miniboosts_adaboost_mh_cross_validation.csv");
+        std::fs::write(&path, csv).unwrap();
+        let sample = SampleReader::default()
+            .file(path)
+            .has_header(true)
+            .multi_target_features(vec!["l1", "l2"])
+            .read()
+            .unwrap();
+        assert!(sample.target().is_empty());
+
+        let cv = CrossValidation::new(&sample)
+            .n_folds(5)
+            .shuffle();
+        for (train, test) in cv {
+            assert_eq!(train.shape(), (16, 2));
+            assert_eq!(test.shape(), (4, 2));
+            assert!(train.target().is_empty());
+            assert_eq!(train.multi_target().len(), 2);
+            assert!(test.multi_target().iter().all(|l| l.len() == 4));
+
+            let mut booster = AdaBoostMH::init(&train)
+                .force_quit_at(10);
+            let f = booster.run(&MultiLabelStump::init());
+            assert_eq!(f.predict_labels_all(&test).len(), 4);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `AdaC2`.
+#[cfg(test)]
+pub mod adac2_tests {
+    use super::*;
+
+    // Toy example that no single stump separates.
+    fn toy_sample() -> Sample {
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let y = vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let target = vec![
+            -1.0, -1.0, -1.0, 1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0
+        ];
+        Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let mut booster = AdaC2::init(&sample)
+            .fp_cost(1.0)
+            .fn_cost(10.0)
+            .force_quit_at(5);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let predictions = f.predict_all(&sample);
+
+        let n_false_negative = sample.target()
+            .iter()
+            .zip(predictions)
+            .filter(|(&t, p)| t > 0.0 && *p < 0)
+            .count();
+
+        println!("# of false negatives: {n_false_negative}");
+        assert_eq!(n_false_negative, 0);
+    }
+}
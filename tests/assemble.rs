@@ -0,0 +1,99 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `ASSEMBLE`.
+#[cfg(test)]
+pub mod assemble_tests {
+    use super::*;
+
+    // Toy example with two clusters on the line.
+    // Only two examples in each cluster are labeled.
+    fn toy_sample() -> (Sample, Vec<f64>) {
+        let x = (0..40)
+            .map(|i| if i < 20 { i as f64 * 0.1 } else { 5.0 + i as f64 * 0.1 })
+            .collect::<Vec<_>>();
+        let y = vec![0.0; 40];
+        let truth = (0..40)
+            .map(|i| if i < 20 { -1.0 } else { 1.0 })
+            .collect::<Vec<_>>();
+        let target = truth.iter()
+            .enumerate()
+            .map(|(i, &t)| if [0, 1, 38, 39].contains(&i) { t } else { f64::NAN })
+            .collect::<Vec<_>>();
+        let sample = Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap();
+        (sample, truth)
+    }
+
+
+    #[test]
+    fn toy() {
+        let (sample, truth) = toy_sample();
+        assert_eq!(sample.is_valid_semi_supervised_instance(), 36);
+
+        let mut booster = ASSEMBLE::init(&sample)
+            .labeled_weight(0.9)
+            .force_quit_at(20);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let predictions = f.predict_all(&sample);
+
+        let loss = truth.iter()
+            .zip(predictions)
+            .map(|(t, p)| if *t as i64 != p { 1.0 } else { 0.0 })
+            .sum::<f64>() / m as f64;
+
+        println!("Loss (all examples): {loss}");
+        assert!(loss <= 0.1);
+    }
+
+
+    #[test]
+    fn missing_values_in_csv() {
+        let dir = std::env::temp_dir();
+
+        // An empty field in the target column is an unlabeled example.
+        let path = dir.join("miniboosts_assemble_missing_target.csv");
+        std::fs::write(&path, "x,y,class\n0.1,0.2,1\n0.3,0.4,\n0.5,0.6,-1\n")
+            .unwrap();
+        let sample = SampleReader::default()
+            .file(path.clone())
+            .has_header(true)
+            .target_feature("class")
+            .allow_missing_target(true)
+            .read()
+            .unwrap();
+        assert_eq!(sample.shape(), (3, 2));
+        assert!(sample.target()[1].is_nan());
+        assert_eq!(sample.is_valid_semi_supervised_instance(), 1);
+
+        // Without the opt-in, an empty target field is an error.
+        let sample = SampleReader::default()
+            .file(path.clone())
+            .has_header(true)
+            .target_feature("class")
+            .read();
+        assert!(sample.is_err());
+
+        // An empty field in a feature column is an error.
+        let path = dir.join("miniboosts_assemble_missing_feature.csv");
+        std::fs::write(&path, "x,y,class\n0.1,0.2,1\n,0.4,\n0.5,0.6,-1\n")
+            .unwrap();
+        let sample = SampleReader::default()
+            .file(path)
+            .has_header(true)
+            .target_feature("class")
+            .allow_missing_target(true)
+            .read();
+        assert!(sample.is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `BrownBoost`.
+#[cfg(test)]
+pub mod brownboost_tests {
+    use super::*;
+
+    // Toy example with a noisy label.
+    // The label of `x = 1.0` is flipped.
+    fn toy_sample() -> Sample {
+        let x = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        let y = vec![0.5, 0.0, 1.0, 0.5, 1.5, 1.0, 0.5, 0.0, 1.5, 1.0];
+        let target = vec![
+            -1.0, 1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0
+        ];
+        Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let mut booster = BrownBoost::init(&sample)
+            .time(1.0)
+            .force_quit_at(100);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let predictions = f.predict_all(&sample);
+
+        let loss = sample.target()
+            .iter()
+            .zip(predictions)
+            .map(|(t, p)| if *t != p as f64 { 1.0 } else { 0.0 })
+            .sum::<f64>() / m as f64;
+
+        println!("Training Loss: {loss}");
+        assert!(loss <= 0.1);
+    }
+}
@@ -0,0 +1,135 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `DPBoost`.
+#[cfg(test)]
+pub mod dpboost_tests {
+    use super::*;
+
+    // Toy example with an informative feature `x`
+    // and a noisy feature `z`.
+    fn toy_sample() -> Sample {
+        let n_sample = 400;
+        let x = (0..n_sample)
+            .map(|i| i as f64 / n_sample as f64)
+            .collect::<Vec<_>>();
+        let z = (0..n_sample)
+            .map(|i| ((i * 37) % 101) as f64)
+            .collect::<Vec<_>>();
+        let target = x.iter()
+            .map(|&xi| if xi < 0.3 { -1.0 } else { 1.0 })
+            .collect::<Vec<_>>();
+        Sample::from_dense_columns(vec![("x", x), ("z", z)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let n_round = 10;
+        let mut booster = DPBoost::init(&sample)
+            .smoothness(0.5)
+            .edge_epsilon(0.5)
+            .delta(1e-6)
+            .force_quit_at(n_round);
+
+        let mut builder = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .feature_range("x", 0.0, 1.0)
+            .feature_range("z", 0.0, 101.0)
+            .differential_privacy(1.0, 0.5)
+            .seed(777);
+        builder.set_nbins("x", 20);
+        builder.set_nbins("z", 20);
+        let wl = builder.build();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let predictions = f.predict_all(&sample);
+
+        let loss = sample.target()
+            .iter()
+            .zip(predictions)
+            .map(|(t, p)| if *t as i64 != p { 1.0 } else { 0.0 })
+            .sum::<f64>() / m as f64;
+
+        println!("Loss: {loss}");
+        assert!(loss <= 0.1);
+
+
+        let accountant = booster.accountant();
+        assert_eq!(accountant.rounds().len(), n_round);
+        assert!(accountant.rounds().iter().all(|&r| r == (1.5, 0.0)));
+
+        let (epsilon, delta) = accountant.spent();
+        println!("Privacy loss: ({epsilon}, {delta})");
+        let (basic, _) = accountant.basic();
+        assert!((basic - 1.5 * n_round as f64).abs() < 1e-9);
+        assert!(epsilon <= basic);
+        assert!(delta <= 1e-6);
+    }
+
+
+    #[test]
+    #[should_panic(expected = "public ranges")]
+    fn private_tree_requires_public_ranges() {
+        let sample = toy_sample();
+        let _ = DecisionTreeBuilder::new(&sample)
+            .feature_range("x", 0.0, 1.0)
+            .differential_privacy(1.0, 0.5);
+    }
+
+
+    #[test]
+    fn private_tree_reports_privacy() {
+        let sample = toy_sample();
+        let mut builder = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .feature_range("x", -10.0, 10.0)
+            .feature_range("z", -10.0, 10.0)
+            .differential_privacy(1.0, 0.5);
+        builder.set_nbins("x", 4);
+        builder.set_nbins("z", 4);
+        let wl = builder.build();
+        assert_eq!(wl.privacy(), Some((1.0, 0.5)));
+
+        // The data lie in a few of the public bins.
+        let n_sample = sample.shape().0;
+        let dist = vec![1.0 / n_sample as f64; n_sample];
+        let h = wl.produce(&sample, &dist[..]);
+        let predictions = h.predict_all(&sample);
+        assert_eq!(predictions.len(), n_sample);
+    }
+
+
+    #[test]
+    #[should_panic(expected = "smooth")]
+    fn smoothness_mismatch() {
+        let sample = toy_sample();
+        let wl = DecisionTreeBuilder::new(&sample)
+            .feature_range("x", 0.0, 1.0)
+            .feature_range("z", 0.0, 101.0)
+            .differential_privacy(1.0, 0.5)
+            .build();
+        let _ = DPBoost::init(&sample)
+            .smoothness(0.25)
+            .force_quit_at(1)
+            .run(&wl);
+    }
+
+
+    #[test]
+    #[should_panic(expected = "differentially private")]
+    fn non_private_weak_learner() {
+        let sample = toy_sample();
+        let wl = DecisionTreeBuilder::new(&sample).build();
+        let _ = DPBoost::init(&sample)
+            .force_quit_at(1)
+            .run(&wl);
+    }
+}
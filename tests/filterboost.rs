@@ -0,0 +1,107 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `FilterBoost`.
+#[cfg(test)]
+pub mod filterboost_tests {
+    use super::*;
+
+    // Toy example on the plane.
+    // The label is positive iff `x > 0.3` and `y > 0.3`.
+    fn toy_sample() -> Sample {
+        let n_sample = 500;
+        let x = (0..n_sample)
+            .map(|t| ((t * 37) % 101) as f64 / 100.0)
+            .collect::<Vec<_>>();
+        let y = (0..n_sample)
+            .map(|t| ((t * 53) % 97) as f64 / 96.0)
+            .collect::<Vec<_>>();
+        let target = x.iter()
+            .zip(&y[..])
+            .map(|(&xi, &yi)| if xi > 0.3 && yi > 0.3 { 1.0 } else { -1.0 })
+            .collect::<Vec<_>>();
+        Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let oracle = SampleOracle::new(&sample)
+            .batch_size(200)
+            .seed(777);
+        let mut booster = FilterBoost::init(oracle)
+            .n_train(200)
+            .tolerance(0.05)
+            .edge_sample_limit(2_000)
+            .force_quit_at(20);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let predictions = f.predict_all(&sample);
+
+        let loss = sample.target()
+            .iter()
+            .zip(predictions)
+            .map(|(t, p)| if *t != p as f64 { 1.0 } else { 0.0 })
+            .sum::<f64>() / m as f64;
+
+        println!("Training Loss: {loss}");
+        assert!(loss <= 0.05);
+    }
+
+
+    // An oracle that returns `n_batches` batches and then empty ones.
+    struct FiniteOracle<'a> {
+        oracle: SampleOracle<'a>,
+        n_batches: usize,
+    }
+
+
+    impl ExampleOracle for FiniteOracle<'_> {
+        fn draw(&mut self) -> Sample {
+            if self.n_batches == 0 {
+                let columns = vec![("x", Vec::new()), ("y", Vec::new())];
+                return Sample::from_dense_columns(columns, Vec::new())
+                    .unwrap();
+            }
+            self.n_batches -= 1;
+            self.oracle.draw()
+        }
+    }
+
+
+    #[test]
+    fn exhausted_oracle() {
+        let sample = toy_sample();
+
+        let oracle = FiniteOracle {
+            oracle: SampleOracle::new(&sample).batch_size(200).seed(777),
+            n_batches: 3,
+        };
+        let mut booster = FilterBoost::init(oracle)
+            .n_train(200)
+            .force_quit_at(20);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+        // `FilterBoost` terminates without panic
+        // once the oracle runs out of examples.
+        let f = booster.run(&wl);
+        let predictions = f.predict_all(&sample);
+        assert_eq!(predictions.len(), sample.shape().0);
+    }
+}
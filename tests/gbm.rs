@@ -62,3 +62,870 @@ pub mod gbm_boston {
         assert!(true);
     }
 }
+
+
+/// Tests for stochastic gradient boosting.
+#[cfg(test)]
+pub mod gbm_stochastic {
+    use super::*;
+
+    // The relevance depends only on `x0`,
+    // and `x1`, `x2` are irrelevant features.
+    fn toy_sample() -> Sample {
+        let mut x0 = Vec::new();
+        let mut x1 = Vec::new();
+        let mut x2 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                x0.push(i as f64);
+                x1.push(j as f64);
+                x2.push(((i * 10 + j) * 7 % 11) as f64);
+                target.push((i / 3) as f64);
+            }
+        }
+        let columns = vec![("x0", x0), ("x1", x1), ("x2", x2)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    #[test]
+    fn sampling() {
+        // Each value of `x1` forms a query.
+        let sample = toy_sample().set_group("x1");
+        let loss = LambdaRank::new();
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(loss)
+            .build();
+
+        let boosters = [
+            GBM::init_with_loss(&sample, loss)
+                .subsample(0.5),
+            GBM::init_with_loss(&sample, loss)
+                .colsample(0.5),
+            GBM::init_with_loss(&sample, loss)
+                .goss(0.2, 0.3),
+        ];
+        for mut gbm in boosters {
+            let f = gbm.run(&tree);
+            let scores = f.predict_all(&sample);
+            let value = loss.eval_grouped(
+                &scores, sample.target(), sample.group()
+            );
+            assert!(value < 1e-6, "1 - NDCG = {value}");
+        }
+    }
+
+
+    #[test]
+    fn seed() {
+        let sample = toy_sample();
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(GBMLoss::L2)
+            .build();
+
+        let run = |seed| {
+            let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+                .subsample(0.5)
+                .colsample(0.7)
+                .seed(seed);
+            gbm.run(&tree).predict_all(&sample)
+        };
+        let p = run(7);
+        let q = run(7);
+        let r = run(8);
+        assert_eq!(p, q);
+        assert_ne!(p, r);
+    }
+}
+
+
+/// Tests for the second-order boosting.
+#[cfg(test)]
+pub mod gbm_newton {
+    use super::*;
+
+    // `y = 2 x0 - x1 + 3` on a grid.
+    fn toy_sample() -> Sample {
+        let mut x0 = Vec::new();
+        let mut x1 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                x0.push(i as f64);
+                x1.push(j as f64);
+                target.push(2.0 * i as f64 - j as f64 + 3.0);
+            }
+        }
+        let columns = vec![("x0", x0), ("x1", x1)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    #[test]
+    fn regularized_leaf() {
+        let sample = toy_sample();
+        let n_sample = sample.shape().0;
+        let mean = sample.target().iter().sum::<f64>() / n_sample as f64;
+        let zeros = vec![0.0; n_sample];
+
+        // A tree of depth 1 is a single leaf `-G / (H + λ)`.
+        // For the L2-loss, `H` is the number of examples.
+        for lambda in [0.0, n_sample as f64] {
+            let tree = RegressionTreeBuilder::new(&sample)
+                .max_depth(1)
+                .lambda_l2(lambda)
+                .loss(GBMLoss::L2)
+                .build();
+            let p = tree.produce(&sample, &zeros).predict(&sample, 0);
+            let expected = mean * n_sample as f64 / (n_sample as f64 + lambda);
+            assert!((p - expected).abs() < 1e-9, "{p} vs {expected}");
+        }
+
+        // No split has the gain larger than `min_split_gain`.
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(4)
+            .lambda_l2(0.0)
+            .min_split_gain(1e12)
+            .loss(GBMLoss::L2)
+            .build();
+        let predictions = tree.produce(&sample, &zeros).predict_all(&sample);
+        assert!(predictions.iter().all(|p| (p - mean).abs() < 1e-9));
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+        let n_sample = sample.shape().0 as f64;
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .lambda_l2(1.0)
+            .loss(GBMLoss::L2)
+            .build();
+
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .newton(true);
+        let f = gbm.run(&tree);
+        let predictions = f.predict_all(&sample);
+
+        let loss = sample.target()
+            .iter()
+            .zip(&predictions[..])
+            .map(|(y, p)| (y - p).powi(2))
+            .sum::<f64>()
+            / n_sample;
+        assert!(loss < 0.1, "L2-loss = {loss}");
+    }
+
+
+    #[test]
+    fn fallback_without_hessian() {
+        assert!(GBMLoss::L2.has_hessian());
+        assert!(!GBMLoss::L1.has_hessian());
+
+        // The L1-loss has zero Hessian,
+        // so that `GBM` uses the line search even in the Newton mode.
+        let sample = toy_sample();
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .lambda_l2(1.0)
+            .loss(GBMLoss::L1)
+            .build();
+
+        let mut newton = GBM::init_with_loss(&sample, GBMLoss::L1)
+            .newton(true);
+        let mut line_search = GBM::init_with_loss(&sample, GBMLoss::L1);
+        let f = newton.run(&tree).predict_all(&sample);
+        let g = line_search.run(&tree).predict_all(&sample);
+        assert_eq!(f, g);
+
+        let target = sample.target();
+        let zeros = vec![0.0; f.len()];
+        assert!(GBMLoss::L1.eval(&f, target) < GBMLoss::L1.eval(&zeros, target));
+    }
+}
+
+
+/// Tests for the binary classification by `GBM`.
+#[cfg(test)]
+pub mod gbm_logistic {
+    use super::*;
+
+    // The label is `+1` iff `x0 + x1 > 9`,
+    // except for a few flipped examples.
+    fn toy_sample() -> Sample {
+        let mut x0 = Vec::new();
+        let mut x1 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                x0.push(i as f64);
+                x1.push(j as f64);
+                let y = if i + j > 9 { 1.0 } else { -1.0 };
+                let flip = (i * 10 + j) % 17 == 0;
+                target.push(if flip { -y } else { y });
+            }
+        }
+        let columns = vec![("x0", x0), ("x1", x1)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    #[test]
+    fn line_search() {
+        // Three of the four examples are positive,
+        // so that the best constant log-odds is `ln(3)`.
+        let target = [1.0, 1.0, 1.0, -1.0];
+        let current = [0.0; 4];
+        let direction = [1.0, 1.0, 1.0, 1.0];
+        let coef = GBMLoss::Logistic.line_search(
+            &current, &direction, &target
+        );
+        let expected = 3f64.ln();
+        assert!((coef - expected).abs() < 1e-6, "{coef} vs {expected}");
+
+        // The L2 line search fits the residuals.
+        let target = [1.0, 2.0, 3.0];
+        let current = [0.5, 1.0, 1.5];
+        let direction = [1.0, 2.0, 3.0];
+        let coef = GBMLoss::L2.line_search(&current, &direction, &target);
+        assert!((coef - 0.5).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+        let n_sample = sample.shape().0 as f64;
+
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::Logistic);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::Logistic)
+            .build();
+        let f = LogisticClassifier::new(gbm.run(&tree));
+
+        let probabilities = f.probability_all(&sample);
+        assert!(probabilities.iter().all(|p| (0.0..=1.0).contains(p)));
+
+        let predictions = f.predict_all(&sample);
+        let loss = sample.target()
+            .iter()
+            .zip(predictions)
+            .filter(|(&y, p)| y != *p as f64)
+            .count() as f64
+            / n_sample;
+        assert!(loss < 0.1, "0/1-loss = {loss}");
+
+        let deviance = GBMLoss::Logistic.eval(
+            &f.regressor().predict_all(&sample), sample.target()
+        );
+        assert!(deviance < 2f64.ln(), "deviance = {deviance}");
+    }
+}
+
+
+/// Tests for the Huber and the quantile losses.
+#[cfg(test)]
+pub mod gbm_robust {
+    use super::*;
+
+    // `y = x0` with a periodic noise in `[-2, 2]`.
+    // If `with_outliers` is `true`,
+    // every tenth example has a huge target value.
+    fn toy_sample(with_outliers: bool) -> Sample {
+        let mut x0 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..200 {
+            let x = (i / 10) as f64;
+            let noise = ((i * 7) % 5) as f64 - 2.0;
+            x0.push(x);
+            let y = if with_outliers && i % 10 == 3 { 1000.0 } else { x + noise };
+            target.push(y);
+        }
+        let columns = vec![("x0", x0)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    #[test]
+    fn quantile_line_search() {
+        let target = (1..=10).map(|y| y as f64).collect::<Vec<_>>();
+        let current = vec![0.0; 10];
+        let direction = vec![1.0; 10];
+
+        let coef = GBMLoss::Quantile(0.9)
+            .line_search(&current, &direction, &target);
+        assert_eq!(coef, 9.0);
+
+        // Along a negative direction,
+        // every point in `[-10, -9]` is a minimizer.
+        let direction = vec![-1.0; 10];
+        let coef = GBMLoss::Quantile(0.9)
+            .line_search(&current, &direction, &target);
+        assert!((-10.0..=-9.0).contains(&coef), "{coef}");
+    }
+
+
+    #[test]
+    fn quantile() {
+        let sample = toy_sample(false);
+        let n_sample = sample.shape().0 as f64;
+
+        for alpha in [0.1, 0.9] {
+            let loss = GBMLoss::Quantile(alpha);
+            let mut gbm = GBM::init_with_loss(&sample, loss);
+            let tree = RegressionTreeBuilder::new(&sample)
+                .max_depth(3)
+                .loss(loss)
+                .build();
+            let f = gbm.run(&tree);
+            let predictions = f.predict_all(&sample);
+
+            let coverage = sample.target()
+                .iter()
+                .zip(&predictions[..])
+                .filter(|(y, p)| y <= p)
+                .count() as f64
+                / n_sample;
+            assert!(
+                (coverage - alpha).abs() <= 0.1,
+                "alpha = {alpha}, coverage = {coverage}"
+            );
+        }
+    }
+
+
+    #[test]
+    fn huber() {
+        let sample = toy_sample(true);
+        let clean = toy_sample(false);
+        let n_sample = sample.shape().0 as f64;
+
+        let error = |loss: GBMLoss| -> f64 {
+            let mut gbm = GBM::init_with_loss(&sample, loss);
+            let tree = RegressionTreeBuilder::new(&sample)
+                .max_depth(3)
+                .loss(loss)
+                .build();
+            let f = gbm.run(&tree);
+            let predictions = f.predict_all(&sample);
+            clean.target()
+                .iter()
+                .zip(predictions)
+                .map(|(y, p)| (y - p).abs())
+                .sum::<f64>()
+                / n_sample
+        };
+
+        let huber = error(GBMLoss::Huber(1.0));
+        let l2 = error(GBMLoss::L2);
+        assert!(huber < 3.0, "Huber: {huber}");
+        assert!(huber < l2, "Huber: {huber}, L2: {l2}");
+    }
+}
+
+
+/// Tests for the log-link losses.
+#[cfg(test)]
+pub mod gbm_log_link {
+    use super::*;
+
+    // The mean of `y` is about `exp(0.3 * x0)`.
+    // If `counts` is `true`, the targets are rounded down,
+    // so that some of them are zero.
+    fn toy_sample(counts: bool) -> Sample {
+        let mut x0 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..200 {
+            let x = (i / 20) as f64;
+            let noise = 0.5 + ((i * 7) % 5) as f64 / 4.0;
+            let y = (0.3 * x).exp() * noise;
+            x0.push(x);
+            target.push(if counts { y.floor() } else { y });
+        }
+        let columns = vec![("x0", x0)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    fn fit(sample: &Sample, loss: GBMLoss) -> Vec<f64> {
+        let mut gbm = GBM::init_with_loss(sample, loss);
+        let tree = RegressionTreeBuilder::new(sample)
+            .max_depth(2)
+            .loss(loss)
+            .build();
+        let f = LogLinkRegressor::new(gbm.run(&tree));
+        f.predict_all(sample)
+    }
+
+
+    #[test]
+    fn log_link() {
+        let cases = [
+            (true, GBMLoss::Poisson),
+            (false, GBMLoss::Gamma),
+            (true, GBMLoss::Tweedie(1.5)),
+        ];
+        for (counts, loss) in cases {
+            let sample = toy_sample(counts);
+            let target = sample.target();
+            let n_sample = sample.shape().0 as f64;
+            let predictions = fit(&sample, loss);
+            assert!(predictions.iter().all(|p| *p > 0.0));
+
+            // Compare to the constant prediction of the mean.
+            let mean = target.iter().sum::<f64>() / n_sample;
+            let error = target.iter()
+                .zip(&predictions[..])
+                .map(|(y, p)| (y - p).powi(2))
+                .sum::<f64>()
+                / n_sample;
+            let baseline = target.iter()
+                .map(|y| (y - mean).powi(2))
+                .sum::<f64>()
+                / n_sample;
+            assert!(
+                error < 0.5 * baseline,
+                "{}: {error} vs {baseline}", loss.name()
+            );
+        }
+    }
+
+
+    #[test]
+    #[should_panic(expected = "requires positive targets")]
+    fn gamma_zero_target() {
+        let x0 = (0..40).map(|i| i as f64).collect::<Vec<_>>();
+        let target = (0..40)
+            .map(|i| if i % 4 == 0 { 0.0 } else { 1.0 + i as f64 })
+            .collect::<Vec<_>>();
+        let sample = Sample::from_dense_columns(vec![("x0", x0)], target)
+            .unwrap();
+        fit(&sample, GBMLoss::Gamma);
+    }
+
+
+    #[test]
+    #[should_panic(expected = "requires non-negative targets")]
+    fn poisson_negative_target() {
+        let x0 = (0..40).map(|i| i as f64).collect::<Vec<_>>();
+        let target = (0..40)
+            .map(|i| if i % 4 == 0 { -1.0 } else { i as f64 })
+            .collect::<Vec<_>>();
+        let sample = Sample::from_dense_columns(vec![("x0", x0)], target)
+            .unwrap();
+        fit(&sample, GBMLoss::Poisson);
+    }
+
+
+    // `exp(800)` overflows, so that the line search must stop
+    // on the non-finite gradient instead of halving the step forever.
+    #[test]
+    fn overflowing_line_search() {
+        let losses = [
+            GBMLoss::Logistic,
+            GBMLoss::Exponential,
+            GBMLoss::Poisson,
+            GBMLoss::Gamma,
+            GBMLoss::Tweedie(1.5),
+        ];
+        for loss in losses {
+            let coef = loss.line_search(
+                &[-800.0, 0.0], &[1.0, 1.0], &[1.0, 0.0]
+            );
+            assert!(coef.is_finite(), "{}: {coef}", loss.name());
+        }
+    }
+}
+
+
+/// Tests for DART.
+#[cfg(test)]
+pub mod gbm_dart {
+    use super::*;
+
+    // `y = 2 x0 - x1 + 3` on a grid.
+    fn toy_sample() -> Sample {
+        let mut x0 = Vec::new();
+        let mut x1 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..10 {
+            for j in 0..10 {
+                x0.push(i as f64);
+                x1.push(j as f64);
+                target.push(2.0 * i as f64 - j as f64 + 3.0);
+            }
+        }
+        let columns = vec![("x0", x0), ("x1", x1)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    #[test]
+    fn dart() {
+        let sample = toy_sample();
+        let n_sample = sample.shape().0 as f64;
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2)
+            .build();
+
+        let target = sample.target();
+        let mean = target.iter().sum::<f64>() / n_sample;
+        let baseline = target.iter()
+            .map(|y| (y - mean).powi(2))
+            .sum::<f64>()
+            / n_sample;
+
+        for newton in [false, true] {
+            let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+                .newton(newton)
+                .dart(0.1, 0.5)
+                .seed(7);
+            let f = gbm.run(&tree);
+            let predictions = f.predict_all(&sample);
+
+            let loss = target.iter()
+                .zip(&predictions[..])
+                .map(|(y, p)| (y - p).powi(2))
+                .sum::<f64>()
+                / n_sample;
+            assert!(loss < 0.05 * baseline, "{loss} vs {baseline}");
+        }
+    }
+
+
+    #[test]
+    fn skip_all() {
+        let sample = toy_sample();
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(GBMLoss::L2)
+            .build();
+
+        // DART that always skips the dropout is the plain `GBM`.
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .dart(0.5, 1.0);
+        let p = gbm.run(&tree).predict_all(&sample);
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2);
+        let q = gbm.run(&tree).predict_all(&sample);
+        assert_eq!(p, q);
+    }
+}
+
+
+/// Tests for the base score, the shrinkage, and the early stopping.
+#[cfg(test)]
+pub mod gbm_base_score {
+    use super::*;
+
+    // `y = x0 + 100` with a periodic noise.
+    // The feature `id` lets the trees memorize the noise,
+    // which has the opposite sign in the validation sample.
+    fn toy_sample(valid: bool) -> Sample {
+        let mut x0 = Vec::new();
+        let mut id = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..100 {
+            let x = (i / 5) as f64;
+            let noise = ((i * 7) % 5) as f64 - 2.0;
+            let noise = if valid { -noise } else { noise };
+            x0.push(x);
+            id.push(i as f64);
+            target.push(x + 100.0 + noise);
+        }
+        let columns = vec![("x0", x0), ("id", id)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    fn l2_loss(sample: &Sample, predictions: &[f64]) -> f64 {
+        let n_sample = sample.shape().0 as f64;
+        sample.target()
+            .iter()
+            .zip(predictions)
+            .map(|(y, p)| (y - p).powi(2))
+            .sum::<f64>()
+            / n_sample
+    }
+
+
+    #[test]
+    fn base_score() {
+        let target = [1.0, 2.0, 3.0, 10.0];
+        assert_eq!(GBMLoss::L2.base_score(&target), 4.0);
+        assert_eq!(GBMLoss::L1.base_score(&target), 2.0);
+        let log_mean = GBMLoss::Poisson.base_score(&target);
+        assert!((log_mean - 4f64.ln()).abs() < 1e-6);
+        assert_eq!(LambdaRank::new().base_score(&target), 0.0);
+
+        let sample = toy_sample(false);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(GBMLoss::L2)
+            .build();
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .newton(true)
+            .learning_rate(0.1);
+        let f = gbm.run(&tree);
+
+        // The model stores the mean of the target.
+        let n_sample = sample.shape().0 as f64;
+        let mean = sample.target().iter().sum::<f64>() / n_sample;
+        assert!((f.base_score() - mean).abs() < 1e-9);
+
+        let predictions = f.predict_all(&sample);
+        let trees = f.regressor().predict_all(&sample);
+        assert!(
+            predictions.iter()
+                .zip(&trees[..])
+                .all(|(p, t)| (p - mean - t).abs() < 1e-9)
+        );
+        assert!(l2_loss(&sample, &predictions) < 5.0);
+    }
+
+
+    #[test]
+    fn early_stopping() {
+        let sample = toy_sample(false);
+        let valid = toy_sample(true);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2)
+            .build();
+
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .newton(true)
+            .learning_rate(0.3);
+        let f = gbm.run(&tree);
+
+        let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .newton(true)
+            .learning_rate(0.3)
+            .early_stopping(&valid, 5);
+        let g = gbm.run(&tree);
+
+        let n_trees = f.regressor().hypotheses.len();
+        assert!(g.regressor().hypotheses.len() < n_trees);
+
+        let f_loss = l2_loss(&valid, &f.predict_all(&valid));
+        let g_loss = l2_loss(&valid, &g.predict_all(&valid));
+        assert!(g_loss <= f_loss, "{g_loss} vs {f_loss}");
+    }
+}
+
+
+/// Tests for the Cox proportional hazards loss.
+#[cfg(test)]
+pub mod gbm_cox {
+    use super::*;
+
+    // The hazard increases in `x0`,
+    // and every fourth example is censored.
+    fn toy_sample() -> Sample {
+        let mut x0 = Vec::new();
+        let mut event = Vec::new();
+        let mut time = Vec::new();
+        for i in 0..200 {
+            let x = (i / 20) as f64;
+            let noise = 0.5 + ((i * 7) % 5) as f64 / 4.0;
+            x0.push(x);
+            event.push(if i % 4 == 0 { 0.0 } else { 1.0 });
+            time.push(10.0 * (-0.5 * x).exp() * noise);
+        }
+        let columns = vec![("x0", x0), ("event", event)];
+        Sample::from_dense_columns(columns, time)
+            .unwrap()
+            .set_event("event")
+    }
+
+
+    #[test]
+    fn set_event() {
+        let sample = toy_sample();
+        assert_eq!(sample.shape().1, 1);
+        sample.target()
+            .iter()
+            .enumerate()
+            .for_each(|(i, y)| assert_eq!(i % 4 == 0, *y < 0.0));
+    }
+
+
+    #[test]
+    fn gradient() {
+        let loss = CoxPH::new();
+        let target = [3.0, -2.0, 2.0, 5.0, -1.0, 2.0];
+        let predictions = [0.3, -0.2, 1.0, 0.0, 0.5, -0.7];
+        let n_sample = target.len() as f64;
+        let gradient = loss.gradient(&predictions, &target);
+        let hessian = loss.hessian(&predictions, &target);
+
+        let eps = 1e-5;
+        for k in 0..target.len() {
+            let mut plus = predictions;
+            let mut minus = predictions;
+            plus[k] += eps;
+            minus[k] -= eps;
+            let lp = loss.eval(&plus, &target) * n_sample;
+            let lm = loss.eval(&minus, &target) * n_sample;
+            let l0 = loss.eval(&predictions, &target) * n_sample;
+            let g = (lp - lm) / (2.0 * eps);
+            let h = (lp - 2.0 * l0 + lm) / (eps * eps);
+            assert!((g - gradient[k]).abs() < 1e-6, "{g} vs {}", gradient[k]);
+            assert!((h - hessian[k]).abs() < 1e-3, "{h} vs {}", hessian[k]);
+        }
+    }
+
+
+    #[test]
+    fn concordance_index() {
+        let loss = CoxPH::new();
+        let target = [1.0, 2.0, -3.0, 4.0];
+        assert_eq!(loss.concordance_index(&[4.0, 3.0, 2.0, 1.0], &target), 1.0);
+        assert_eq!(loss.concordance_index(&[1.0, 2.0, 3.0, 4.0], &target), 0.0);
+        assert_eq!(loss.concordance_index(&[0.0; 4], &target), 0.5);
+    }
+
+
+    #[test]
+    fn stratified_line_search() {
+        let loss = CoxPH::new();
+        // The second stratum has the later survival times,
+        // so that the unstratified risk sets mix the two strata.
+        let target = [1.0, 2.0, -3.0, 4.0, 11.0, 12.0, -13.0, 14.0];
+        let group = [0, 0, 0, 0, 1, 1, 1, 1];
+        let current = [0.0; 8];
+        let direction = [0.5, -1.0, 1.0, 0.0, -0.5, 1.0, 0.0, 2.0];
+
+        let grouped = loss.line_search_grouped(
+            &current, &direction, &target, &group
+        );
+        let ungrouped = loss.line_search(&current, &direction, &target);
+        assert!(
+            (grouped - ungrouped).abs() > 1e-2,
+            "{grouped} vs {ungrouped}"
+        );
+
+        // The coefficient minimizes the stratified partial likelihood.
+        let eval = |c: f64| {
+            let p = direction.iter().map(|d| c * d).collect::<Vec<_>>();
+            loss.eval_grouped(&p, &target, &group)
+        };
+        let eps = 1e-4;
+        assert!(eval(grouped) <= eval(grouped + eps));
+        assert!(eval(grouped) <= eval(grouped - eps));
+        assert!(eval(grouped) < eval(ungrouped));
+    }
+
+
+    #[test]
+    fn cox() {
+        let sample = toy_sample();
+        let target = sample.target();
+        let loss = CoxPH::new();
+
+        let mut gbm = GBM::init_with_loss(&sample, loss);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(loss)
+            .build();
+        let f = gbm.run(&tree);
+        let scores = f.predict_all(&sample);
+
+        let zeros = vec![0.0; scores.len()];
+        assert!(loss.eval(&scores, target) < loss.eval(&zeros, target));
+
+        let c_index = loss.concordance_index(&scores, target);
+        assert!(c_index > 0.8, "C-index: {c_index}");
+    }
+}
+
+
+/// Tests for `MultiClassGBM`.
+#[cfg(test)]
+pub mod gbm_multiclass {
+    use super::*;
+
+    // The class is `0`, `1`, or `2` depending on `x0`,
+    // and every tenth example is mislabeled.
+    fn toy_sample() -> Sample {
+        let mut x0 = Vec::new();
+        let mut x1 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..300 {
+            let x = (i % 30) as f64;
+            let y = if x < 10.0 { 0 } else if x < 20.0 { 1 } else { 2 };
+            let y = if i % 10 == 3 { (y + 1) % 3 } else { y };
+            x0.push(x);
+            x1.push((i % 7) as f64);
+            target.push(y as f64);
+        }
+        let columns = vec![("x0", x0), ("x1", x1)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    #[test]
+    fn softmax_loss() {
+        let loss = SoftmaxLoss::new();
+        let target = [0.0, 2.0, 1.0];
+        let predictions = vec![
+            vec![0.5, -1.0, 0.0],
+            vec![0.0, 0.3, 2.0],
+            vec![-0.2, 1.0, 0.0],
+        ];
+        let n_sample = target.len() as f64;
+        let gradient = loss.gradient(&predictions, &target);
+
+        let eps = 1e-6;
+        for k in 0..3 {
+            for i in 0..3 {
+                let mut plus = predictions.clone();
+                let mut minus = predictions.clone();
+                plus[k][i] += eps;
+                minus[k][i] -= eps;
+                let g = (loss.eval(&plus, &target) - loss.eval(&minus, &target))
+                    * n_sample / (2.0 * eps);
+                assert!((g - gradient[k][i]).abs() < 1e-6);
+            }
+        }
+
+        // The base scores are the log-priors.
+        let base = loss.base_score(&[0.0, 0.0, 1.0, 2.0], 3);
+        assert!((base[0] - 0.5_f64.ln()).abs() < 1e-12);
+        assert!((base[1] - 0.25_f64.ln()).abs() < 1e-12);
+    }
+
+
+    #[test]
+    fn multiclass() {
+        let sample = toy_sample();
+        let mut gbm = MultiClassGBM::init_with_loss(&sample, SoftmaxLoss)
+            .learning_rate(0.5)
+            .force_quit_at(30);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(GBMLoss::L2)
+            .build();
+        let f = gbm.run(&tree);
+        assert_eq!(f.n_class(), 3);
+
+        for p in f.probability_all(&sample) {
+            assert_eq!(p.len(), 3);
+            assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+
+        let n_sample = sample.shape().0 as f64;
+        let accuracy = sample.target()
+            .iter()
+            .zip(f.predict_class_all(&sample))
+            .filter(|(y, p)| **y as usize == *p)
+            .count() as f64
+            / n_sample;
+        assert!(accuracy >= 0.85, "Accuracy: {accuracy}");
+    }
+}
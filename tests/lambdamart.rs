@@ -0,0 +1,78 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for LambdaMART, `GBM` with `LambdaRank`.
+#[cfg(test)]
+pub mod lambdamart_tests {
+    use super::*;
+
+    // Toy example with 4 queries of 8 documents each.
+    // The relevance depends on `x` with a query-dependent offset,
+    // so that only the order within each query is learnable.
+    fn toy_sample() -> Sample {
+        let mut x = Vec::new();
+        let mut noise = Vec::new();
+        let mut query = Vec::new();
+        let mut target = Vec::new();
+        for q in 0..4 {
+            for d in 0..8 {
+                let xi = q as f64 + d as f64 / 8.0;
+                x.push(xi);
+                noise.push(((q * 8 + d) * 7 % 5) as f64);
+                query.push(q as f64);
+                target.push((d / 3) as f64);
+            }
+        }
+        let columns = vec![("x", x), ("noise", noise), ("query", query)];
+        Sample::from_dense_columns(columns, target)
+            .unwrap()
+            .set_group("query")
+    }
+
+
+    #[test]
+    fn ndcg_of_ideal_ranking() {
+        let sample = toy_sample();
+        let loss = LambdaRank::new();
+
+        let target = sample.target();
+        let value = loss.eval_grouped(target, target, sample.group());
+        assert_eq!(value, 0.0);
+
+        let reversed = target.iter().map(|y| -y).collect::<Vec<_>>();
+        let value = loss.eval_grouped(&reversed, target, sample.group());
+        assert!(value > 0.0);
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+        assert_eq!(sample.shape().1, 2);
+
+        let loss = LambdaRank::new().truncation(5);
+        let mut booster = GBM::init_with_loss(&sample, loss);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(loss)
+            .build();
+
+
+        let f = booster.run(&tree);
+
+
+        let scores = f.predict_all(&sample);
+        let ndcg = 1.0 - loss.eval_grouped(&scores, sample.target(), sample.group());
+        println!("NDCG@5: {ndcg}");
+        assert!(ndcg >= 0.99);
+    }
+
+
+    #[test]
+    #[should_panic]
+    fn no_queries() {
+        let target = vec![2.0, 1.0, 0.0];
+        let predictions = vec![0.0, 1.0, 2.0];
+        LambdaRank::new().gradient_grouped(&predictions, &target, &[]);
+    }
+}
@@ -0,0 +1,51 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `LogitBoost`.
+#[cfg(test)]
+pub mod logitboost_tests {
+    use super::*;
+
+    // Toy example.
+    // The positive examples lie on the upper-right area.
+    fn toy_sample() -> Sample {
+        let x = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.5, 6.5];
+        let y = vec![2.0, 1.0, 3.0, 2.0, 7.0, 6.0, 8.0, 7.0, 6.0, 2.0];
+        let target = vec![-1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 1.0];
+        Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let mut booster = LogitBoost::init(&sample)
+            .force_quit_at(20);
+
+        let wl = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(GBMLoss::Logistic)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let predictions = f.predict_all(&sample);
+
+        let loss = sample.target()
+            .iter()
+            .zip(predictions)
+            .map(|(t, p)| if *t != p as f64 { 1.0 } else { 0.0 })
+            .sum::<f64>() / m as f64;
+
+        println!("Training Loss: {loss}");
+        assert_eq!(loss, 0.0);
+
+        let probabilities = f.probability_all(&sample);
+        assert!(probabilities.iter().all(|p| (0.0..=1.0).contains(p)));
+    }
+}
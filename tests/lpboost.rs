@@ -1,6 +1,7 @@
 use miniboosts::prelude::*;
 use miniboosts::research::Logger;
 use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
 
 fn zero_one_loss<H>(sample: &Sample, f: &H)
     -> f64
@@ -25,6 +26,52 @@ const TIME_LIMIT: u128 = 60_000; // 1 minute as millisecond.
 #[cfg(test)]
 pub mod lpboost_tests {
     use super::*;
+    // Toy example whose decision boundary is a diagonal line,
+    // so that `LPBoost` needs many decision stumps.
+    fn diagonal_sample() -> Sample {
+        let n_sample = 200;
+        let x1 = (0..n_sample)
+            .map(|i| i as f64 / n_sample as f64)
+            .collect::<Vec<_>>();
+        let x2 = (0..n_sample)
+            .map(|i| ((i * 37) % 101) as f64 / 101.0)
+            .collect::<Vec<_>>();
+        let target = x1.iter()
+            .zip(&x2[..])
+            .map(|(a, b)| if a + b > 1.0 { 1.0 } else { -1.0 })
+            .collect::<Vec<_>>();
+        Sample::from_dense_columns(vec![("x1", x1), ("x2", x2)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn active_set() {
+        const TOLERANCE: f64 = 0.01;
+        let sample = diagonal_sample();
+        let objective = SoftMarginObjective::new(1.0);
+        let tree = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Edge)
+            .build();
+
+        let mut booster = LPBoost::init(&sample)
+            .tolerance(TOLERANCE);
+        let f = booster.run(&tree);
+        let full = objective.eval(&sample, &f);
+
+        let mut booster = LPBoost::init(&sample)
+            .tolerance(TOLERANCE)
+            .drop_columns_after(3)
+            .drop_constraints_after(3);
+        let f = booster.run(&tree);
+        let dropped = objective.eval(&sample, &f);
+
+        println!("objective: {full} (full), {dropped} (dropped)");
+        assert!((full - dropped).abs() <= 2.0 * TOLERANCE);
+    }
+
+
     #[test]
     fn bcancer() {
         const TOLERANCE: f64 = 0.001;
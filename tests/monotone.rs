@@ -0,0 +1,168 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for the monotone constraints on the tree weak learners.
+#[cfg(test)]
+pub mod monotone_tests {
+    use super::*;
+
+    // The target is `x0` plus a bump at `x0 = 5`,
+    // which makes the unconstrained fit non-monotone.
+    // For classification, the label is `+1` if `x0 > 5`
+    // except a dip at `x0 = 7`.
+    // `x1` is an unconstrained noise feature.
+    fn toy_sample(classification: bool) -> Sample {
+        let mut x0 = Vec::new();
+        let mut x1 = Vec::new();
+        let mut target = Vec::new();
+        for i in 0..200 {
+            let x = (i % 20) as f64 * 0.5;
+            let bump = if (5.0..6.0).contains(&x) { 4.0 } else { 0.0 };
+            let y = x + bump + ((i * 7) % 5) as f64 * 0.1;
+            x0.push(x);
+            x1.push((i % 3) as f64);
+            target.push(if classification {
+                let dip = (7.0..8.0).contains(&x);
+                if x > 5.0 && !dip { 1.0 } else { -1.0 }
+            } else {
+                y
+            });
+        }
+        let columns = vec![("x0", x0), ("x1", x1)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    // Returns the grid of `x0` with fixed `x1`.
+    fn grid(x1: f64) -> Sample {
+        let x0 = (0..100).map(|i| i as f64 * 0.1).collect::<Vec<_>>();
+        let x1 = vec![x1; x0.len()];
+        let target = vec![0.0; x0.len()];
+        let columns = vec![("x0", x0), ("x1", x1)];
+        Sample::from_dense_columns(columns, target).unwrap()
+    }
+
+
+    fn is_sorted(values: &[f64], sign: f64) -> bool {
+        values.windows(2).all(|w| sign * (w[1] - w[0]) >= -1e-12)
+    }
+
+
+    fn fit_gbm(sample: &Sample, sign: Option<i32>)
+        -> BaseScoreRegressor<WeightedMajority<RegressionTreeRegressor>>
+    {
+        let mut gbm = GBM::init_with_loss(sample, GBMLoss::L2)
+            .newton(true)
+            .learning_rate(0.3);
+        let mut builder = RegressionTreeBuilder::new(sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2);
+        if let Some(sign) = sign {
+            builder = builder.monotone_constraint("x0", sign);
+        }
+        gbm.run(&builder.build())
+    }
+
+
+    #[test]
+    fn regression_tree() {
+        let sample = toy_sample(false);
+
+        // Without the constraint, the ensemble fits the bump.
+        let f = fit_gbm(&sample, None);
+        let predictions = f.predict_all(&grid(0.0));
+        assert!(!is_sorted(&predictions[..], 1.0));
+
+        let f = fit_gbm(&sample, Some(1));
+        for x1 in [0.0, 1.0, 2.0] {
+            let predictions = f.predict_all(&grid(x1));
+            assert!(is_sorted(&predictions[..], 1.0));
+            assert!(predictions[99] - predictions[0] > 5.0);
+        }
+
+        // The decreasing constraint yields a non-increasing function.
+        let f = fit_gbm(&sample, Some(-1));
+        let predictions = f.predict_all(&grid(1.0));
+        assert!(is_sorted(&predictions[..], -1.0));
+    }
+
+
+    // Fits by the line search on random subsamples,
+    // which may find a negative coefficient for a tree.
+    fn fit_gbm_line_search(sample: &Sample, loss: GBMLoss, subsample: f64)
+        -> BaseScoreRegressor<WeightedMajority<RegressionTreeRegressor>>
+    {
+        let mut gbm = GBM::init_with_loss(sample, loss)
+            .newton(false)
+            .learning_rate(0.3)
+            .subsample(subsample);
+        let tree = RegressionTreeBuilder::new(sample)
+            .max_depth(3)
+            .loss(loss)
+            .monotone_constraint("x0", 1)
+            .build();
+        gbm.run(&tree)
+    }
+
+
+    #[test]
+    fn line_search() {
+        let sample = toy_sample(false);
+        let losses = [GBMLoss::L2, GBMLoss::Huber(1.0), GBMLoss::L1];
+        for loss in losses {
+            for subsample in [1.0, 0.3] {
+                let f = fit_gbm_line_search(&sample, loss, subsample);
+                assert!(f.regressor().weights.iter().all(|w| *w >= 0.0));
+                for x1 in [0.0, 1.0, 2.0] {
+                    let predictions = f.predict_all(&grid(x1));
+                    assert!(
+                        is_sorted(&predictions[..], 1.0),
+                        "{} with subsample {subsample}", loss.name()
+                    );
+                    assert!(predictions[99] - predictions[0] > 5.0);
+                }
+            }
+        }
+    }
+
+
+    fn fit_adaboost(sample: &Sample, sign: i32)
+        -> WeightedMajority<DecisionTreeClassifier>
+    {
+        let mut booster = AdaBoost::init(sample)
+            .tolerance(0.01)
+            .force_quit_at(50);
+        let tree = DecisionTreeBuilder::new(sample)
+            .max_depth(3)
+            .criterion(Criterion::Entropy)
+            .monotone_constraint("x0", sign)
+            .build();
+        booster.run(&tree)
+    }
+
+
+    #[test]
+    fn decision_tree() {
+        let sample = toy_sample(true);
+
+        // Without the constraint, the ensemble fits the dip.
+        let f = fit_adaboost(&sample, 0);
+        let confidences = f.confidence_all(&grid(0.0));
+        assert!(!is_sorted(&confidences[..], 1.0));
+
+        let f = fit_adaboost(&sample, 1);
+        for x1 in [0.0, 1.0, 2.0] {
+            let confidences = f.confidence_all(&grid(x1));
+            assert!(is_sorted(&confidences[..], 1.0));
+        }
+    }
+
+
+    #[test]
+    #[should_panic]
+    fn unknown_feature() {
+        let sample = toy_sample(false);
+        let _ = RegressionTreeBuilder::<GBMLoss>::new(&sample)
+            .monotone_constraint("x2", 1);
+    }
+}
@@ -0,0 +1,181 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `OzaBoost`.
+#[cfg(test)]
+pub mod ozaboost_tests {
+    use super::*;
+
+    // A stream of examples on the plane.
+    // The label is positive iff `x0 > 0.3` and `x1 > 0.3`.
+    fn stream(n: usize) -> Vec<(Vec<f64>, f64)> {
+        (0..n).map(|t| {
+                let x0 = ((t * 37) % 101) as f64 / 100.0;
+                let x1 = ((t * 53) % 97) as f64 / 96.0;
+                let y = if x0 > 0.3 && x1 > 0.3 { 1.0 } else { -1.0 };
+                (vec![x0, x1], y)
+            })
+            .collect()
+    }
+
+
+    fn accuracy<L>(booster: &OzaBoost<L>, examples: &[(Vec<f64>, f64)])
+        -> f64
+        where L: OnlineWeakLearner,
+    {
+        let n_correct = examples.iter()
+            .filter(|(x, y)| booster.predict_on(&x[..]) as f64 == *y)
+            .count();
+        n_correct as f64 / examples.len() as f64
+    }
+
+
+    #[test]
+    fn hoeffding_stump() {
+        let examples = stream(5_000);
+
+        let mut booster = OzaBoost::init(HoeffdingStump::init(), 10)
+            .seed(777);
+        for (x, y) in examples.iter() {
+            booster.update(&x[..], *y);
+        }
+
+        let acc = accuracy(&booster, &examples[..]);
+        println!("Accuracy: {acc}");
+        assert!(acc >= 0.8);
+    }
+
+
+    #[test]
+    fn gaussian_nb() {
+        let examples = stream(2_000);
+
+        let mut booster = OzaBoost::init(OnlineGaussianNB::init(), 5)
+            .seed(777)
+            .decay(0.999);
+        for (x, y) in examples.iter() {
+            booster.update(&x[..], *y);
+        }
+
+        let acc = accuracy(&booster, &examples[..]);
+        println!("Accuracy: {acc}");
+        assert!(acc >= 0.8);
+    }
+
+
+    // A stream whose concept changes at the middle.
+    // Before the change, the label is positive iff `x0 > 0.5`.
+    // After the change, `concept` gives the label.
+    fn drifting_stream<C>(n: usize, concept: C) -> Vec<(Vec<f64>, f64)>
+        where C: Fn(f64, f64) -> bool,
+    {
+        stream(n).into_iter()
+            .enumerate()
+            .map(|(t, (x, _))| {
+                let positive = if t < n / 2 {
+                    x[0] > 0.5
+                } else {
+                    concept(x[0], x[1])
+                };
+                let y = if positive { 1.0 } else { -1.0 };
+                (x, y)
+            })
+            .collect()
+    }
+
+
+    fn learner_accuracy<L>(learner: &L, examples: &[(Vec<f64>, f64)])
+        -> f64
+        where L: OnlineWeakLearner,
+    {
+        let n_correct = examples.iter()
+            .filter(|(x, y)| learner.predict_on(&x[..]) as f64 == *y)
+            .count();
+        n_correct as f64 / examples.len() as f64
+    }
+
+
+    #[test]
+    fn hoeffding_stump_drift() {
+        // The relevant feature changes from `x0` to `x1`.
+        let examples = drifting_stream(6_000, |_, x1| x1 > 0.5);
+        let (_, recent) = examples.split_at(5_000);
+
+        let mut stump = HoeffdingStump::init()
+            .grace_period(50.0)
+            .decay(0.995);
+        for (x, y) in examples.iter() {
+            stump.update(&x[..], *y, 1.0);
+        }
+
+        let acc = learner_accuracy(&stump, recent);
+        println!("Accuracy after the drift: {acc}");
+        assert!(stump.is_split());
+        assert!(acc >= 0.9);
+    }
+
+
+    #[test]
+    fn gaussian_nb_drift() {
+        // The labels are flipped.
+        let examples = drifting_stream(4_000, |x0, _| x0 <= 0.5);
+        let (_, recent) = examples.split_at(3_000);
+
+        let mut forgetful = OnlineGaussianNB::init()
+            .decay(0.99);
+        let mut stubborn = OnlineGaussianNB::init();
+        for (x, y) in examples.iter() {
+            forgetful.update(&x[..], *y, 1.0);
+            stubborn.update(&x[..], *y, 1.0);
+        }
+
+        let acc = learner_accuracy(&forgetful, recent);
+        println!("Accuracy after the drift: {acc}");
+        assert!(acc >= 0.9);
+
+        // Without forgetting, the two concepts cancel out each other.
+        let acc = learner_accuracy(&stubborn, recent);
+        println!("Accuracy after the drift (no decay): {acc}");
+        assert!(acc < 0.9);
+    }
+
+
+    // A weak learner that counts the calls of `update`.
+    // A forgetting learner forgets in each call.
+    #[derive(Clone)]
+    struct Recorder {
+        calls: usize,
+    }
+
+
+    impl OnlineWeakLearner for Recorder {
+        fn update(&mut self, _x: &[f64], _y: f64, _weight: f64) {
+            self.calls += 1;
+        }
+
+
+        fn confidence_on(&self, x: &[f64]) -> f64 {
+            if x[0] > 0.3 { 1.0 } else { -1.0 }
+        }
+    }
+
+
+    #[test]
+    fn decay_per_example() {
+        let examples = stream(500);
+        let n_learners = 5;
+
+        let recorder = Recorder { calls: 0 };
+        let mut booster = OzaBoost::init(recorder, n_learners)
+            .seed(777);
+        for (x, y) in examples.iter() {
+            booster.update(&x[..], *y);
+        }
+
+        // Every learner forgets once per example,
+        // even if the Poisson weight is zero.
+        for learner in booster.learners() {
+            assert_eq!(learner.calls, examples.len());
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `RankBoost`.
+#[cfg(test)]
+pub mod rankboost_tests {
+    use super::*;
+
+    // Toy example with imbalanced labels.
+    // The positive examples lie in the middle of the line,
+    // so that no single stump ranks them above the negative ones.
+    fn toy_sample() -> Sample {
+        let x = (0..12).map(|i| i as f64).collect::<Vec<_>>();
+        let target = x.iter()
+            .map(|&xi| if (4.0..=6.0).contains(&xi) { 1.0 } else { -1.0 })
+            .collect::<Vec<_>>();
+        Sample::from_dense_columns(vec![("x", x)], target)
+            .unwrap()
+    }
+
+
+    fn auc(scores: &[f64], target: &[f64]) -> f64 {
+        let mut n_correct = 0.0;
+        let mut n_pair = 0.0;
+        for (s1, _) in scores.iter().zip(target).filter(|(_, &y)| y > 0.0) {
+            for (s0, _) in scores.iter().zip(target).filter(|(_, &y)| y <= 0.0) {
+                n_pair += 1.0;
+                if s1 > s0 { n_correct += 1.0; }
+                else if s1 == s0 { n_correct += 0.5; }
+            }
+        }
+        n_correct / n_pair
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let mut booster = RankBoost::init(&sample)
+            .force_quit_at(20);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let scores = f.confidence_all(&sample);
+        let auc = auc(&scores[..], sample.target());
+
+        println!("AUC: {auc}");
+        assert_eq!(auc, 1.0);
+    }
+}
@@ -0,0 +1,51 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `RUSBoost`.
+#[cfg(test)]
+pub mod rusboost_tests {
+    use super::*;
+
+    // Imbalanced toy example.
+    // Only the examples with `x >= 17` are positive.
+    fn toy_sample() -> Sample {
+        let x = (0..20).map(|i| i as f64).collect::<Vec<_>>();
+        let y = (0..20).map(|i| (i % 3) as f64).collect::<Vec<_>>();
+        let target = (0..20)
+            .map(|i| if i >= 17 { 1.0 } else { -1.0 })
+            .collect::<Vec<_>>();
+        Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let mut booster = RUSBoost::init(&sample)
+            .seed(777)
+            .force_quit_at(20);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let predictions = f.predict_all(&sample);
+
+        let n_miss = sample.target()
+            .iter()
+            .zip(predictions)
+            .filter(|(&t, _)| t > 0.0)
+            .filter(|(&t, p)| t != *p as f64)
+            .count();
+
+        println!("# of misclassified positives: {n_miss}");
+        assert_eq!(n_miss, 0);
+    }
+}
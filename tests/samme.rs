@@ -0,0 +1,85 @@
+use miniboosts::prelude::*;
+
+
+/// Tests for `SAMME`.
+#[cfg(test)]
+pub mod samme_tests {
+    use super::*;
+
+    // Toy example with three classes.
+    // Class `k` lies around `(3k, 3k)` on the plane.
+    fn toy_sample() -> Sample {
+        let x = vec![0.0, 1.0, 0.5, 3.0, 4.0, 3.5, 6.0, 7.0, 6.5, 2.0];
+        let y = vec![1.0, 0.0, 0.5, 3.5, 3.0, 4.0, 7.0, 6.0, 6.5, 2.5];
+        let target = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 1.0];
+        Sample::from_dense_columns(vec![("x", x), ("y", y)], target)
+            .unwrap()
+    }
+
+
+    #[test]
+    fn toy() {
+        let sample = toy_sample();
+
+        let mut booster = SAMME::init(&sample)
+            .force_quit_at(20);
+
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+
+
+        let f = booster.run(&wl);
+
+
+        let (m, _) = sample.shape();
+        let predictions = f.predict_class_all(&sample);
+
+        let loss = sample.target()
+            .iter()
+            .zip(predictions)
+            .map(|(t, p)| if *t as usize != p { 1.0 } else { 0.0 })
+            .sum::<f64>() / m as f64;
+
+        println!("Training Loss: {loss}");
+        assert_eq!(loss, 0.0);
+    }
+
+
+    // A weak learner that always predicts the class `0`.
+    struct ConstantLearner;
+
+    #[derive(Clone)]
+    struct Constant;
+
+    impl MultiClassifier for Constant {
+        fn predict_class(&self, _sample: &Sample, _row: usize) -> usize {
+            0
+        }
+    }
+
+    impl WeakLearner for ConstantLearner {
+        type Hypothesis = Constant;
+
+        fn produce(&self, _sample: &Sample, _dist: &[f64]) -> Constant {
+            Constant
+        }
+    }
+
+
+    #[test]
+    fn no_better_than_random() {
+        let sample = toy_sample();
+
+        let mut booster = SAMME::init(&sample)
+            .force_quit_at(20);
+
+        // The constant learner makes the error `7/10 >= 1 - 1/3`
+        // in the first round, so `SAMME` keeps it as a fallback.
+        let f = booster.run(&ConstantLearner);
+
+        let predictions = f.predict_class_all(&sample);
+        assert!(predictions.into_iter().all(|p| p == 0));
+    }
+}